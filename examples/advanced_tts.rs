@@ -1,14 +1,10 @@
-use elevenlabs_tts::{ElevenLabsTTSClient, VoiceSettings, models, voices};
-use std::env;
+use elevenlabs_tts::{ElevenLabsTTSClient, OutputFormat, VoiceSettings, models, voices};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Get API key from environment variable
-    let api_key =
-        env::var("ELEVENLABS_API_KEY").expect("Please set ELEVENLABS_API_KEY environment variable");
-
+    // Read the API key from the ELEVENLABS_API_KEY environment variable
     println!("Creating ElevenLabs client...");
-    let client = ElevenLabsTTSClient::new(api_key);
+    let client = ElevenLabsTTSClient::from_env()?;
 
     // Test basic TTS with new voice API
     println!(
@@ -32,7 +28,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Only Turbo v2.5 & Flash v2.5 support language_code for pronunciation/accent
         .model(models::elevanlabs_models::ELEVEN_FLASH_V2_5)
         .language_code("fr")
-        .output_format("mp3_44100_192")
+        .output_format(OutputFormat::Mp3_44100_192)
         .seed(4000)
         .execute()
         .await?;
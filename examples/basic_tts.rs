@@ -1,14 +1,10 @@
 use elevenlabs_tts::{ElevenLabsTTSClient, models, voices};
-use std::env;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Get API key from environment variable
-    let api_key =
-        env::var("ELEVENLABS_API_KEY").expect("Please set ELEVENLABS_API_KEY environment variable");
-
+    // Read the API key from the ELEVENLABS_API_KEY environment variable
     println!("Creating ElevenLabs client...");
-    let client = ElevenLabsTTSClient::new(api_key);
+    let client = ElevenLabsTTSClient::from_env()?;
 
     // Test basic TTS with new voice API
     println!(
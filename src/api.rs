@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+
+use crate::dialogue::TextToDialogueRequest;
+use crate::music::MusicRequest;
+use crate::{AudioResponse, AudioStream, ElevenLabsTTSClient, ElevenLabsTTSError, TTSRequest};
+
+/// The API operations of [`ElevenLabsTTSClient`] as a trait
+///
+/// Depend on `TtsApi` (e.g. `Arc<dyn TtsApi>`) instead of the concrete client to swap in a
+/// hand-rolled mock in unit tests. Requests are built with the usual builders and their
+/// `build_request()`, or constructed directly.
+///
+/// ```rust
+/// use elevenlabs_tts::{ElevenLabsTTSError, TTSRequest, TtsApi};
+///
+/// async fn narrate(api: &dyn TtsApi, request: TTSRequest) -> Result<usize, ElevenLabsTTSError> {
+///     Ok(api.synthesize(request).await?.audio.len())
+/// }
+/// ```
+#[async_trait]
+pub trait TtsApi: Send + Sync {
+    /// Convert text to speech
+    async fn synthesize(&self, request: TTSRequest) -> Result<AudioResponse, ElevenLabsTTSError>;
+
+    /// Convert text to speech, streaming the audio as it is generated
+    async fn synthesize_stream(
+        &self,
+        request: TTSRequest,
+    ) -> Result<AudioStream, ElevenLabsTTSError>;
+
+    /// Render a multi-speaker dialogue
+    async fn synthesize_dialogue(
+        &self,
+        request: TextToDialogueRequest,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError>;
+
+    /// Compose music from a prompt
+    async fn compose_music(&self, request: MusicRequest) -> Result<Vec<u8>, ElevenLabsTTSError>;
+
+    /// Compose music from a prompt, streaming the audio as it is generated
+    async fn compose_music_stream(
+        &self,
+        request: MusicRequest,
+    ) -> Result<AudioStream, ElevenLabsTTSError>;
+
+    /// Mint a signed WebSocket URL for a Conversational AI agent
+    async fn conversation_signed_url(&self, agent_id: &str) -> Result<String, ElevenLabsTTSError>;
+}
+
+#[async_trait]
+impl TtsApi for ElevenLabsTTSClient {
+    async fn synthesize(&self, request: TTSRequest) -> Result<AudioResponse, ElevenLabsTTSError> {
+        self.execute_tts(request, &Default::default()).await
+    }
+
+    async fn synthesize_stream(
+        &self,
+        request: TTSRequest,
+    ) -> Result<AudioStream, ElevenLabsTTSError> {
+        self.execute_tts_stream(request, &Default::default()).await
+    }
+
+    async fn synthesize_dialogue(
+        &self,
+        request: TextToDialogueRequest,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        self.execute_dialogue(request, &Default::default()).await
+    }
+
+    async fn compose_music(&self, request: MusicRequest) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        self.execute_music(request, &Default::default()).await
+    }
+
+    async fn compose_music_stream(
+        &self,
+        request: MusicRequest,
+    ) -> Result<AudioStream, ElevenLabsTTSError> {
+        self.execute_music_stream(request, &Default::default())
+            .await
+    }
+
+    async fn conversation_signed_url(&self, agent_id: &str) -> Result<String, ElevenLabsTTSError> {
+        self.conversational_ai().signed_url(agent_id).await
+    }
+}
+
+/// Send text-to-speech requests through tower middleware (rate limiting, load shedding,
+/// buffering...). The client is always ready; its own concurrency limit, if configured,
+/// still applies inside `call`.
+///
+/// ```rust
+/// # #[cfg(feature = "tower")]
+/// # async fn example(request: elevenlabs_tts::TTSRequest) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// use tower_service::Service;
+/// use elevenlabs_tts::ElevenLabsTTSClient;
+///
+/// let mut service = ElevenLabsTTSClient::new("your-api-key");
+/// let response = service.call(request).await?;
+/// println!("{} bytes", response.audio.len());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tower")]
+impl tower_service::Service<TTSRequest> for ElevenLabsTTSClient {
+    type Response = AudioResponse;
+    type Error = ElevenLabsTTSError;
+    type Future = std::pin::Pin<
+        Box<dyn std::future::Future<Output = Result<AudioResponse, ElevenLabsTTSError>> + Send>,
+    >;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: TTSRequest) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { client.execute_tts(request, &Default::default()).await })
+    }
+}
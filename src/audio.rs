@@ -0,0 +1,541 @@
+//! Helpers for working with the generated audio
+
+use std::time::Duration;
+
+use crate::{ElevenLabsTTSError, OutputFormat};
+
+const BITS_PER_SAMPLE: u16 = 16;
+
+/// Wrap raw 16-bit little-endian PCM (the `pcm_*` output formats) in a WAV container,
+/// so it can be played or saved as a `.wav` file
+///
+/// ```rust
+/// let wav = elevenlabs_tts::audio::wrap_wav(&[0, 0, 1, 0], 16_000, 1).unwrap();
+/// assert_eq!(&wav[..4], b"RIFF");
+/// assert_eq!(wav.len(), 44 + 4);
+/// ```
+pub fn wrap_wav(
+    pcm: &[u8],
+    sample_rate: u32,
+    channels: u16,
+) -> Result<Vec<u8>, ElevenLabsTTSError> {
+    if channels == 0 || sample_rate == 0 {
+        return Err(ElevenLabsTTSError::ValidationError(
+            "WAV needs at least one channel and a non-zero sample rate".to_string(),
+        ));
+    }
+    let data_len = u32::try_from(pcm.len())
+        .ok()
+        .filter(|len| *len <= u32::MAX - 36)
+        .ok_or_else(|| {
+            ElevenLabsTTSError::ValidationError("PCM audio too large for a WAV file".to_string())
+        })?;
+
+    let mut wav = Vec::with_capacity(44 + pcm.len());
+    wav.extend_from_slice(&wav_header(sample_rate, channels, data_len)?);
+    wav.extend_from_slice(pcm);
+    Ok(wav)
+}
+
+/// 44-byte RIFF header for 16-bit little-endian PCM
+pub(crate) fn wav_header(
+    sample_rate: u32,
+    channels: u16,
+    data_len: u32,
+) -> Result<[u8; 44], ElevenLabsTTSError> {
+    let block_align = u32::from(channels)
+        .checked_mul(u32::from(BITS_PER_SAMPLE / 8))
+        .and_then(|block_align| u16::try_from(block_align).ok());
+    let byte_rate =
+        block_align.and_then(|block_align| sample_rate.checked_mul(u32::from(block_align)));
+    let (Some(block_align), Some(byte_rate)) = (block_align, byte_rate) else {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "{} channels at {} Hz don't fit in a WAV header",
+            channels, sample_rate
+        )));
+    };
+
+    let mut header = [0u8; 44];
+    header[0..4].copy_from_slice(b"RIFF");
+    header[4..8].copy_from_slice(&data_len.saturating_add(36).to_le_bytes());
+    header[8..12].copy_from_slice(b"WAVE");
+    header[12..16].copy_from_slice(b"fmt ");
+    header[16..20].copy_from_slice(&16u32.to_le_bytes());
+    header[20..22].copy_from_slice(&1u16.to_le_bytes()); // PCM
+    header[22..24].copy_from_slice(&channels.to_le_bytes());
+    header[24..28].copy_from_slice(&sample_rate.to_le_bytes());
+    header[28..32].copy_from_slice(&byte_rate.to_le_bytes());
+    header[32..34].copy_from_slice(&block_align.to_le_bytes());
+    header[34..36].copy_from_slice(&BITS_PER_SAMPLE.to_le_bytes());
+    header[36..40].copy_from_slice(b"data");
+    header[40..44].copy_from_slice(&data_len.to_le_bytes());
+    Ok(header)
+}
+
+/// Playback duration of generated audio without decoding it: exact for PCM, μ-law and
+/// A-law (from the byte count), summed from the frame headers for MP3 and read from the
+/// last Ogg page for Opus
+///
+/// ```rust
+/// use std::time::Duration;
+/// use elevenlabs_tts::{OutputFormat, audio};
+///
+/// let pcm = vec![0u8; 48_000]; // 24 000 samples of 16-bit PCM
+/// let duration = audio::duration(&pcm, OutputFormat::Pcm_24000).unwrap();
+/// assert_eq!(duration, Duration::from_secs(1));
+/// ```
+pub fn duration(audio: &[u8], format: OutputFormat) -> Result<Duration, ElevenLabsTTSError> {
+    let samples = match format.codec() {
+        "pcm" => (audio.len() / 2) as u64,
+        "ulaw" | "alaw" => audio.len() as u64,
+        "mp3" => return mp3_duration(audio),
+        "opus" => return opus_duration(audio),
+        codec => {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Cannot compute the duration of {} audio",
+                codec
+            )));
+        }
+    };
+    Ok(samples_duration(
+        samples / u64::from(format.channels()),
+        format.sample_rate(),
+    ))
+}
+
+fn samples_duration(samples: u64, sample_rate: u32) -> Duration {
+    Duration::from_nanos((u128::from(samples) * 1_000_000_000 / u128::from(sample_rate)) as u64)
+}
+
+/// Sum the duration of every MPEG audio Layer III frame, skipping an ID3v2 tag and
+/// any bytes between frames that are not a valid frame header
+fn mp3_duration(mut audio: &[u8]) -> Result<Duration, ElevenLabsTTSError> {
+    if let Some(length) = id3v2_length(audio) {
+        audio = audio.get(length..).unwrap_or_default();
+    }
+
+    let mut total = Duration::ZERO;
+    let mut frames = 0;
+    let mut offset = 0;
+    while offset + 4 <= audio.len() {
+        match mp3_frame(&audio[offset..offset + 4]) {
+            Some((length, samples, sample_rate)) => {
+                total += samples_duration(samples, sample_rate);
+                frames += 1;
+                offset += length;
+            }
+            None => offset += 1,
+        }
+    }
+    if frames == 0 {
+        return Err(ElevenLabsTTSError::ValidationError(
+            "No MP3 frames found in the audio".to_string(),
+        ));
+    }
+    Ok(total)
+}
+
+/// Whether `audio` starts with an MP3 frame, after any ID3v2 tag
+#[cfg(any(feature = "tags", feature = "decode"))]
+pub(crate) fn is_mp3(audio: &[u8]) -> bool {
+    let audio = audio
+        .get(id3v2_length(audio).unwrap_or(0)..)
+        .unwrap_or_default();
+    audio.len() >= 4 && mp3_frame(&audio[..4]).is_some()
+}
+
+/// Frame length in bytes, samples per frame and sample rate of a Layer III frame header
+fn mp3_frame(header: &[u8]) -> Option<(usize, u64, u32)> {
+    const MPEG1_BITRATES: [u32; 15] = [
+        0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+    ];
+    const MPEG2_BITRATES: [u32; 15] =
+        [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    const SAMPLE_RATES: [u32; 3] = [44_100, 48_000, 32_000];
+
+    if header[0] != 0xFF || header[1] & 0xE0 != 0xE0 {
+        return None;
+    }
+    // 0 = MPEG 2.5, 2 = MPEG 2, 3 = MPEG 1; 1 is reserved
+    let version = (header[1] >> 3) & 0x03;
+    let layer_three = (header[1] >> 1) & 0x03 == 0x01;
+    let bitrate_index = usize::from(header[2] >> 4);
+    let sample_rate_index = usize::from((header[2] >> 2) & 0x03);
+    if version == 1 || !layer_three || bitrate_index == 0 || bitrate_index == 15 {
+        return None;
+    }
+    let sample_rate = SAMPLE_RATES.get(sample_rate_index)? >> (3 - version).min(2);
+    let padding = usize::from((header[2] >> 1) & 0x01);
+    let (bitrate, samples) = match version {
+        3 => (MPEG1_BITRATES[bitrate_index], 1152),
+        _ => (MPEG2_BITRATES[bitrate_index], 576),
+    };
+    let length = (samples / 8 * bitrate * 1000 / sample_rate) as usize + padding;
+    Some((length, u64::from(samples), sample_rate))
+}
+
+/// Join clips generated in the same format into one playable file
+///
+/// MP3 clips are joined frame by frame: the ID3v2 tag of the first clip is kept, later
+/// tags, ID3v1 trailers, truncated frames and the Xing/Info/VBRI header frames (which
+/// describe a single clip and would make players show the wrong length) are dropped.
+/// Raw formats (PCM, μ-law, A-law) are appended as is, and Opus clips form a chained
+/// Ogg stream, which players handle as one file.
+///
+/// ```rust
+/// use elevenlabs_tts::{OutputFormat, audio};
+///
+/// let joined = audio::concat([&[1u8, 0][..], &[2, 0]], OutputFormat::Pcm_16000).unwrap();
+/// assert_eq!(joined, [1, 0, 2, 0]);
+/// ```
+pub fn concat<I, B>(clips: I, format: OutputFormat) -> Result<Vec<u8>, ElevenLabsTTSError>
+where
+    I: IntoIterator<Item = B>,
+    B: AsRef<[u8]>,
+{
+    let mut joined = Vec::new();
+    for (index, clip) in clips.into_iter().enumerate() {
+        let clip = clip.as_ref();
+        match format.codec() {
+            "mp3" => append_mp3(&mut joined, clip, index == 0).map_err(|err| {
+                ElevenLabsTTSError::ValidationError(format!("Clip {}: {}", index, err))
+            })?,
+            "pcm" if !clip.len().is_multiple_of(2) => {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Clip {} has an odd number of bytes, not 16-bit PCM",
+                    index
+                )));
+            }
+            _ => joined.extend_from_slice(clip),
+        }
+    }
+    Ok(joined)
+}
+
+/// Append the complete MP3 frames of `clip`, keeping its ID3v2 tag only if `keep_tag`
+fn append_mp3(joined: &mut Vec<u8>, mut clip: &[u8], keep_tag: bool) -> Result<(), String> {
+    if let Some(length) = id3v2_length(clip) {
+        if keep_tag {
+            joined.extend_from_slice(&clip[..length.min(clip.len())]);
+        }
+        clip = clip.get(length..).unwrap_or_default();
+    }
+
+    let mut frames = 0;
+    let mut offset = 0;
+    while offset + 4 <= clip.len() {
+        let frame = mp3_frame(&clip[offset..offset + 4])
+            .map(|(length, _, _)| length)
+            .and_then(|length| clip.get(offset..offset + length));
+        match frame {
+            Some(frame) => {
+                if !is_vbr_header(frame) {
+                    joined.extend_from_slice(frame);
+                }
+                frames += 1;
+                offset += frame.len();
+            }
+            None => offset += 1,
+        }
+    }
+    if frames == 0 {
+        return Err("no MP3 frames found".to_string());
+    }
+    Ok(())
+}
+
+/// Size of a leading ID3v2 tag, header included
+pub(crate) fn id3v2_length(audio: &[u8]) -> Option<usize> {
+    if audio.len() < 10 || &audio[..3] != b"ID3" {
+        return None;
+    }
+    // Synchsafe tag size, excluding the 10-byte header
+    let size = audio[6..10]
+        .iter()
+        .fold(0usize, |size, byte| (size << 7) | usize::from(byte & 0x7F));
+    Some(10 + size)
+}
+
+/// Whether a frame carries a Xing/Info (LAME) or VBRI header instead of audio
+fn is_vbr_header(frame: &[u8]) -> bool {
+    let side_info = &frame[4..frame.len().min(40)];
+    side_info
+        .windows(4)
+        .any(|window| window == b"Xing" || window == b"Info")
+        || frame.get(36..40) == Some(b"VBRI")
+}
+
+/// Granule position of the last Ogg page minus the Opus pre-skip, at Opus' fixed 48 kHz
+fn opus_duration(audio: &[u8]) -> Result<Duration, ElevenLabsTTSError> {
+    let granule = audio
+        .windows(4)
+        .rposition(|window| window == b"OggS")
+        .and_then(|page| audio.get(page + 6..page + 14))
+        .map(|bytes| u64::from_le_bytes(bytes.try_into().expect("8 bytes")));
+    let Some(granule) = granule else {
+        return Err(ElevenLabsTTSError::ValidationError(
+            "No Ogg pages found in the audio".to_string(),
+        ));
+    };
+    let pre_skip = audio
+        .windows(8)
+        .position(|window| window == b"OpusHead")
+        .and_then(|head| audio.get(head + 10..head + 12))
+        .map_or(0, |bytes| u16::from_le_bytes([bytes[0], bytes[1]]));
+    Ok(samples_duration(
+        granule.saturating_sub(u64::from(pre_skip)),
+        48_000,
+    ))
+}
+
+/// Decode G.711 μ-law (`ulaw_8000`) to 16-bit little-endian PCM at the same sample rate
+pub fn ulaw_to_pcm(ulaw: &[u8]) -> Vec<u8> {
+    ulaw.iter()
+        .flat_map(|&byte| ulaw_to_linear(byte).to_le_bytes())
+        .collect()
+}
+
+/// Encode 16-bit little-endian PCM to G.711 μ-law. The PCM must already be 8 kHz mono
+/// for `ulaw_8000` consumers such as Twilio; a trailing odd byte is ignored.
+pub fn pcm_to_ulaw(pcm: &[u8]) -> Vec<u8> {
+    samples(pcm).map(linear_to_ulaw).collect()
+}
+
+/// Decode G.711 A-law (`alaw_8000`) to 16-bit little-endian PCM at the same sample rate
+pub fn alaw_to_pcm(alaw: &[u8]) -> Vec<u8> {
+    alaw.iter()
+        .flat_map(|&byte| alaw_to_linear(byte).to_le_bytes())
+        .collect()
+}
+
+/// Encode 16-bit little-endian PCM to G.711 A-law; a trailing odd byte is ignored
+pub fn pcm_to_alaw(pcm: &[u8]) -> Vec<u8> {
+    samples(pcm).map(linear_to_alaw).collect()
+}
+
+/// Split audio in a raw format into frames of `frame` length (the last one may be shorter),
+/// e.g. the 20 ms packets expected by RTP and Twilio media streams
+///
+/// ```rust
+/// use std::time::Duration;
+/// use elevenlabs_tts::{OutputFormat, audio};
+///
+/// let ulaw = vec![0xFF; 400];
+/// let frames: Vec<&[u8]> = audio::frames(&ulaw, OutputFormat::Ulaw_8000, Duration::from_millis(20))
+///     .unwrap()
+///     .collect();
+/// assert_eq!(frames.len(), 3);
+/// assert_eq!(frames[0].len(), 160);
+/// ```
+pub fn frames(
+    audio: &[u8],
+    format: OutputFormat,
+    frame: Duration,
+) -> Result<std::slice::Chunks<'_, u8>, ElevenLabsTTSError> {
+    let bytes_per_sample = match format.codec() {
+        "pcm" => 2,
+        "ulaw" | "alaw" => 1,
+        _ => {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Only raw formats can be split into frames, got {}",
+                format
+            )));
+        }
+    };
+    let samples = (u128::from(format.sample_rate()) * frame.as_micros() / 1_000_000) as usize;
+    if samples == 0 {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "Frame of {:?} holds no samples at {} Hz",
+            frame,
+            format.sample_rate()
+        )));
+    }
+    Ok(audio.chunks(samples * bytes_per_sample))
+}
+
+/// Split audio into the 20 ms frames used by telephony (160 bytes for `ulaw_8000`)
+pub fn telephony_frames(
+    audio: &[u8],
+    format: OutputFormat,
+) -> Result<std::slice::Chunks<'_, u8>, ElevenLabsTTSError> {
+    frames(audio, format, Duration::from_millis(20))
+}
+
+fn samples(pcm: &[u8]) -> impl Iterator<Item = i16> + '_ {
+    pcm.chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+}
+
+/// G.711 μ-law encoding of a 16-bit sample
+pub fn linear_to_ulaw(sample: i16) -> u8 {
+    const BIAS: i32 = 0x84;
+    const CLIP: i32 = 32_635;
+
+    let mut value = i32::from(sample);
+    let sign = if value < 0 {
+        value = -value;
+        0x80
+    } else {
+        0
+    };
+    let value = value.min(CLIP) + BIAS;
+    let exponent = (7..=14)
+        .rev()
+        .find(|bit| value & (1 << bit) != 0)
+        .unwrap_or(7)
+        - 7;
+    let mantissa = (value >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) as u8 | mantissa as u8)
+}
+
+/// G.711 μ-law decoding to a 16-bit sample
+pub fn ulaw_to_linear(ulaw: u8) -> i16 {
+    let ulaw = !ulaw;
+    let exponent = (ulaw >> 4) & 0x07;
+    let mantissa = i32::from(ulaw & 0x0F);
+    let magnitude = (((mantissa << 3) + 0x84) << exponent) - 0x84;
+    if ulaw & 0x80 != 0 {
+        -magnitude as i16
+    } else {
+        magnitude as i16
+    }
+}
+
+/// G.711 A-law encoding of a 16-bit sample
+pub fn linear_to_alaw(sample: i16) -> u8 {
+    let (sign, magnitude) = if sample >= 0 {
+        (0x80, i32::from(sample))
+    } else {
+        (0x00, -i32::from(sample) - 1)
+    };
+    let magnitude = magnitude.min(32_767);
+    let (exponent, mantissa) = if magnitude < 256 {
+        (0, (magnitude >> 4) & 0x0F)
+    } else {
+        let exponent = (8..=14)
+            .rev()
+            .find(|bit| magnitude & (1 << bit) != 0)
+            .unwrap_or(8)
+            - 7;
+        (exponent, (magnitude >> (exponent + 3)) & 0x0F)
+    };
+    (sign | (exponent << 4) as u8 | mantissa as u8) ^ 0x55
+}
+
+/// G.711 A-law decoding to a 16-bit sample
+pub fn alaw_to_linear(alaw: u8) -> i16 {
+    let alaw = alaw ^ 0x55;
+    let exponent = (alaw >> 4) & 0x07;
+    let mantissa = i32::from(alaw & 0x0F);
+    let magnitude = match exponent {
+        0 => (mantissa << 4) + 8,
+        _ => ((mantissa << 4) + 0x108) << (exponent - 1),
+    };
+    if alaw & 0x80 != 0 {
+        magnitude as i16
+    } else {
+        -magnitude as i16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_g711_reference_values() {
+        assert_eq!(linear_to_ulaw(0), 0xFF);
+        assert_eq!(linear_to_ulaw(i16::MAX), 0x80);
+        assert_eq!(linear_to_ulaw(i16::MIN), 0x00);
+        assert_eq!(linear_to_alaw(0), 0xD5);
+        assert_eq!(linear_to_alaw(i16::MAX), 0xAA);
+        assert_eq!(linear_to_alaw(i16::MIN), 0x2A);
+        assert_eq!(ulaw_to_linear(0xFF), 0);
+        assert_eq!(ulaw_to_linear(0x80), 32_124);
+        assert_eq!(alaw_to_linear(0xD5), 8);
+        assert_eq!(alaw_to_linear(0x2A), -32_256);
+    }
+
+    #[test]
+    fn test_wav_header_rejects_impossible_channel_counts() {
+        let header = wav_header(48_000, 2, 8).unwrap();
+        assert_eq!(&header[28..32], &192_000u32.to_le_bytes());
+        assert_eq!(&header[32..34], &4u16.to_le_bytes());
+
+        assert!(wav_header(16_000, 32_767, 0).is_ok());
+        assert!(matches!(
+            wav_header(16_000, 32_768, 0),
+            Err(ElevenLabsTTSError::ValidationError(_))
+        ));
+        assert!(wrap_wav(&[0, 0], 16_000, u16::MAX).is_err());
+        assert!(wrap_wav(&[0, 0], u32::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_mp3_frame_header() {
+        // MPEG 1, 128 kbps, 44.1 kHz, unpadded and padded
+        assert_eq!(
+            mp3_frame(&[0xFF, 0xFB, 0x90, 0xC4]),
+            Some((417, 1152, 44_100))
+        );
+        assert_eq!(
+            mp3_frame(&[0xFF, 0xFB, 0x92, 0xC4]),
+            Some((418, 1152, 44_100))
+        );
+        // MPEG 2, 64 kbps, 22.05 kHz and MPEG 2.5, 32 kbps, 8 kHz
+        assert_eq!(
+            mp3_frame(&[0xFF, 0xF3, 0x80, 0xC4]),
+            Some((208, 576, 22_050))
+        );
+        assert_eq!(
+            mp3_frame(&[0xFF, 0xE3, 0x48, 0xC4]),
+            Some((288, 576, 8_000))
+        );
+        // Layer II, free bitrate and a missing sync word are not frames
+        assert_eq!(mp3_frame(&[0xFF, 0xFD, 0x90, 0xC4]), None);
+        assert_eq!(mp3_frame(&[0xFF, 0xFB, 0x00, 0xC4]), None);
+        assert_eq!(mp3_frame(&[0x49, 0x44, 0x33, 0x04]), None);
+    }
+
+    #[test]
+    fn test_concat_mp3_keeps_audio_frames_only() {
+        let frame = |fill: u8| {
+            let mut frame = vec![fill; 417];
+            frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0xC4]);
+            frame
+        };
+        let tag = b"ID3\x04\x00\x00\x00\x00\x00\x02ab".to_vec();
+        let mut info = frame(0);
+        info[36..40].copy_from_slice(b"Info");
+
+        let first = [tag.clone(), info.clone(), frame(1)].concat();
+        let second = [
+            tag.clone(),
+            frame(2),
+            frame(3)[..200].to_vec(),
+            b"TAG".to_vec(),
+        ]
+        .concat();
+        let joined = concat([first, second], OutputFormat::Mp3_44100_128).unwrap();
+        assert_eq!(joined, [tag, frame(1), frame(2)].concat());
+        assert_eq!(
+            duration(&joined, OutputFormat::Mp3_44100_128).unwrap(),
+            samples_duration(1152, 44_100) * 2
+        );
+
+        assert!(concat([&b"not audio"[..]], OutputFormat::Mp3_44100_128).is_err());
+        assert!(concat([&[0u8; 3][..]], OutputFormat::Pcm_16000).is_err());
+    }
+
+    #[test]
+    fn test_g711_round_trip_is_close() {
+        for sample in (i16::MIN..=i16::MAX).step_by(97) {
+            let ulaw = ulaw_to_linear(linear_to_ulaw(sample));
+            let alaw = alaw_to_linear(linear_to_alaw(sample));
+            // Companding keeps the error proportional to the magnitude
+            let tolerance = (i32::from(sample).abs() / 16).max(16);
+            assert!((i32::from(ulaw) - i32::from(sample)).abs() <= tolerance);
+            assert!((i32::from(alaw) - i32::from(sample)).abs() <= tolerance);
+        }
+    }
+}
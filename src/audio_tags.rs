@@ -0,0 +1,159 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::ElevenLabsTTSError;
+
+/// Longest tag name accepted by [`AudioTag::new`]
+const MAX_TAG_LEN: usize = 40;
+
+/// An eleven_v3 audio tag such as `[whispers]` or `[laughs]`
+///
+/// Tags steer delivery (emotion, reactions, pauses) and are written inline in the text.
+/// Constants cover the common tags; others can be created with [`AudioTag::new`], which
+/// only checks the name is well formed since the model accepts free-form tags.
+///
+/// ```rust
+/// use elevenlabs_tts::AudioTag;
+///
+/// assert_eq!(AudioTag::WHISPERS.to_string(), "[whispers]");
+/// assert!("happily".parse::<AudioTag>().is_ok());
+/// assert!("laughs!".parse::<AudioTag>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AudioTag(Cow<'static, str>);
+
+impl AudioTag {
+    // Delivery and emotion
+    pub const WHISPERS: AudioTag = AudioTag::known("whispers");
+    pub const SARCASTIC: AudioTag = AudioTag::known("sarcastic");
+    pub const CURIOUS: AudioTag = AudioTag::known("curious");
+    pub const EXCITED: AudioTag = AudioTag::known("excited");
+    pub const CRYING: AudioTag = AudioTag::known("crying");
+    pub const MISCHIEVOUSLY: AudioTag = AudioTag::known("mischievously");
+    pub const HAPPY: AudioTag = AudioTag::known("happy");
+    pub const SAD: AudioTag = AudioTag::known("sad");
+    pub const ANGRY: AudioTag = AudioTag::known("angry");
+    pub const SHOUTING: AudioTag = AudioTag::known("shouting");
+
+    // Reactions
+    pub const LAUGHS: AudioTag = AudioTag::known("laughs");
+    pub const LAUGHS_HARDER: AudioTag = AudioTag::known("laughs harder");
+    pub const STARTS_LAUGHING: AudioTag = AudioTag::known("starts laughing");
+    pub const WHEEZING: AudioTag = AudioTag::known("wheezing");
+    pub const SIGHS: AudioTag = AudioTag::known("sighs");
+    pub const EXHALES: AudioTag = AudioTag::known("exhales");
+    pub const SNORTS: AudioTag = AudioTag::known("snorts");
+    pub const CLEARS_THROAT: AudioTag = AudioTag::known("clears throat");
+    pub const GULPS: AudioTag = AudioTag::known("gulps");
+
+    // Pauses
+    pub const SHORT_PAUSE: AudioTag = AudioTag::known("short pause");
+    pub const LONG_PAUSE: AudioTag = AudioTag::known("long pause");
+
+    const fn known(name: &'static str) -> Self {
+        AudioTag(Cow::Borrowed(name))
+    }
+
+    /// Validate a tag name: letters, spaces, hyphens and apostrophes, at most 40 characters.
+    /// Surrounding brackets are stripped, so `[sighs]` and `sighs` are the same tag.
+    pub fn new<S: AsRef<str>>(name: S) -> Result<Self, ElevenLabsTTSError> {
+        let name = name.as_ref().trim();
+        let name = name
+            .strip_prefix('[')
+            .and_then(|inner| inner.strip_suffix(']'))
+            .unwrap_or(name)
+            .trim();
+        let valid = !name.is_empty()
+            && name.chars().count() <= MAX_TAG_LEN
+            && name
+                .chars()
+                .all(|c| c.is_alphabetic() || matches!(c, ' ' | '-' | '\''));
+        if !valid {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Invalid audio tag {:?}: expected up to {} letters, spaces, hyphens or apostrophes",
+                name, MAX_TAG_LEN
+            )));
+        }
+        Ok(AudioTag(Cow::Owned(name.to_string())))
+    }
+
+    /// The tag name without brackets, e.g. `whispers`
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for AudioTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]", self.name())
+    }
+}
+
+impl FromStr for AudioTag {
+    type Err = ElevenLabsTTSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AudioTag::new(s)
+    }
+}
+
+/// Text interleaved with audio tags, for eleven_v3 requests
+///
+/// ```rust
+/// use elevenlabs_tts::{AudioTag, TaggedText};
+///
+/// let text = TaggedText::new()
+///     .tag(AudioTag::WHISPERS)
+///     .text("I have a secret.")
+///     .tag(AudioTag::SHORT_PAUSE)
+///     .tag(AudioTag::LAUGHS)
+///     .text("Just kidding!");
+/// assert_eq!(
+///     text.to_string(),
+///     "[whispers] I have a secret. [short pause] [laughs] Just kidding!"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaggedText {
+    parts: Vec<String>,
+}
+
+impl TaggedText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an audio tag
+    pub fn tag(mut self, tag: AudioTag) -> Self {
+        self.parts.push(tag.to_string());
+        self
+    }
+
+    /// Append spoken text
+    pub fn text<S: AsRef<str>>(mut self, text: S) -> Self {
+        let text = text.as_ref().trim();
+        if !text.is_empty() {
+            self.parts.push(text.to_string());
+        }
+        self
+    }
+
+    /// Append spoken text delivered with the given tag, e.g. `[excited] We won!`
+    pub fn tagged<S: AsRef<str>>(self, tag: AudioTag, text: S) -> Self {
+        self.tag(tag).text(text)
+    }
+}
+
+impl fmt::Display for TaggedText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.parts.join(" "))
+    }
+}
+
+// Lets `text_to_speech()` and dialogue turns take a `TaggedText` directly
+impl From<TaggedText> for String {
+    fn from(text: TaggedText) -> Self {
+        text.to_string()
+    }
+}
@@ -0,0 +1,347 @@
+//! Whole-book narration: one audio file per chapter plus a JSON manifest
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::long_form::{LongFormBuilder, StitchedSession};
+use crate::{ElevenLabsTTSError, TextToSpeechBuilder, audio, cost};
+
+/// File name of the manifest written next to the chapter files
+pub const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// A chapter of an audiobook
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub title: String,
+    pub text: String,
+}
+
+impl Chapter {
+    pub fn new<T: Into<String>, S: Into<String>>(title: T, text: S) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+        }
+    }
+}
+
+impl<T: Into<String>, S: Into<String>> From<(T, S)> for Chapter {
+    fn from((title, text): (T, S)) -> Self {
+        Self::new(title, text)
+    }
+}
+
+/// What was generated for an audiobook, saved as `manifest.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudiobookManifest {
+    pub voice_id: Option<String>,
+    pub model_id: String,
+    pub output_format: String,
+    pub seed: Option<u32>,
+    pub chapters: Vec<ChapterManifest>,
+
+    /// Characters billed across every chapter
+    pub total_characters: u64,
+
+    /// Length of the whole book, when every chapter's duration is known
+    pub total_duration_seconds: Option<f64>,
+}
+
+/// One generated chapter in an [`AudiobookManifest`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterManifest {
+    pub title: String,
+
+    /// File name, relative to the output directory
+    pub file: String,
+
+    /// Length of the chapter audio, when it can be computed for the format
+    pub duration_seconds: Option<f64>,
+
+    /// Characters billed for the chapter (estimated locally when the API did not report it)
+    pub characters: u64,
+
+    /// Request IDs of the chunks, in order
+    pub request_ids: Vec<String>,
+}
+
+/// Builder for narrating a book chapter by chapter
+///
+/// Each chapter is split into sentence-aligned chunks within the model's limit, and the
+/// chunks are generated in order with the request IDs of the preceding chunks, so the
+/// prosody carries across chunk boundaries. Every chunk uses the same voice, model,
+/// settings and seed. Each chapter is saved as its own file (`01-title.mp3`, PCM as
+/// WAV) and a [`MANIFEST_FILE_NAME`] describing the files is written at the end.
+/// Created by [`ElevenLabsTTSClient::audiobook`](crate::ElevenLabsTTSClient::audiobook).
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::voices;
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let manifest = client
+///     .audiobook("out/book", |builder| builder.voice(&voices::all_voices::RACHEL))
+///     .seed(42)
+///     .chapter("Chapter One", "It was a bright cold day in April...")
+///     .chapter("Chapter Two", "The clocks were striking thirteen...")
+///     .execute()
+///     .await?;
+/// println!("{} characters", manifest.total_characters);
+/// # Ok(())
+/// # }
+/// ```
+pub struct AudiobookBuilder {
+    template: TextToSpeechBuilder,
+    output_dir: PathBuf,
+    chapters: Vec<Chapter>,
+    max_chunk_characters: Option<usize>,
+    speak_titles: bool,
+    #[cfg(feature = "tags")]
+    id3_tags: Option<crate::tags::Id3Tags>,
+}
+
+impl AudiobookBuilder {
+    pub(crate) fn new(template: TextToSpeechBuilder, output_dir: PathBuf) -> Self {
+        Self {
+            template,
+            output_dir,
+            chapters: Vec::new(),
+            max_chunk_characters: None,
+            speak_titles: true,
+            #[cfg(feature = "tags")]
+            id3_tags: None,
+        }
+    }
+
+    /// Add a chapter
+    pub fn chapter<T: Into<String>, S: Into<String>>(mut self, title: T, text: S) -> Self {
+        self.chapters.push(Chapter::new(title, text));
+        self
+    }
+
+    /// Add chapters from [`Chapter`]s or `(title, text)` tuples
+    pub fn chapters<I, C>(mut self, chapters: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Chapter>,
+    {
+        self.chapters.extend(chapters.into_iter().map(Into::into));
+        self
+    }
+
+    /// Seed used for every chunk, for reproducible narration
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.template.seed = Some(seed);
+        self
+    }
+
+    /// Set the maximum characters per request (defaults to the model's limit)
+    pub fn max_chunk_characters(mut self, max_chunk_characters: usize) -> Self {
+        self.max_chunk_characters = Some(max_chunk_characters);
+        self
+    }
+
+    /// Read each chapter's title before its text (default `true`)
+    pub fn speak_titles(mut self, speak_titles: bool) -> Self {
+        self.speak_titles = speak_titles;
+        self
+    }
+
+    /// Tag MP3 chapter files with ID3v2 metadata (`tags` feature). The album and artist
+    /// come from `tags`; each chapter's title and track number are filled in, and the
+    /// artist defaults to the voice's name for pre-made voices.
+    #[cfg(feature = "tags")]
+    pub fn id3_tags(mut self, tags: crate::tags::Id3Tags) -> Self {
+        self.id3_tags = Some(tags);
+        self
+    }
+
+    /// The text of each chapter, split into the chunks that will be generated
+    pub fn chunks(&self) -> Vec<Vec<String>> {
+        self.chapters
+            .iter()
+            .map(|chapter| {
+                let mut builder = self.template.clone();
+                builder.text = self.chapter_text(chapter);
+                let mut long_form = LongFormBuilder::new(builder);
+                if let Some(max) = self.max_chunk_characters {
+                    long_form = long_form.max_chunk_characters(max);
+                }
+                long_form.chunks()
+            })
+            .collect()
+    }
+
+    fn chapter_text(&self, chapter: &Chapter) -> String {
+        if !self.speak_titles || chapter.title.trim().is_empty() {
+            return chapter.text.clone();
+        }
+        let title = chapter.title.trim();
+        let end = if title.ends_with(crate::long_form::SENTENCE_ENDS) {
+            ""
+        } else {
+            "."
+        };
+        format!("{}{}\n\n{}", title, end, chapter.text)
+    }
+
+    /// Generate every chapter, write the chapter files and the manifest to the output
+    /// directory, and return the manifest. Chapters are validated before anything is sent.
+    pub async fn execute(self) -> Result<AudiobookManifest, ElevenLabsTTSError> {
+        if self.chapters.is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "An audiobook needs at least one chapter".to_string(),
+            ));
+        }
+        let format = self.template.output_format.unwrap_or_default();
+        #[cfg(feature = "tags")]
+        if self.id3_tags.is_some() && format.codec() != "mp3" {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "ID3 tags need an MP3 output format, not {}",
+                format
+            )));
+        }
+        let chunks = self.chunks();
+        for (chapter, chapter_chunks) in self.chapters.iter().zip(&chunks) {
+            if chapter_chunks.is_empty() {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Chapter \"{}\" has no text",
+                    chapter.title
+                )));
+            }
+            for chunk in chapter_chunks {
+                let mut builder = self.template.clone();
+                builder.text = chunk.clone();
+                builder.validate()?;
+            }
+        }
+
+        tokio::fs::create_dir_all(&self.output_dir).await?;
+        let mut manifests = Vec::new();
+        for (index, (chapter, chapter_chunks)) in self.chapters.iter().zip(chunks).enumerate() {
+            let mut session = StitchedSession::new(self.template.clone());
+            for chunk in chapter_chunks {
+                session.generate(chunk).await?;
+            }
+
+            let characters = session
+                .clips()
+                .iter()
+                .map(|clip| {
+                    clip.response
+                        .character_cost
+                        .unwrap_or_else(|| cost::estimate_characters(&clip.text))
+                })
+                .sum();
+            let request_ids = session
+                .clips()
+                .iter()
+                .filter_map(|clip| clip.response.request_id.clone())
+                .collect();
+            let audio = audio::concat(
+                session.clips().iter().map(|clip| &clip.response.audio),
+                format,
+            )?;
+            let duration_seconds = audio::duration(&audio, format)
+                .ok()
+                .map(|duration| duration.as_secs_f64());
+
+            let file = chapter_file_name(index, &chapter.title, format);
+            let bytes = if format.codec() == "pcm" {
+                audio::wrap_wav(&audio, format.sample_rate(), format.channels())?
+            } else {
+                audio
+            };
+            #[cfg(feature = "tags")]
+            let bytes = match &self.id3_tags {
+                Some(tags) => self.chapter_tags(tags, index).apply(&bytes)?,
+                None => bytes,
+            };
+            tokio::fs::write(self.output_dir.join(&file), bytes).await?;
+
+            manifests.push(ChapterManifest {
+                title: chapter.title.clone(),
+                file,
+                duration_seconds,
+                characters,
+                request_ids,
+            });
+        }
+
+        let manifest = AudiobookManifest {
+            voice_id: self.template.voice_id.clone(),
+            model_id: self.template.resolved_model().to_string(),
+            output_format: format.to_string(),
+            seed: self.template.seed,
+            total_characters: manifests.iter().map(|chapter| chapter.characters).sum(),
+            total_duration_seconds: manifests
+                .iter()
+                .map(|chapter| chapter.duration_seconds)
+                .sum::<Option<f64>>(),
+            chapters: manifests,
+        };
+        manifest
+            .save(self.output_dir.join(MANIFEST_FILE_NAME))
+            .await?;
+        Ok(manifest)
+    }
+}
+
+#[cfg(feature = "tags")]
+impl AudiobookBuilder {
+    fn chapter_tags(&self, tags: &crate::tags::Id3Tags, index: usize) -> crate::tags::Id3Tags {
+        let mut tags = tags
+            .clone()
+            .title(self.chapters[index].title.clone())
+            .track(index as u32 + 1)
+            .total_tracks(self.chapters.len() as u32);
+        if tags.artist.is_none() {
+            let voice_id = self.template.voice_id.as_deref();
+            tags.artist = crate::voices::all()
+                .iter()
+                .find(|voice| Some(voice.voice_id) == voice_id)
+                .map(|voice| voice.name.to_string());
+        }
+        tags
+    }
+}
+
+impl AudiobookManifest {
+    /// Read a manifest written by [`AudiobookBuilder::execute`]
+    pub async fn load<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTSError> {
+        let json = tokio::fs::read(path).await?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Write the manifest as pretty-printed JSON
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTSError> {
+        tokio::fs::write(path, serde_json::to_vec_pretty(self)?).await?;
+        Ok(())
+    }
+
+    /// Length of the whole book, when every chapter's duration is known
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.total_duration_seconds.map(Duration::from_secs_f64)
+    }
+}
+
+/// `01-chapter-one.mp3`: the position keeps the files in order, the title makes them readable
+fn chapter_file_name(index: usize, title: &str, format: crate::OutputFormat) -> String {
+    let mut slug = String::new();
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug: String = slug.trim_end_matches('-').chars().take(48).collect();
+    let slug = slug.trim_end_matches('-');
+    let extension = format.file_extension();
+    if slug.is_empty() {
+        format!("{:02}.{}", index + 1, extension)
+    } else {
+        format!("{:02}-{}.{}", index + 1, slug, extension)
+    }
+}
@@ -0,0 +1,90 @@
+//! Hearing one text in several voices, for voice pickers
+
+use crate::{
+    AudioResponse, BatchBuilder, BatchJob, ElevenLabsTTSError, TextToSpeechBuilder, voices,
+};
+
+/// One voice's take on the audition text
+#[derive(Debug)]
+pub struct VoiceAudition {
+    pub voice_id: String,
+
+    /// Name of the voice, when it is one of the pre-made voices
+    pub name: Option<&'static str>,
+
+    /// The generated audio, or why this voice failed
+    pub result: Result<AudioResponse, ElevenLabsTTSError>,
+}
+
+/// Builder generating the same text with several voices concurrently
+///
+/// Each voice gets its own result, so one failing voice does not hide the others.
+/// Created by [`ElevenLabsTTSClient::compare_voices`](crate::ElevenLabsTTSClient::compare_voices).
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::voices::all_voices::{ADAM, RACHEL};
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let auditions = client
+///     .compare_voices("Welcome back! Here is today's summary.", &[RACHEL.voice_id, ADAM.voice_id])
+///     .execute()
+///     .await;
+/// for audition in auditions {
+///     let label = audition.name.unwrap_or(&audition.voice_id);
+///     match audition.result {
+///         Ok(clip) => println!("{}: {} bytes", label, clip.audio.len()),
+///         Err(error) => println!("{}: {}", label, error),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct VoiceComparison {
+    batch: BatchBuilder,
+    voice_ids: Vec<String>,
+}
+
+impl VoiceComparison {
+    pub(crate) fn new(template: TextToSpeechBuilder, text: String, voice_ids: Vec<String>) -> Self {
+        let jobs = voice_ids
+            .iter()
+            .map(|voice_id| BatchJob::new(text.clone()).voice_id(voice_id.clone()))
+            .collect();
+        Self {
+            batch: BatchBuilder::new(template, jobs),
+            voice_ids,
+        }
+    }
+
+    /// Set the maximum number of voices generated at the same time (default: 4)
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.batch = self.batch.concurrency(concurrency);
+        self
+    }
+
+    /// Configure the model, format and settings shared by every voice
+    pub fn configure<F>(mut self, configure: F) -> Self
+    where
+        F: FnOnce(TextToSpeechBuilder) -> TextToSpeechBuilder,
+    {
+        self.batch = self.batch.configure(configure);
+        self
+    }
+
+    /// Generate the text with every voice, returning one audition per voice in input order
+    pub async fn execute(self) -> Vec<VoiceAudition> {
+        let results = self.batch.execute().await;
+        self.voice_ids
+            .into_iter()
+            .zip(results)
+            .map(|(voice_id, result)| VoiceAudition {
+                name: voices::all()
+                    .iter()
+                    .find(|voice| voice.voice_id == voice_id)
+                    .map(|voice| voice.name),
+                voice_id,
+                result,
+            })
+            .collect()
+    }
+}
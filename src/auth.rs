@@ -0,0 +1,93 @@
+use std::fmt;
+
+use async_trait::async_trait;
+use reqwest::header::HeaderValue;
+#[cfg(feature = "secrecy")]
+use secrecy::{ExposeSecret, SecretString};
+
+use crate::ElevenLabsTTSError;
+
+/// Source of the API key, consulted before every request
+///
+/// Implement this to rotate keys at runtime or fetch them from a secrets manager.
+/// The default provider, [`StaticApiKey`], always returns the key given to the client.
+///
+/// ```rust
+/// use elevenlabs_tts::{ApiKeyProvider, ElevenLabsTTSError, async_trait};
+///
+/// struct FromVault;
+///
+/// #[async_trait]
+/// impl ApiKeyProvider for FromVault {
+///     async fn get_key(&self) -> Result<String, ElevenLabsTTSError> {
+///         // e.g. read from a secrets manager, with caching
+///         Ok("rotated-key".to_string())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait ApiKeyProvider: Send + Sync {
+    /// Return the API key to use for the next request
+    async fn get_key(&self) -> Result<String, ElevenLabsTTSError>;
+}
+
+/// A fixed API key
+///
+/// The key never appears in `Debug` output. With the `secrecy` feature it is held in a
+/// [`SecretString`](secrecy::SecretString), which wipes it from memory when dropped.
+#[derive(Clone)]
+pub struct StaticApiKey {
+    #[cfg(feature = "secrecy")]
+    key: SecretString,
+    #[cfg(not(feature = "secrecy"))]
+    key: String,
+}
+
+impl StaticApiKey {
+    /// Wrap a fixed API key
+    pub fn new<S: Into<String>>(api_key: S) -> Self {
+        Self {
+            #[cfg(feature = "secrecy")]
+            key: SecretString::from(api_key.into()),
+            #[cfg(not(feature = "secrecy"))]
+            key: api_key.into(),
+        }
+    }
+
+    /// The key itself, for the request header
+    pub(crate) fn expose(&self) -> &str {
+        #[cfg(feature = "secrecy")]
+        return self.key.expose_secret();
+        #[cfg(not(feature = "secrecy"))]
+        return &self.key;
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl From<SecretString> for StaticApiKey {
+    fn from(key: SecretString) -> Self {
+        Self { key }
+    }
+}
+
+impl fmt::Debug for StaticApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StaticApiKey([REDACTED])")
+    }
+}
+
+#[async_trait]
+impl ApiKeyProvider for StaticApiKey {
+    async fn get_key(&self) -> Result<String, ElevenLabsTTSError> {
+        Ok(self.expose().to_string())
+    }
+}
+
+/// The `xi-api-key` header value, marked sensitive so HTTP debug logging hides it
+pub(crate) fn api_key_header(api_key: &str) -> Result<HeaderValue, ElevenLabsTTSError> {
+    let mut value = HeaderValue::from_str(api_key).map_err(|_| {
+        ElevenLabsTTSError::ValidationError("API key is not a valid header value".to_string())
+    })?;
+    value.set_sensitive(true);
+    Ok(value)
+}
@@ -0,0 +1,177 @@
+use futures_util::{StreamExt, stream};
+
+use crate::retry::{self, RetryEvent};
+use crate::{
+    AudioResponse, ElevenLabsTTSError, RetryPolicy, StaticVoice, TextToSpeechBuilder, VoiceSettings,
+};
+
+/// Number of jobs generated at the same time unless configured
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A single text-to-speech job in a batch
+#[derive(Debug, Clone)]
+pub struct BatchJob {
+    /// The text to convert to speech
+    pub text: String,
+
+    /// Voice for this job, overriding the batch's voice
+    pub voice_id: Option<String>,
+
+    /// Voice settings for this job, overriding the batch's settings
+    pub voice_settings: Option<VoiceSettings>,
+}
+
+impl BatchJob {
+    /// Create a job using the batch's voice and settings
+    pub fn new<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: text.into(),
+            voice_id: None,
+            voice_settings: None,
+        }
+    }
+
+    /// Set the voice to use (accepts StaticVoice reference)
+    pub fn voice(mut self, voice: &StaticVoice) -> Self {
+        self.voice_id = Some(voice.voice_id.to_string());
+        self
+    }
+
+    /// Set the voice ID to use
+    pub fn voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Set the voice settings to use
+    pub fn voice_settings(mut self, voice_settings: VoiceSettings) -> Self {
+        self.voice_settings = Some(voice_settings);
+        self
+    }
+}
+
+impl From<&str> for BatchJob {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for BatchJob {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl<T: Into<String>> From<(T, &StaticVoice)> for BatchJob {
+    fn from((text, voice): (T, &StaticVoice)) -> Self {
+        Self::new(text).voice(voice)
+    }
+}
+
+impl<T: Into<String>> From<(T, &StaticVoice, VoiceSettings)> for BatchJob {
+    fn from((text, voice, voice_settings): (T, &StaticVoice, VoiceSettings)) -> Self {
+        Self::new(text).voice(voice).voice_settings(voice_settings)
+    }
+}
+
+/// Builder for generating many clips with bounded concurrency
+///
+/// Results come back in the order of the jobs, each with its own error, so one
+/// failed clip never loses the rest of the batch. Created by [`ElevenLabsTTSClient::batch`].
+///
+/// [`ElevenLabsTTSClient::batch`]: crate::ElevenLabsTTSClient::batch
+pub struct BatchBuilder {
+    template: TextToSpeechBuilder,
+    jobs: Vec<BatchJob>,
+    concurrency: usize,
+    retry_policy: RetryPolicy,
+}
+
+impl BatchBuilder {
+    pub(crate) fn new(template: TextToSpeechBuilder, jobs: Vec<BatchJob>) -> Self {
+        Self {
+            template,
+            jobs,
+            concurrency: DEFAULT_CONCURRENCY,
+            retry_policy: RetryPolicy::none(),
+        }
+    }
+
+    /// Append a job
+    pub fn job<J: Into<BatchJob>>(mut self, job: J) -> Self {
+        self.jobs.push(job.into());
+        self
+    }
+
+    /// Set the maximum number of jobs generated at the same time (default: 4)
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Retry a failed job as a whole according to this policy (default: no retries).
+    /// This comes on top of the client's own retry policy for each HTTP attempt.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Configure the voice, model and settings shared by every job
+    pub fn configure<F>(mut self, configure: F) -> Self
+    where
+        F: FnOnce(TextToSpeechBuilder) -> TextToSpeechBuilder,
+    {
+        self.template = configure(self.template);
+        self
+    }
+
+    /// Request builder for a single job
+    fn builder_for(&self, job: BatchJob) -> TextToSpeechBuilder {
+        let mut builder = self.template.clone();
+        builder.text = job.text;
+        if job.voice_id.is_some() {
+            builder.voice_id = job.voice_id;
+        }
+        if job.voice_settings.is_some() {
+            builder.voice_settings = job.voice_settings;
+        }
+        builder
+    }
+
+    /// Run a job, retrying it while the batch's retry policy allows
+    async fn run(&self, builder: TextToSpeechBuilder) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let mut attempt = 1;
+        loop {
+            match builder.clone().execute_detailed().await {
+                Err(error)
+                    if attempt < self.retry_policy.max_attempts
+                        && self.retry_policy.should_retry(&error) =>
+                {
+                    let delay = self.retry_policy.delay_after(attempt, &error);
+                    self.retry_policy.notify(RetryEvent {
+                        endpoint: "text-to-speech",
+                        attempt,
+                        delay,
+                        status: error.status_code(),
+                        retry_after: retry::retry_after(&error),
+                    });
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Generate every job, returning one result per job in input order
+    pub async fn execute(mut self) -> Vec<Result<AudioResponse, ElevenLabsTTSError>> {
+        let jobs = std::mem::take(&mut self.jobs);
+        let builders: Vec<_> = jobs.into_iter().map(|job| self.builder_for(job)).collect();
+        let this = &self;
+        stream::iter(builders)
+            .map(|builder| this.run(builder))
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+}
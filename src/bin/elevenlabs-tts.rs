@@ -0,0 +1,211 @@
+//! Command-line text-to-speech (`cli` feature)
+//!
+//! ```text
+//! elevenlabs-tts speak "Hello there" --voice rachel --out clip.mp3
+//! elevenlabs-tts speak "Hello there" --stream      # play while generating (`playback` feature)
+//! elevenlabs-tts voices list --gender female
+//! elevenlabs-tts models list
+//! ```
+//!
+//! The API key is read from `--api-key` or `ELEVENLABS_API_KEY`.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use elevenlabs_tts::{ElevenLabsTTSClient, ElevenLabsTTSError, OutputFormat, models, voices};
+use tokio::io::AsyncWriteExt;
+
+#[derive(Parser)]
+#[command(
+    name = "elevenlabs-tts",
+    version,
+    about = "ElevenLabs text-to-speech from the command line"
+)]
+struct Cli {
+    /// ElevenLabs API key
+    #[arg(
+        long,
+        env = "ELEVENLABS_API_KEY",
+        hide_env_values = true,
+        global = true
+    )]
+    api_key: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert text to speech
+    Speak {
+        /// The text to speak
+        text: String,
+
+        /// Voice name (e.g. `rachel`) or voice ID
+        #[arg(long, short, default_value = "rachel")]
+        voice: String,
+
+        /// Model ID, e.g. `eleven_flash_v2_5`
+        #[arg(long, short)]
+        model: Option<String>,
+
+        /// Output format, e.g. `mp3_44100_128` or `pcm_24000`
+        #[arg(long, short)]
+        format: Option<OutputFormat>,
+
+        /// Save the audio to this file instead of writing it to stdout
+        #[arg(long, short, conflicts_with = "stream")]
+        out: Option<PathBuf>,
+
+        /// Play the audio on the default output device while it is generated
+        #[arg(long)]
+        stream: bool,
+    },
+
+    /// Pre-made voices
+    Voices {
+        #[command(subcommand)]
+        command: VoicesCommand,
+    },
+
+    /// Known models
+    Models {
+        #[command(subcommand)]
+        command: ModelsCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum VoicesCommand {
+    /// List the pre-made voices
+    List {
+        /// Only voices of this gender (`male`, `female`)
+        #[arg(long)]
+        gender: Option<String>,
+
+        /// Only voices with this accent (e.g. `british`)
+        #[arg(long)]
+        accent: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ModelsCommand {
+    /// List the known models with their limits
+    List,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), ElevenLabsTTSError> {
+    match cli.command {
+        Command::Speak {
+            text,
+            voice,
+            model,
+            format,
+            out,
+            stream,
+        } => {
+            let api_key = cli.api_key.filter(|key| !key.trim().is_empty());
+            let api_key = api_key.ok_or_else(|| {
+                ElevenLabsTTSError::ValidationError(
+                    "Set ELEVENLABS_API_KEY or pass --api-key".to_string(),
+                )
+            })?;
+            let client = ElevenLabsTTSClient::new(api_key);
+            let voice_id = voices::find_by_name(&voice)
+                .map_or(voice.as_str(), |voice| voice.voice_id)
+                .to_string();
+            let mut builder = client.text_to_speech(text).voice_id(voice_id);
+            if let Some(model) = model {
+                builder = builder.model(model);
+            }
+            if let Some(format) = format {
+                builder = builder.output_format(format);
+            }
+
+            if stream {
+                return play_stream(builder).await;
+            }
+            match out {
+                Some(path) => {
+                    let bytes = builder.execute_to_file(&path).await?;
+                    eprintln!("Saved {} bytes to {}", bytes, path.display());
+                }
+                None => {
+                    let mut stdout = tokio::io::stdout();
+                    builder.execute_to_writer(&mut stdout).await?;
+                    stdout.flush().await?;
+                }
+            }
+        }
+        Command::Voices {
+            command: VoicesCommand::List { gender, accent },
+        } => {
+            let matches = |filter: &Option<String>, value: &str| {
+                filter
+                    .as_ref()
+                    .is_none_or(|filter| filter.eq_ignore_ascii_case(value))
+            };
+            println!(
+                "{:<12} {:<22} {:<8} {:<12} USE CASE",
+                "NAME", "ID", "GENDER", "ACCENT"
+            );
+            for voice in voices::all() {
+                if matches(&gender, voice.gender) && matches(&accent, voice.accent) {
+                    println!(
+                        "{:<12} {:<22} {:<8} {:<12} {}",
+                        voice.name, voice.voice_id, voice.gender, voice.accent, voice.use_case
+                    );
+                }
+            }
+        }
+        Command::Models {
+            command: ModelsCommand::List,
+        } => {
+            println!("{:<28} {:>10} {:>12}", "MODEL", "MAX CHARS", "CREDITS/CHAR");
+            for model in models::ModelId::KNOWN {
+                let known = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+                println!(
+                    "{:<28} {:>10} {:>12}",
+                    model.as_str(),
+                    known(model.max_characters().map(|max| max.to_string())),
+                    known(
+                        model
+                            .credits_per_character()
+                            .map(|credits| credits.to_string())
+                    ),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "playback")]
+async fn play_stream(
+    builder: elevenlabs_tts::TextToSpeechBuilder,
+) -> Result<(), ElevenLabsTTSError> {
+    builder.play_stream().await?.wait().await
+}
+
+#[cfg(not(feature = "playback"))]
+async fn play_stream(_: elevenlabs_tts::TextToSpeechBuilder) -> Result<(), ElevenLabsTTSError> {
+    Err(ElevenLabsTTSError::ValidationError(
+        "--stream needs the `playback` feature: cargo install elevenlabs_tts --features cli,playback"
+            .to_string(),
+    ))
+}
@@ -0,0 +1,483 @@
+//! Blocking client for CLI tools and scripts that don't run a tokio runtime
+//!
+//! Enabled by the `blocking` feature. It mirrors the async client's builders and
+//! shares its retry policy, circuit breaker and metrics hooks.
+//!
+//! ```rust,no_run
+//! use elevenlabs_tts::{blocking::ElevenLabsTTSClientBlocking, voices};
+//!
+//! # fn main() -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+//! let client = ElevenLabsTTSClientBlocking::from_env()?;
+//! let audio = client
+//!     .text_to_speech("Hello from a script!")
+//!     .voice(&voices::all_voices::RACHEL)
+//!     .execute()?;
+//! std::fs::write("hello.mp3", audio).unwrap();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Don't create or use this client from inside an async runtime; use
+//! [`ElevenLabsTTSClient`](crate::ElevenLabsTTSClient) there instead.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::Proxy;
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+
+use crate::circuit_breaker::CircuitBreaker;
+use crate::{
+    API_KEY_ENV, AudioResponse, BASE_URL_ENV, CircuitBreakerConfig, CircuitState, DEFAULT_BASE_URL,
+    DEFAULT_USER_AGENT, ElevenLabsTTSError, MetricsObserver, OutputFormat, RequestDefaults,
+    RequestOptions, RetryEvent, RetryPolicy, StaticApiKey, StaticVoice, TTSRequest,
+    TextToSpeechBuilder, UsageTracker, VoiceSettings, auth, error, header_map, metrics,
+    models::ModelId,
+};
+
+/// Blocking client for interacting with ElevenLabs API
+#[derive(Clone)]
+pub struct ElevenLabsTTSClientBlocking {
+    client: Client,
+    api_key: StaticApiKey,
+    base_url: String,
+    retry_policy: RetryPolicy,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    default_headers: HeaderMap,
+    defaults: RequestDefaults,
+    usage: Option<UsageTracker>,
+}
+
+// Only the base URL is shown, so logging a client never leaks the API key
+impl fmt::Debug for ElevenLabsTTSClientBlocking {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElevenLabsTTSClientBlocking")
+            .field("base_url", &self.base_url)
+            .field("api_key", &"[REDACTED]")
+            .finish_non_exhaustive()
+    }
+}
+
+impl ElevenLabsTTSClientBlocking {
+    /// Create a new blocking client with API key
+    pub fn new<S: Into<String>>(api_key: S) -> Result<Self, ElevenLabsTTSError> {
+        Self::builder().api_key(api_key).build()
+    }
+
+    /// Create a new blocking client from the `ELEVENLABS_API_KEY` environment variable,
+    /// honoring `ELEVENLABS_BASE_URL` when it is set
+    pub fn from_env() -> Result<Self, ElevenLabsTTSError> {
+        let api_key = std::env::var(API_KEY_ENV)
+            .ok()
+            .filter(|key| !key.trim().is_empty())
+            .ok_or_else(|| {
+                ElevenLabsTTSError::ValidationError(format!(
+                    "{} environment variable is not set",
+                    API_KEY_ENV
+                ))
+            })?;
+
+        let mut builder = Self::builder().api_key(api_key);
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            if !base_url.trim().is_empty() {
+                builder = builder.base_url(base_url);
+            }
+        }
+
+        builder.build()
+    }
+
+    /// Start building a blocking client with HTTP configuration
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Current state of the circuit breaker, if one is configured
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.state())
+    }
+
+    /// Start building a text-to-speech request
+    pub fn text_to_speech<S: Into<String>>(&self, text: S) -> TextToSpeechBuilder<Self> {
+        TextToSpeechBuilder::new(self.clone(), text.into()).with_defaults(&self.defaults)
+    }
+
+    /// Internal method to execute TTS request
+    fn execute_tts(
+        &self,
+        request: TTSRequest,
+        options: &RequestOptions,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        if let Some(usage) = &self.usage {
+            usage.charge(&[(&request.voice_id, &request.model_id, &request.text)])?;
+        }
+        let url = format!("{}/text-to-speech/{}", self.base_url, request.voice_id);
+        self.post_for_audio("text-to-speech", &url, &request.query(), &request, options)
+    }
+
+    /// Internal method to POST a JSON body and collect the audio response
+    fn post_for_audio<B: Serialize>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let mut request = self
+            .client
+            .post(url)
+            .headers(self.default_headers.clone())
+            .headers(header_map(&options.headers)?)
+            .query(query)
+            .header("Content-Type", "application/json")
+            .json(body);
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let response = self.send(endpoint, request)?;
+        let headers = response.headers().clone();
+        let audio = response.bytes()?;
+        if let Some(metrics) = &self.metrics {
+            metrics.bytes_received(endpoint, audio.len());
+        }
+        Ok(AudioResponse::from_parts(audio.to_vec(), &headers))
+    }
+
+    /// Internal method to authenticate and send a request, retrying transient failures
+    /// according to the retry policy and mapping error statuses to errors
+    fn send(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<Response, ElevenLabsTTSError> {
+        let api_key = auth::api_key_header(self.api_key.expose())?;
+        let mut pending = Some(request.header("xi-api-key", api_key));
+        let mut attempt = 1;
+
+        loop {
+            // Bodies that can't be cloned are sent once, without retries
+            let (current, retryable) = match pending.as_ref().and_then(|r| r.try_clone()) {
+                Some(current) => (current, attempt < self.retry_policy.max_attempts),
+                None => (pending.take().expect("request is sent once"), false),
+            };
+
+            let permit = self
+                .circuit_breaker
+                .as_ref()
+                .map(|breaker| breaker.before_request())
+                .transpose()?;
+
+            let result = self.send_attempt(endpoint, current);
+            if let Some(permit) = permit {
+                match &result {
+                    Ok(r) if CircuitBreaker::is_failure_status(r.status().as_u16()) => {
+                        permit.record_failure()
+                    }
+                    Err(e) if error::request_error_is_transient(e) => permit.record_failure(),
+                    _ => permit.record_success(),
+                }
+            }
+
+            let policy = &self.retry_policy;
+            let (status, retry_after) = match result {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    if !(retryable && policy.should_retry_status(status)) {
+                        let headers = response.headers().clone();
+                        let body = response.text().unwrap_or_default();
+                        return Err(ElevenLabsTTSError::from_response_parts(
+                            status, &headers, body,
+                        ));
+                    }
+                    let retry_after = error::parse_retry_after(response.headers());
+                    (Some(status), retry_after.map(Duration::from_secs))
+                }
+                Err(error) => {
+                    if !(retryable && policy.should_retry_error(&error)) {
+                        return Err(error.into());
+                    }
+                    (None, None)
+                }
+            };
+
+            let delay = policy.retry_delay(attempt, retry_after);
+            policy.notify(RetryEvent {
+                endpoint,
+                attempt,
+                delay,
+                status,
+                retry_after,
+            });
+            std::thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    /// Internal method to send a single HTTP attempt, reporting it to the metrics observer
+    fn send_attempt(
+        &self,
+        endpoint: &'static str,
+        request: RequestBuilder,
+    ) -> Result<Response, reqwest::Error> {
+        let Some(metrics) = &self.metrics else {
+            return request.send();
+        };
+
+        metrics.request_started(endpoint);
+        let started = Instant::now();
+        let result = request.send();
+
+        let status = result.as_ref().ok().map(|r| r.status().as_u16());
+        metrics.request_finished(endpoint, status, started.elapsed());
+        if let Some(characters) = result
+            .as_ref()
+            .ok()
+            .filter(|r| r.status().is_success())
+            .and_then(|r| metrics::character_cost(r.headers()))
+        {
+            metrics.characters_billed(endpoint, characters);
+        }
+
+        result
+    }
+}
+
+impl TextToSpeechBuilder<ElevenLabsTTSClientBlocking> {
+    /// Execute the text-to-speech request, blocking until the whole audio is received
+    pub fn execute(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        Ok(client.execute_tts(request, &options)?.audio)
+    }
+
+    /// Execute the text-to-speech request and keep the response metadata
+    /// (request ID for stitching, history item ID, character cost, content type)
+    pub fn execute_detailed(self) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        client.execute_tts(request, &options)
+    }
+}
+
+/// Builder for [`ElevenLabsTTSClientBlocking`]
+#[derive(Default)]
+pub struct ClientBuilder {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    pool_max_idle_per_host: Option<usize>,
+    tcp_keepalive: Option<Duration>,
+    proxies: Vec<Proxy>,
+    http_client: Option<Client>,
+    retry_policy: Option<RetryPolicy>,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    default_headers: Vec<(String, String)>,
+    user_agent: Option<String>,
+    user_agent_suffix: Vec<String>,
+    defaults: RequestDefaults,
+    usage: Option<UsageTracker>,
+}
+
+impl ClientBuilder {
+    /// Set the API key
+    pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
+        self.api_key = Some(api_key.into()).filter(|key| !key.trim().is_empty());
+        self
+    }
+
+    /// Set a custom base URL (for testing/enterprise)
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the total timeout for each request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing connections
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Close pooled connections idle for longer than this (`None` keeps them open indefinitely)
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum number of idle connections kept warm per host
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Send TCP keepalive probes on open connections at this interval
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Route requests through a proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Use a pre-configured `reqwest::blocking::Client`.
+    /// HTTP options set on this builder are ignored when a client is provided.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Retry failures with exponential backoff. By default only failures the API never
+    /// billed are retried (429, 503 and connections that couldn't be established); see
+    /// [`RetryPolicy::retry_undetermined`] to also retry 5xx responses, resets and timeouts
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Report request lifecycle, latency, bytes and billed characters to an observer
+    pub fn metrics_observer<M: MetricsObserver + 'static>(mut self, observer: M) -> Self {
+        self.metrics = Some(Arc::new(observer));
+        self
+    }
+
+    /// Fail fast during API outages: open the circuit after consecutive failures
+    /// and only let probe requests through until the API recovers
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Attach a header to every outbound request.
+    /// Request builders can override it with their own `.header()`.
+    pub fn default_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Replace the User-Agent header (defaults to `elevenlabs_tts-rs/<crate version>`)
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Append your integration to the User-Agent, e.g. `("my-app", "1.4.0")`
+    pub fn app_user_agent<N: AsRef<str>, V: AsRef<str>>(mut self, app_name: N, version: V) -> Self {
+        self.user_agent_suffix
+            .push(format!("{}/{}", app_name.as_ref(), version.as_ref()));
+        self
+    }
+
+    /// Voice used by requests that don't set one (instead of Rachel)
+    pub fn default_voice(mut self, voice: &StaticVoice) -> Self {
+        self.defaults.voice_id = Some(voice.voice_id.to_string());
+        self
+    }
+
+    /// Voice ID used by requests that don't set one (for custom voices)
+    pub fn default_voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.defaults.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Text-to-speech model used by requests that don't set one
+    pub fn default_model<M: Into<ModelId>>(mut self, model_id: M) -> Self {
+        self.defaults.model_id = Some(model_id.into().to_string());
+        self
+    }
+
+    /// Output format used by requests that don't set one
+    pub fn default_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.defaults.output_format = Some(output_format);
+        self
+    }
+
+    /// Voice settings used by requests that don't set their own
+    pub fn default_voice_settings(mut self, settings: VoiceSettings) -> Self {
+        self.defaults.voice_settings = Some(settings);
+        self
+    }
+
+    /// Count the characters of every request, refusing requests once the tracker's
+    /// daily or monthly budget is spent
+    pub fn usage_tracker(mut self, tracker: UsageTracker) -> Self {
+        self.usage = Some(tracker);
+        self
+    }
+
+    /// Build the blocking client
+    pub fn build(self) -> Result<ElevenLabsTTSClientBlocking, ElevenLabsTTSError> {
+        let api_key = self.api_key.ok_or_else(|| {
+            ElevenLabsTTSError::ValidationError("API key is required".to_string())
+        })?;
+
+        // The User-Agent goes first so an explicit default_header("user-agent", ..) still wins
+        let user_agent = std::iter::once(
+            self.user_agent
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+        )
+        .chain(self.user_agent_suffix)
+        .collect::<Vec<_>>()
+        .join(" ");
+        let mut default_headers = vec![("user-agent".to_string(), user_agent)];
+        default_headers.extend(self.default_headers);
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(timeout);
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(interval) = self.tcp_keepalive {
+                    builder = builder.tcp_keepalive(interval);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(ElevenLabsTTSClientBlocking {
+            client,
+            api_key: StaticApiKey::new(api_key),
+            base_url: self
+                .base_url
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            metrics: self.metrics,
+            circuit_breaker: self
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config))),
+            default_headers: header_map(&default_headers)?,
+            defaults: self.defaults,
+            usage: self.usage,
+        })
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::ElevenLabsTTSError;
+
+/// Storage for generated audio, keyed on a hash of the full request
+///
+/// When a cache is configured with [`ClientBuilder::cache`], non-streaming audio requests
+/// whose URL, query and body match an earlier one are answered from the cache, without
+/// calling the API or billing characters. Caching is best effort: a failing cache never
+/// fails the request.
+///
+/// [`ClientBuilder::cache`]: crate::ClientBuilder::cache
+#[async_trait]
+pub trait TtsCache: Send + Sync {
+    /// Audio stored under `key`, if any
+    async fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Store the audio generated for `key`
+    async fn put(&self, key: &str, audio: &[u8]);
+}
+
+// Lets callers keep a handle to their cache after passing it to the client builder
+#[async_trait]
+impl<T: TtsCache + ?Sized> TtsCache for Arc<T> {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        (**self).get(key).await
+    }
+
+    async fn put(&self, key: &str, audio: &[u8]) {
+        (**self).put(key, audio).await
+    }
+}
+
+/// Hex-encoded SHA-256 of everything that determines the generated audio
+pub(crate) fn cache_key<B: Serialize>(
+    url: &str,
+    query: &[(&str, String)],
+    body: &B,
+) -> Result<String, ElevenLabsTTSError> {
+    let mut hasher = Sha256::new();
+    hasher.update(url.as_bytes());
+    for (name, value) in query {
+        hasher.update(b"\n");
+        hasher.update(name.as_bytes());
+        hasher.update(b"=");
+        hasher.update(value.as_bytes());
+    }
+    hasher.update(b"\n");
+    hasher.update(serde_json::to_vec(body)?);
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+/// In-memory cache keeping the most recently used entries
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Mutex<LruEntries>,
+}
+
+#[derive(Default)]
+struct LruEntries {
+    audio: HashMap<String, Vec<u8>>,
+    // Least recently used first
+    order: VecDeque<String>,
+}
+
+impl LruEntries {
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|entry| entry == key) {
+            let key = self.order.remove(position).unwrap_or_default();
+            self.order.push_back(key);
+        }
+    }
+}
+
+impl MemoryCache {
+    /// Create a cache holding at most `capacity` clips
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(LruEntries::default()),
+        }
+    }
+
+    /// Number of cached clips
+    pub fn len(&self) -> usize {
+        self.lock().audio.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruEntries> {
+        // A panic while holding the lock can't leave the maps inconsistent enough to matter
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl TtsCache for MemoryCache {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut entries = self.lock();
+        let audio = entries.audio.get(key).cloned()?;
+        entries.touch(key);
+        Some(audio)
+    }
+
+    async fn put(&self, key: &str, audio: &[u8]) {
+        let mut entries = self.lock();
+        if entries
+            .audio
+            .insert(key.to_string(), audio.to_vec())
+            .is_some()
+        {
+            entries.touch(key);
+            return;
+        }
+        entries.order.push_back(key.to_string());
+        while entries.order.len() > self.capacity {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.audio.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Cache storing one file per clip in a directory, shared across runs
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+impl DiskCache {
+    /// Store clips in `directory`, created on first write
+    pub fn new<P: Into<PathBuf>>(directory: P) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{}.audio", key))
+    }
+}
+
+#[async_trait]
+impl TtsCache for DiskCache {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        tokio::fs::read(self.path(key)).await.ok()
+    }
+
+    async fn put(&self, key: &str, audio: &[u8]) {
+        let path = self.path(key);
+        let partial = path.with_extension("partial");
+        // Write then rename, so readers never see a half-written clip
+        let written = async {
+            tokio::fs::create_dir_all(&self.directory).await?;
+            tokio::fs::write(&partial, audio).await?;
+            tokio::fs::rename(&partial, &path).await
+        }
+        .await;
+        if written.is_err() {
+            let _ = tokio::fs::remove_file(&partial).await;
+        }
+    }
+}
+
+/// A value fetched from the API (models list, subscription) and reused until it expires
+pub(crate) struct Expiring<T> {
+    ttl: Duration,
+    value: tokio::sync::Mutex<Option<(Instant, T)>>,
+}
+
+impl<T: Clone> Expiring<T> {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            value: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// The cached value, calling `fetch` when there is none or it has expired.
+    /// Concurrent callers wait for a single fetch; failed fetches are not cached.
+    pub(crate) async fn get<F, Fut>(&self, fetch: F) -> Result<T, ElevenLabsTTSError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, ElevenLabsTTSError>>,
+    {
+        let mut value = self.value.lock().await;
+        if let Some((fetched_at, cached)) = value.as_ref() {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(cached.clone());
+            }
+        }
+        let fetched = fetch().await?;
+        *value = Some((Instant::now(), fetched.clone()));
+        Ok(fetched)
+    }
+
+    /// Adjust the cached value, if there is one, without refreshing it
+    pub(crate) async fn update<F: FnOnce(&mut T)>(&self, update: F) {
+        if let Some((_, cached)) = self.value.lock().await.as_mut() {
+            update(cached);
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::ElevenLabsTTSError;
+
+/// Configuration for the optional circuit breaker
+///
+/// After `failure_threshold` consecutive failures (connection errors or 5xx responses)
+/// the circuit opens and requests fail immediately with
+/// [`ElevenLabsTTSError::CircuitOpenError`]. Once `reset_timeout` has elapsed, up to
+/// `half_open_probes` requests are let through; a success closes the circuit again,
+/// a failure re-opens it.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures needed to open the circuit
+    pub failure_threshold: u32,
+
+    /// How long the circuit stays open before probing the API again
+    pub reset_timeout: Duration,
+
+    /// Number of concurrent probe requests allowed while half-open
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            reset_timeout: Duration::from_secs(30),
+            half_open_probes: 1,
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Create a config opening after the given number of consecutive failures
+    pub fn new(failure_threshold: u32) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Set how long the circuit stays open before probing again
+    pub fn reset_timeout(mut self, reset_timeout: Duration) -> Self {
+        self.reset_timeout = reset_timeout;
+        self
+    }
+
+    /// Set the number of concurrent probe requests while half-open
+    pub fn half_open_probes(mut self, probes: u32) -> Self {
+        self.half_open_probes = probes.max(1);
+        self
+    }
+}
+
+/// Current state of the circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests fail fast
+    Open,
+    /// A limited number of probe requests are let through
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { in_flight: u32 },
+}
+
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<State>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    pub(crate) fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() < until => CircuitState::Open,
+            State::Open { .. } | State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Ask permission to send a request. The permit records the outcome; dropped without
+    /// one (e.g. the request future was cancelled), a half-open probe frees its slot.
+    pub(crate) fn before_request(&self) -> Result<Permit<'_>, ElevenLabsTTSError> {
+        let mut state = self.state.lock().unwrap();
+        let probe = match *state {
+            State::Closed { .. } => false,
+            State::Open { until } => {
+                let now = Instant::now();
+                if now < until {
+                    return Err(ElevenLabsTTSError::CircuitOpenError {
+                        retry_after: (until - now).as_secs_f64().ceil() as u64,
+                    });
+                }
+                *state = State::HalfOpen { in_flight: 1 };
+                true
+            }
+            State::HalfOpen { in_flight } => {
+                if in_flight >= self.config.half_open_probes {
+                    return Err(ElevenLabsTTSError::CircuitOpenError { retry_after: 0 });
+                }
+                *state = State::HalfOpen {
+                    in_flight: in_flight + 1,
+                };
+                true
+            }
+        };
+        Ok(Permit {
+            breaker: self,
+            probe,
+        })
+    }
+
+    fn record_success(&self) {
+        *self.state.lock().unwrap() = State::Closed { failures: 0 };
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        let open = State::Open {
+            until: Instant::now() + self.config.reset_timeout,
+        };
+        *state = match *state {
+            State::Closed { failures } if failures + 1 < self.config.failure_threshold => {
+                State::Closed {
+                    failures: failures + 1,
+                }
+            }
+            _ => open,
+        };
+    }
+
+    /// Give back the slot of a probe that ended without an outcome
+    fn release_probe(&self) {
+        if let State::HalfOpen { in_flight } = &mut *self.state.lock().unwrap() {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+    }
+
+    /// Whether a response status means the API itself is degraded
+    pub(crate) fn is_failure_status(status: u16) -> bool {
+        matches!(status, 500 | 502 | 503 | 504)
+    }
+}
+
+/// Permission to send one request, from [`CircuitBreaker::before_request`]
+#[must_use]
+pub(crate) struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+}
+
+impl Permit<'_> {
+    pub(crate) fn record_success(mut self) {
+        self.probe = false;
+        self.breaker.record_success();
+    }
+
+    pub(crate) fn record_failure(mut self) {
+        self.probe = false;
+        self.breaker.record_failure();
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            self.breaker.release_probe();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_after_threshold_and_probes_after_timeout() {
+        let breaker =
+            CircuitBreaker::new(CircuitBreakerConfig::new(2).reset_timeout(Duration::ZERO));
+
+        breaker.before_request().unwrap().record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.before_request().unwrap().record_failure();
+
+        // Reset timeout is zero, so the next request is a half-open probe
+        let probe = breaker.before_request().unwrap();
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(matches!(
+            breaker.before_request(),
+            Err(ElevenLabsTTSError::CircuitOpenError { .. })
+        ));
+
+        probe.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_dropped_probe_frees_its_slot() {
+        let breaker =
+            CircuitBreaker::new(CircuitBreakerConfig::new(1).reset_timeout(Duration::ZERO));
+        breaker.before_request().unwrap().record_failure();
+
+        // A cancelled probe leaves no outcome, so the next request probes instead
+        drop(breaker.before_request().unwrap());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        let probe = breaker.before_request().unwrap();
+        probe.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_open_circuit_fails_fast() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig::new(1));
+        breaker.before_request().unwrap().record_failure();
+
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            breaker.before_request(),
+            Err(ElevenLabsTTSError::CircuitOpenError { retry_after }) if retry_after > 0
+        ));
+    }
+}
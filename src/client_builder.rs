@@ -0,0 +1,421 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Certificate, Client, NoProxy, Proxy};
+use tokio::sync::Semaphore;
+
+use crate::cache::Expiring;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::models::{MODEL_CACHE_TTL, ModelId};
+use crate::subscription::SUBSCRIPTION_CACHE_TTL;
+use crate::{
+    ApiKeyProvider, CircuitBreakerConfig, DEFAULT_BASE_URL, DEFAULT_USER_AGENT,
+    ElevenLabsTTSClient, ElevenLabsTTSError, MetricsObserver, OutputFormat, RetryPolicy,
+    StaticApiKey, StaticVoice, TtsCache, UsageTracker, VoiceSettings,
+};
+use crate::{ClientInner, RequestDefaults, header_map};
+
+/// Builder for [`ElevenLabsTTSClient`] with HTTP configuration
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use elevenlabs_tts::ElevenLabsTTSClient;
+///
+/// # fn main() -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let client = ElevenLabsTTSClient::builder()
+///     .api_key("your-api-key")
+///     .timeout(Duration::from_secs(60))
+///     .connect_timeout(Duration::from_secs(5))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ClientBuilder {
+    api_key: Option<Arc<dyn ApiKeyProvider>>,
+    base_url: Option<String>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    pool_max_idle_per_host: Option<usize>,
+    tcp_keepalive: Option<Duration>,
+    proxies: Vec<Proxy>,
+    http_client: Option<Client>,
+    retry_policy: Option<RetryPolicy>,
+    max_concurrent_requests: Option<usize>,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    cache: Option<Arc<dyn TtsCache>>,
+    default_headers: Vec<(String, String)>,
+    user_agent: Option<String>,
+    user_agent_suffix: Vec<String>,
+    defaults: RequestDefaults,
+    proxy_urls: Vec<(ProxyScope, String)>,
+    proxy_auth: Option<(String, String)>,
+    no_proxy: Option<String>,
+    root_certificates: Vec<RootCertificate>,
+    built_in_root_certs: Option<bool>,
+    check_model_capabilities: bool,
+    usage: Option<UsageTracker>,
+}
+
+/// Which requests a proxy URL applies to
+enum ProxyScope {
+    Http,
+    Https,
+    All,
+}
+
+/// A custom CA certificate, parsed when the client is built
+enum RootCertificate {
+    Pem(Vec<u8>),
+    Der(Vec<u8>),
+}
+
+impl ClientBuilder {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a fixed API key (this or `api_key_provider` is required)
+    pub fn api_key<S: Into<String>>(mut self, api_key: S) -> Self {
+        let api_key: String = api_key.into();
+        self.api_key = (!api_key.trim().is_empty())
+            .then(|| Arc::new(StaticApiKey::new(api_key)) as Arc<dyn ApiKeyProvider>);
+        self
+    }
+
+    /// Set a fixed API key already held as a secret (`secrecy` feature)
+    #[cfg(feature = "secrecy")]
+    pub fn api_key_secret(mut self, api_key: secrecy::SecretString) -> Self {
+        use secrecy::ExposeSecret;
+        self.api_key = (!api_key.expose_secret().trim().is_empty())
+            .then(|| Arc::new(StaticApiKey::from(api_key)) as Arc<dyn ApiKeyProvider>);
+        self
+    }
+
+    /// Resolve the API key per request, e.g. for runtime rotation or a secrets manager
+    pub fn api_key_provider<P: ApiKeyProvider + 'static>(mut self, provider: P) -> Self {
+        self.api_key = Some(Arc::new(provider));
+        self
+    }
+
+    /// Set a custom base URL (for testing/enterprise)
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Set the total timeout of each request, from connecting until the body is read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for establishing the connection only
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Close pooled connections idle for longer than this (`None` keeps them open indefinitely)
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Maximum number of idle connections kept warm per host
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Send TCP keepalive probes on open connections at this interval
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Route requests through a proxy. Can be called several times.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Route plain HTTP requests through the given proxy URL
+    pub fn http_proxy<S: Into<String>>(mut self, url: S) -> Self {
+        self.proxy_urls.push((ProxyScope::Http, url.into()));
+        self
+    }
+
+    /// Route HTTPS requests (all API calls by default) through the given proxy URL
+    pub fn https_proxy<S: Into<String>>(mut self, url: S) -> Self {
+        self.proxy_urls.push((ProxyScope::Https, url.into()));
+        self
+    }
+
+    /// Route all requests through the given proxy URL.
+    /// `socks5://` and `socks5h://` URLs require the `socks` feature.
+    pub fn all_proxy<S: Into<String>>(mut self, url: S) -> Self {
+        self.proxy_urls.push((ProxyScope::All, url.into()));
+        self
+    }
+
+    /// Authenticate against the proxies set with `http_proxy`/`https_proxy`/`all_proxy`
+    pub fn proxy_basic_auth<U: Into<String>, P: Into<String>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        self.proxy_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Hosts that bypass the proxies, comma separated (same syntax as `NO_PROXY`)
+    pub fn no_proxy<S: Into<String>>(mut self, hosts: S) -> Self {
+        self.no_proxy = Some(hosts.into());
+        self
+    }
+
+    /// Trust an additional CA certificate in PEM format (e.g. a corporate TLS-inspection root)
+    pub fn add_root_certificate_pem<B: Into<Vec<u8>>>(mut self, pem: B) -> Self {
+        self.root_certificates
+            .push(RootCertificate::Pem(pem.into()));
+        self
+    }
+
+    /// Trust an additional CA certificate in DER format
+    pub fn add_root_certificate_der<B: Into<Vec<u8>>>(mut self, der: B) -> Self {
+        self.root_certificates
+            .push(RootCertificate::Der(der.into()));
+        self
+    }
+
+    /// Whether to trust the system/bundled root certificates (default: true).
+    /// Disable it to trust only the certificates added with `add_root_certificate_*`.
+    pub fn tls_built_in_root_certs(mut self, enabled: bool) -> Self {
+        self.built_in_root_certs = Some(enabled);
+        self
+    }
+
+    /// Use a pre-configured `reqwest::Client` (custom TLS, middleware-free setups, shared pools).
+    /// HTTP options set on this builder are ignored when a client is provided.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Retry failures with exponential backoff. By default only failures the API never
+    /// billed are retried (429, 503 and connections that couldn't be established); see
+    /// [`RetryPolicy::retry_undetermined`] to also retry 5xx responses, resets and timeouts
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Limit how many requests this client (and its clones) run at once.
+    /// Requests over the limit wait for a free slot instead of failing.
+    pub fn max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max.max(1));
+        self
+    }
+
+    /// Report request lifecycle, latency, bytes and billed characters to an observer
+    pub fn metrics_observer<M: MetricsObserver + 'static>(mut self, observer: M) -> Self {
+        self.metrics = Some(Arc::new(observer));
+        self
+    }
+
+    /// Fail fast during API outages: open the circuit after consecutive failures
+    /// and only let probe requests through until the API recovers
+    pub fn circuit_breaker(mut self, config: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(config);
+        self
+    }
+
+    /// Answer repeated audio requests from a cache instead of regenerating (and re-billing) them.
+    /// Streaming requests always go to the API.
+    pub fn cache<T: TtsCache + 'static>(mut self, cache: T) -> Self {
+        self.cache = Some(Arc::new(cache));
+        self
+    }
+
+    /// Attach a header to every outbound request (e.g. gateway or workspace headers).
+    /// Request builders can override it with their own `.header()`.
+    pub fn default_header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Replace the User-Agent header (defaults to `elevenlabs_tts-rs/<crate version>`)
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Append your integration to the User-Agent, e.g. `("my-app", "1.4.0")` sends
+    /// `elevenlabs_tts-rs/<crate version> my-app/1.4.0`
+    pub fn app_user_agent<N: AsRef<str>, V: AsRef<str>>(mut self, app_name: N, version: V) -> Self {
+        self.user_agent_suffix
+            .push(format!("{}/{}", app_name.as_ref(), version.as_ref()));
+        self
+    }
+
+    /// Voice used by requests that don't set one (instead of Rachel)
+    pub fn default_voice(mut self, voice: &StaticVoice) -> Self {
+        self.defaults.voice_id = Some(voice.voice_id.to_string());
+        self
+    }
+
+    /// Voice ID used by requests that don't set one (for custom voices)
+    pub fn default_voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.defaults.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Text-to-speech model used by requests that don't set one
+    pub fn default_model<M: Into<ModelId>>(mut self, model_id: M) -> Self {
+        self.defaults.model_id = Some(model_id.into().to_string());
+        self
+    }
+
+    /// Output format used by requests that don't set one
+    pub fn default_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.defaults.output_format = Some(output_format);
+        self
+    }
+
+    /// Voice settings used by requests that don't set their own
+    pub fn default_voice_settings(mut self, settings: VoiceSettings) -> Self {
+        self.defaults.voice_settings = Some(settings);
+        self
+    }
+
+    /// Check text-to-speech requests against the account's models before sending them,
+    /// so an unsupported model, language or setting fails with a descriptive validation
+    /// error instead of an API 400. The models list (`GET /v1/models`) is fetched once and
+    /// cached for [`MODEL_CACHE_TTL`].
+    pub fn check_model_capabilities(mut self, enabled: bool) -> Self {
+        self.check_model_capabilities = enabled;
+        self
+    }
+
+    /// Count the characters of every text-to-speech and dialogue request, refusing
+    /// requests once the tracker's daily or monthly budget is spent
+    pub fn usage_tracker(mut self, tracker: UsageTracker) -> Self {
+        self.usage = Some(tracker);
+        self
+    }
+
+    /// Build the client
+    pub fn build(self) -> Result<ElevenLabsTTSClient, ElevenLabsTTSError> {
+        let api_key = self.api_key.ok_or_else(|| {
+            ElevenLabsTTSError::ValidationError("API key is required".to_string())
+        })?;
+
+        // The User-Agent goes first so an explicit default_header("user-agent", ..) still wins
+        let user_agent = std::iter::once(
+            self.user_agent
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+        )
+        .chain(self.user_agent_suffix)
+        .collect::<Vec<_>>()
+        .join(" ");
+        let mut default_headers = vec![("user-agent".to_string(), user_agent)];
+        default_headers.extend(self.default_headers);
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                if let Some(timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(timeout);
+                }
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(interval) = self.tcp_keepalive {
+                    builder = builder.tcp_keepalive(interval);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                let no_proxy = self.no_proxy.as_deref().and_then(NoProxy::from_string);
+                for (scope, url) in &self.proxy_urls {
+                    let mut proxy = build_proxy(scope, url)?;
+                    if let Some((username, password)) = &self.proxy_auth {
+                        proxy = proxy.basic_auth(username, password);
+                    }
+                    builder = builder.proxy(proxy.no_proxy(no_proxy.clone()));
+                }
+                for certificate in &self.root_certificates {
+                    let parsed = match certificate {
+                        RootCertificate::Pem(pem) => Certificate::from_pem(pem),
+                        RootCertificate::Der(der) => Certificate::from_der(der),
+                    }
+                    .map_err(|e| {
+                        ElevenLabsTTSError::ValidationError(format!(
+                            "Invalid root certificate: {}",
+                            e
+                        ))
+                    })?;
+                    builder = builder.add_root_certificate(parsed);
+                }
+                if let Some(enabled) = self.built_in_root_certs {
+                    builder = builder.tls_built_in_root_certs(enabled);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(ElevenLabsTTSClient::from_inner(ClientInner {
+            client,
+            api_key,
+            base_url: self
+                .base_url
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+            retry_policy: self.retry_policy.unwrap_or_else(RetryPolicy::none),
+            limiter: self
+                .max_concurrent_requests
+                .map(|max| Arc::new(Semaphore::new(max))),
+            metrics: self.metrics,
+            circuit_breaker: self
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config))),
+            cache: self.cache,
+            default_headers: header_map(&default_headers)?,
+            defaults: self.defaults,
+            model_catalog: self
+                .check_model_capabilities
+                .then(|| Expiring::new(MODEL_CACHE_TTL)),
+            usage: self.usage,
+            subscription: Expiring::new(SUBSCRIPTION_CACHE_TTL),
+            #[cfg(feature = "test-utils")]
+            mock: false,
+        }))
+    }
+}
+
+/// Parse a proxy URL for the given scope
+fn build_proxy(scope: &ProxyScope, url: &str) -> Result<Proxy, ElevenLabsTTSError> {
+    if cfg!(not(feature = "socks")) && url.trim_start().to_lowercase().starts_with("socks") {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "SOCKS proxy {} requires the `socks` feature",
+            url
+        )));
+    }
+
+    match scope {
+        ProxyScope::Http => Proxy::http(url),
+        ProxyScope::Https => Proxy::https(url),
+        ProxyScope::All => Proxy::all(url),
+    }
+    .map_err(|e| ElevenLabsTTSError::ValidationError(format!("Invalid proxy URL {}: {}", url, e)))
+}
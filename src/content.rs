@@ -0,0 +1,480 @@
+//! Markdown and HTML to narration text (`content` feature)
+//!
+//! Documents carry markup that should not be read aloud: emphasis markers, link URLs,
+//! code, navigation scripts. [`TextExtractor`] keeps the words, turns the document
+//! structure into paragraphs and lines, and ends headings with a pause so a chapter
+//! title does not run into its first sentence.
+
+use std::time::Duration;
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::long_form::SENTENCE_ENDS;
+
+/// Pause inserted after headings unless configured otherwise
+pub const DEFAULT_HEADING_PAUSE: Duration = Duration::from_millis(1000);
+
+// Elements whose content is never spoken
+const HIDDEN_ELEMENTS: [&str; 8] = [
+    "script", "style", "head", "noscript", "template", "svg", "iframe", "button",
+];
+
+// Elements that start a new paragraph
+const BLOCK_ELEMENTS: [&str; 20] = [
+    "p",
+    "div",
+    "section",
+    "article",
+    "aside",
+    "header",
+    "footer",
+    "main",
+    "nav",
+    "blockquote",
+    "figure",
+    "figcaption",
+    "ul",
+    "ol",
+    "dl",
+    "table",
+    "hr",
+    "form",
+    "fieldset",
+    "details",
+];
+
+// Elements that start a new line
+const LINE_ELEMENTS: [&str; 6] = ["br", "li", "tr", "dt", "dd", "summary"];
+
+/// Extracts narration text from Markdown or HTML
+///
+/// ```rust
+/// use elevenlabs_tts::content::TextExtractor;
+///
+/// let markdown = "# Setup\n\nRun **this** [command](https://example.com):\n\n```sh\ncargo build\n```\n";
+/// let text = TextExtractor::new().heading_pause(None).markdown(markdown);
+/// assert_eq!(text, "Setup.\n\nRun this command:");
+///
+/// let html = "<h2>News</h2><p>Prices &amp; plans<br>changed.</p><script>track()</script>";
+/// assert_eq!(
+///     TextExtractor::new().html(html),
+///     "News. <break time=\"1.0s\" />\n\nPrices & plans\nchanged."
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct TextExtractor {
+    heading_pause: Option<Duration>,
+    alt_text: bool,
+    code_blocks: bool,
+}
+
+impl Default for TextExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextExtractor {
+    pub fn new() -> Self {
+        Self {
+            heading_pause: Some(DEFAULT_HEADING_PAUSE),
+            alt_text: false,
+            code_blocks: false,
+        }
+    }
+
+    /// Pause after each heading as a `<break time="..." />` tag, or `None` for no pause.
+    /// Break tags are not supported by `eleven_v3`, which takes audio tags instead.
+    pub fn heading_pause(mut self, pause: Option<Duration>) -> Self {
+        self.heading_pause = pause;
+        self
+    }
+
+    /// Speak the alt text of images (skipped by default)
+    pub fn alt_text(mut self, alt_text: bool) -> Self {
+        self.alt_text = alt_text;
+        self
+    }
+
+    /// Keep the content of code blocks (dropped by default). Inline code is always kept.
+    pub fn code_blocks(mut self, code_blocks: bool) -> Self {
+        self.code_blocks = code_blocks;
+        self
+    }
+
+    /// Extract narration text from CommonMark (with tables and strikethrough)
+    pub fn markdown(&self, markdown: &str) -> String {
+        let mut narration = Narration::new(self.heading_pause);
+        let mut image_depth = 0;
+        let mut in_code_block = false;
+        let mut cell = 0;
+
+        let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+        for event in Parser::new_ext(markdown, options) {
+            let hidden =
+                (image_depth > 0 && !self.alt_text) || (in_code_block && !self.code_blocks);
+            match event {
+                Event::Start(Tag::Image { .. }) => image_depth += 1,
+                Event::End(TagEnd::Image) => image_depth -= 1,
+                Event::Start(Tag::CodeBlock(_)) => {
+                    in_code_block = true;
+                    narration.block_break();
+                }
+                Event::End(TagEnd::CodeBlock) => {
+                    in_code_block = false;
+                    narration.block_break();
+                }
+                Event::Start(Tag::Heading { .. }) => narration.block_break(),
+                Event::End(TagEnd::Heading(_)) => narration.heading_end(),
+                Event::Start(Tag::Item) | Event::End(TagEnd::TableHead | TagEnd::TableRow) => {
+                    narration.line_break();
+                    cell = 0;
+                }
+                Event::Start(Tag::TableCell) => {
+                    if cell > 0 {
+                        narration.separator();
+                    }
+                    cell += 1;
+                }
+                Event::End(
+                    TagEnd::Paragraph | TagEnd::BlockQuote(_) | TagEnd::List(_) | TagEnd::Table,
+                )
+                | Event::Rule => narration.block_break(),
+                Event::Text(text) | Event::Code(text) if !hidden => {
+                    if in_code_block {
+                        narration.line_break();
+                    }
+                    narration.text(&text);
+                }
+                Event::Html(html) | Event::InlineHtml(html) if !hidden => {
+                    self.html_into(&mut narration, &html)
+                }
+                Event::SoftBreak => narration.text(" "),
+                Event::HardBreak => narration.line_break(),
+                _ => {}
+            }
+        }
+        narration.finish()
+    }
+
+    /// Extract narration text from an HTML document or fragment
+    pub fn html(&self, html: &str) -> String {
+        let mut narration = Narration::new(self.heading_pause);
+        self.html_into(&mut narration, html);
+        narration.finish()
+    }
+
+    fn html_into(&self, narration: &mut Narration, html: &str) {
+        let mut rest = html;
+        let mut in_pre = false;
+        while let Some(start) = rest.find('<') {
+            if !in_pre || self.code_blocks {
+                narration.text(&decode_entities(&rest[..start]));
+            }
+            rest = &rest[start..];
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+                continue;
+            }
+            let Some(end) = rest.find('>') else {
+                rest = "";
+                break;
+            };
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            let closing = tag.starts_with('/');
+            let name: String = tag
+                .trim_start_matches('/')
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric())
+                .collect::<String>()
+                .to_ascii_lowercase();
+
+            if HIDDEN_ELEMENTS.contains(&name.as_str()) && !closing && !tag.ends_with('/') {
+                rest = skip_element(rest, &name);
+            } else if name == "pre" {
+                in_pre = !closing;
+                narration.block_break();
+            } else if name == "img" && self.alt_text {
+                if let Some(alt) = attribute(tag, "alt") {
+                    narration.text(" ");
+                    narration.text(&decode_entities(alt));
+                    narration.text(" ");
+                }
+            } else if is_heading(&name) {
+                if closing {
+                    narration.heading_end();
+                } else {
+                    narration.block_break();
+                }
+            } else if BLOCK_ELEMENTS.contains(&name.as_str()) {
+                narration.block_break();
+            } else if LINE_ELEMENTS.contains(&name.as_str()) {
+                if !closing {
+                    narration.line_break();
+                }
+            } else if (name == "td" || name == "th") && closing {
+                narration.separator();
+            }
+        }
+        if !in_pre || self.code_blocks {
+            narration.text(&decode_entities(rest));
+        }
+    }
+}
+
+/// Narration text with collapsed whitespace, built event by event
+struct Narration {
+    out: String,
+    pending_space: bool,
+    // A ", " between table cells, written only if more text follows on the line
+    pending_separator: bool,
+    heading_pause: Option<Duration>,
+}
+
+impl Narration {
+    fn new(heading_pause: Option<Duration>) -> Self {
+        Self {
+            out: String::new(),
+            pending_space: false,
+            pending_separator: false,
+            heading_pause,
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        for c in text.chars() {
+            if c.is_whitespace() {
+                self.pending_space = true;
+                continue;
+            }
+            let line_start = self.out.is_empty() || self.out.ends_with('\n');
+            if self.pending_separator && !line_start {
+                self.trim_end();
+                self.out.push_str(", ");
+            } else if self.pending_space && !line_start && !self.out.ends_with(' ') {
+                self.out.push(' ');
+            }
+            self.pending_space = false;
+            self.pending_separator = false;
+            self.out.push(c);
+        }
+    }
+
+    fn separator(&mut self) {
+        self.pending_separator = true;
+    }
+
+    fn line_break(&mut self) {
+        self.trim_end();
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    fn block_break(&mut self) {
+        self.trim_end();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.line_break();
+            self.out.push('\n');
+        }
+    }
+
+    fn heading_end(&mut self) {
+        self.trim_end();
+        if self
+            .out
+            .ends_with(|c: char| !SENTENCE_ENDS.contains(&c) && c != ':' && c != '\n')
+        {
+            self.out.push('.');
+        }
+        let heading = !self.out.is_empty() && !self.out.ends_with('\n');
+        if let Some(pause) = self.heading_pause.filter(|_| heading) {
+            self.out
+                .push_str(&format!(" <break time=\"{:.1}s\" />", pause.as_secs_f32()));
+        }
+        self.block_break();
+    }
+
+    fn trim_end(&mut self) {
+        let trimmed = self.out.trim_end_matches(' ').len();
+        self.out.truncate(trimmed);
+        self.pending_space = false;
+        self.pending_separator = false;
+    }
+
+    fn finish(mut self) -> String {
+        self.trim_end();
+        self.out.trim().to_string()
+    }
+}
+
+fn is_heading(name: &str) -> bool {
+    matches!(name, "h1" | "h2" | "h3" | "h4" | "h5" | "h6")
+}
+
+/// Skip past the closing tag of `name`, or to the end when it is missing
+fn skip_element<'a>(rest: &'a str, name: &str) -> &'a str {
+    let lower = rest.to_ascii_lowercase();
+    let closing = format!("</{}", name);
+    match lower.find(&closing) {
+        Some(start) => rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]),
+        None => "",
+    }
+}
+
+/// The value of an attribute in the text of a tag
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(found) = lower[from..].find(name) {
+        let at = from + found;
+        from = at + name.len();
+        let preceded = lower[..at].ends_with(char::is_whitespace);
+        let value = lower[from..].trim_start();
+        if !preceded || !value.starts_with('=') {
+            continue;
+        }
+        let offset = tag.len() - value.len() + 1;
+        let value = tag[offset..].trim_start();
+        return Some(match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value = &value[1..];
+                &value[..value.find(quote).unwrap_or(value.len())]
+            }
+            _ => {
+                let end = value
+                    .find(|c: char| c.is_whitespace() || c == '/')
+                    .unwrap_or(value.len());
+                &value[..end]
+            }
+        });
+    }
+    None
+}
+
+/// Decode HTML character references
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end + 1]).map(|c| (c, end + 2)));
+        match entity {
+            Some((c, length)) => {
+                decoded.push(c);
+                rest = &rest[length..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => ' ',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "laquo" => '«',
+        "raquo" => '»',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "deg" => '°',
+        "euro" => '€',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_structure() {
+        let markdown = "\
+## Intro:
+
+Some *emphasis*, `code` and a ![diagram](d.png) here.
+
+- first
+- second
+
+| Name | Age |
+|------|-----|
+| Ann  | 30  |
+
+> Quoted<br>line
+
+---
+Done!
+";
+        let extractor = TextExtractor::new().heading_pause(None);
+        assert_eq!(
+            extractor.markdown(markdown),
+            "Intro:\n\nSome emphasis, code and a here.\n\nfirst\nsecond\n\n\
+             Name, Age\nAnn, 30\n\nQuoted\nline\n\nDone!"
+        );
+        assert!(
+            extractor
+                .alt_text(true)
+                .code_blocks(true)
+                .markdown("![A cat](c.png)\n\n```\nlet x = 1;\n```")
+                .starts_with("A cat\n\nlet x = 1;")
+        );
+    }
+
+    #[test]
+    fn test_html_extraction() {
+        let html = r#"<!DOCTYPE html><html><head><title>T</title><style>p {}</style></head>
+            <body><nav>Home</nav><h1>Title</h1><!-- note --><p>It&rsquo;s 5&#176; <i>out</i>side.
+            <img src="x.png" alt='A "sunny" day'></p><pre>ls -la</pre><ul><li>One</li><li>Two</li></ul>
+            <table><tr><th>A</th><th>B</th></tr><tr><td>1</td><td>2</td></tr></table></body></html>"#;
+        assert_eq!(
+            TextExtractor::new()
+                .heading_pause(Some(Duration::from_millis(1500)))
+                .html(html),
+            "Home\n\nTitle. <break time=\"1.5s\" />\n\nIt’s 5° outside.\n\nOne\nTwo\n\nA, B\n1, 2"
+        );
+        assert_eq!(
+            TextExtractor::new()
+                .alt_text(true)
+                .html(r#"<p>Look: <img alt="A cat"/></p>"#),
+            "Look: A cat"
+        );
+        assert_eq!(
+            decode_entities("a &unknown; & b &#x41;"),
+            "a &unknown; & b A"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use crate::{ElevenLabsTTSClient, ElevenLabsTTSError};
+
+/// Response of the get-signed-url endpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignedUrlResponse {
+    /// A WebSocket URL that lets a browser client talk to the agent without the API key
+    pub signed_url: String,
+}
+
+/// Conversational AI (agents) endpoints
+pub struct ConversationalAi {
+    client: ElevenLabsTTSClient,
+}
+
+impl ConversationalAi {
+    pub(crate) fn new(client: ElevenLabsTTSClient) -> Self {
+        Self { client }
+    }
+
+    /// Mint a signed WebSocket URL for the given agent.
+    /// The URL is short-lived and can be handed to browser clients safely.
+    pub async fn signed_url<S: AsRef<str>>(
+        &self,
+        agent_id: S,
+    ) -> Result<String, ElevenLabsTTSError> {
+        let agent_id = agent_id.as_ref();
+        if agent_id.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Agent ID cannot be empty".to_string(),
+            ));
+        }
+
+        let url = format!(
+            "{}/convai/conversation/get-signed-url",
+            self.client.inner.base_url
+        );
+        let response: SignedUrlResponse = self
+            .client
+            .get_json(
+                "convai-signed-url",
+                &url,
+                &[("agent_id", agent_id.to_string())],
+            )
+            .await?;
+
+        Ok(response.signed_url)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{TTSRequest, models::ModelId};
+
+/// Predicted quota consumption of a request, computed locally
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimate {
+    /// Characters of `text` that count toward the quota
+    pub characters: u64,
+
+    /// Credits charged for those characters, when the model's rate is known
+    pub credits: Option<f64>,
+}
+
+/// Number of characters the API bills for `text`
+///
+/// Counts Unicode characters (not bytes), including whitespace and SSML tags such as
+/// `<break time="1s" />`. Text normalization happens server side and is not billed,
+/// and `previous_text`/`next_text` are context only, so neither affects the count.
+///
+/// ```rust
+/// assert_eq!(elevenlabs_tts::cost::estimate_characters("Héllo wörld"), 11);
+/// ```
+pub fn estimate_characters(text: &str) -> u64 {
+    text.chars().count() as u64
+}
+
+/// Predict the characters and credits a text-to-speech request will consume
+pub fn estimate_cost(request: &TTSRequest) -> CostEstimate {
+    let characters = estimate_characters(&request.text);
+    let credits = ModelId::from(request.model_id.as_str())
+        .credits_per_character()
+        .map(|rate| characters as f64 * rate);
+    CostEstimate {
+        characters,
+        credits,
+    }
+}
+
+/// Sum the estimates of many requests, e.g. a batch about to be submitted.
+/// `credits` is `None` if any request uses a model with an unknown rate.
+pub fn estimate_total<'a, I>(requests: I) -> CostEstimate
+where
+    I: IntoIterator<Item = &'a TTSRequest>,
+{
+    requests.into_iter().map(estimate_cost).fold(
+        CostEstimate {
+            characters: 0,
+            credits: Some(0.0),
+        },
+        |total, estimate| CostEstimate {
+            characters: total.characters + estimate.characters,
+            credits: total.credits.zip(estimate.credits).map(|(a, b)| a + b),
+        },
+    )
+}
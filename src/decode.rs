@@ -0,0 +1,158 @@
+//! Decoding generated audio to PCM samples (`decode` feature)
+//!
+//! Mixing speech with music, ducking a music bed under it or measuring loudness works on
+//! samples rather than encoded bytes. [`decode_pcm`] turns any output format except Opus
+//! into interleaved `f32` samples in `-1.0..=1.0`, decoding MP3 with symphonia.
+
+use std::io::Cursor;
+use std::time::Duration;
+
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::{AudioResponse, ElevenLabsTTSError, OutputFormat, audio};
+
+/// Audio decoded to interleaved 32-bit float samples
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecodedAudio {
+    /// Samples in `-1.0..=1.0`, one per channel per frame
+    pub samples: Vec<f32>,
+
+    pub sample_rate: u32,
+
+    pub channels: u16,
+}
+
+impl DecodedAudio {
+    /// Number of frames (samples per channel)
+    pub fn frames(&self) -> usize {
+        self.samples.len() / usize::from(self.channels.max(1))
+    }
+
+    /// Playback duration
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.frames() as f64 / f64::from(self.sample_rate.max(1)))
+    }
+}
+
+/// Decode audio generated in `format`. PCM, μ-law and A-law are converted directly;
+/// MP3 is decoded with symphonia. Opus is not supported, as symphonia has no Opus decoder.
+///
+/// ```rust
+/// use elevenlabs_tts::{OutputFormat, decode};
+///
+/// let decoded = decode::decode_pcm(&[0x00, 0x40, 0x00, 0xC0], OutputFormat::Pcm_16000).unwrap();
+/// assert_eq!(decoded.samples, [0.5, -0.5]);
+/// assert_eq!((decoded.sample_rate, decoded.channels), (16_000, 1));
+/// ```
+pub fn decode_pcm(audio: &[u8], format: OutputFormat) -> Result<DecodedAudio, ElevenLabsTTSError> {
+    let pcm = match format.codec() {
+        "mp3" => return decode_mp3(audio),
+        "pcm" => {
+            if !audio.len().is_multiple_of(2) {
+                return Err(ElevenLabsTTSError::DecodeError(
+                    "PCM audio has an odd number of bytes".to_string(),
+                ));
+            }
+            audio.to_vec()
+        }
+        "ulaw" => audio::ulaw_to_pcm(audio),
+        "alaw" => audio::alaw_to_pcm(audio),
+        codec => {
+            return Err(ElevenLabsTTSError::DecodeError(format!(
+                "Decoding {} audio is not supported",
+                codec
+            )));
+        }
+    };
+    Ok(DecodedAudio {
+        samples: pcm
+            .chunks_exact(2)
+            .map(|sample| f32::from(i16::from_le_bytes([sample[0], sample[1]])) / 32_768.0)
+            .collect(),
+        sample_rate: format.sample_rate(),
+        channels: format.channels(),
+    })
+}
+
+impl AudioResponse {
+    /// Decode MP3 audio to samples (`decode` feature). Raw PCM, μ-law and A-law carry no
+    /// header with their sample rate, so decode them with [`decode_pcm`] and the request's
+    /// output format instead.
+    pub fn decode_pcm(&self) -> Result<DecodedAudio, ElevenLabsTTSError> {
+        if !audio::is_mp3(&self.audio) {
+            return Err(ElevenLabsTTSError::DecodeError(
+                "Only MP3 can be decoded without the output format; use decode::decode_pcm"
+                    .to_string(),
+            ));
+        }
+        decode_mp3(&self.audio)
+    }
+}
+
+fn decode_mp3(mp3: &[u8]) -> Result<DecodedAudio, ElevenLabsTTSError> {
+    let source = MediaSourceStream::new(Box::new(Cursor::new(mp3.to_vec())), Default::default());
+    let mut hint = Hint::new();
+    hint.with_extension("mp3");
+    let mut reader = symphonia::default::get_probe()
+        .format(
+            &hint,
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(decode_error)?
+        .format;
+    let track = reader.default_track().ok_or_else(|| {
+        ElevenLabsTTSError::DecodeError("No audio track found in the MP3".to_string())
+    })?;
+    let track_id = track.id;
+    let mut decoded = DecodedAudio {
+        samples: Vec::new(),
+        sample_rate: track.codec_params.sample_rate.unwrap_or_default(),
+        channels: track
+            .codec_params
+            .channels
+            .map_or(0, |channels| channels.count() as u16),
+    };
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(decode_error)?;
+
+    loop {
+        let packet = match reader.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(error))
+                if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(error) => return Err(decode_error(error)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let buffer = match decoder.decode(&packet) {
+            Ok(buffer) => buffer,
+            // A corrupt frame is skipped rather than failing the whole clip
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(error) => return Err(decode_error(error)),
+        };
+        let spec = *buffer.spec();
+        let mut samples = SampleBuffer::<f32>::new(buffer.capacity() as u64, spec);
+        samples.copy_interleaved_ref(buffer);
+        decoded.samples.extend_from_slice(samples.samples());
+        decoded.sample_rate = spec.rate;
+        decoded.channels = spec.channels.count() as u16;
+    }
+    Ok(decoded)
+}
+
+fn decode_error(error: SymphoniaError) -> ElevenLabsTTSError {
+    ElevenLabsTTSError::DecodeError(error.to_string())
+}
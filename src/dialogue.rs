@@ -0,0 +1,227 @@
+use serde::Serialize;
+
+use crate::{
+    AudioTag, ElevenLabsTTSClient, ElevenLabsTTSError, HttpRequestParts, LanguageCode,
+    OutputFormat, RequestOptions, Stability, StaticVoice, ids, models,
+};
+
+/// A single speaker turn in a text-to-dialogue request
+#[derive(Debug, Clone, Serialize)]
+pub struct DialogueInput {
+    /// The text spoken in this turn. May contain eleven_v3 audio tags such as `[laughs]`.
+    pub text: String,
+
+    /// ID of the voice speaking this turn
+    pub voice_id: String,
+}
+
+impl DialogueInput {
+    /// Create a new dialogue turn for the given voice
+    pub fn new<V: Into<String>, T: Into<String>>(voice_id: V, text: T) -> Self {
+        Self {
+            text: text.into(),
+            voice_id: voice_id.into(),
+        }
+    }
+
+    /// Prefix this turn with an audio tag, e.g. `whispers` becomes `[whispers] ...`
+    pub fn tag<S: AsRef<str>>(mut self, tag: S) -> Self {
+        self.text = format!("[{}] {}", tag.as_ref().trim_matches(['[', ']']), self.text);
+        self
+    }
+
+    /// Prefix this turn with a validated audio tag
+    pub fn audio_tag(mut self, tag: &AudioTag) -> Self {
+        self.text = format!("{} {}", tag, self.text);
+        self
+    }
+
+    /// Prefix this turn with several audio tags, in the given order
+    pub fn tags<I, S>(self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let tags: Vec<S> = tags.into_iter().collect();
+        tags.iter().rev().fold(self, |input, tag| input.tag(tag))
+    }
+}
+
+impl<V: Into<String>, T: Into<String>> From<(V, T)> for DialogueInput {
+    fn from((voice_id, text): (V, T)) -> Self {
+        Self::new(voice_id, text)
+    }
+}
+
+/// Settings applied to the whole dialogue generation
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DialogueSettings {
+    /// Stability of the voices, Must be one of: 0.0, 0.5 and 1.0
+    /// 0.0 : Creative, 0.5 : Natural, 1.0 : Robust
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stability: Option<f32>,
+
+    /// Boost the similarity to the original speakers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_speaker_boost: Option<bool>,
+}
+
+impl DialogueSettings {
+    /// Settings with the given stability preset
+    pub fn with_stability(stability: Stability) -> Self {
+        Self {
+            stability: Some(stability.value()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Request body for text-to-dialogue API calls
+#[derive(Debug, Clone, Serialize)]
+pub struct TextToDialogueRequest {
+    // The ordered list of speaker turns. Each turn is rendered with its own voice into a single audio.
+    pub inputs: Vec<DialogueInput>,
+
+    // Identifier of the model that will be used. Text-to-dialogue is only available on eleven_v3.
+    pub model_id: String,
+
+    // Output format of the generated audio, sent as a query parameter.
+    #[serde(skip_serializing)]
+    pub output_format: OutputFormat,
+
+    // Language code (ISO 639-1) used to enforce a language for the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+
+    // Settings controlling the dialogue generation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<DialogueSettings>,
+
+    // If specified, our system will make a best effort to sample deterministically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+}
+
+/// Builder for text-to-dialogue requests
+pub struct TextToDialogueBuilder {
+    client: ElevenLabsTTSClient,
+    inputs: Vec<DialogueInput>,
+    model_id: Option<String>,
+    output_format: Option<OutputFormat>,
+    language_code: Option<String>,
+    settings: Option<DialogueSettings>,
+    seed: Option<u32>,
+    options: RequestOptions,
+}
+
+impl TextToDialogueBuilder {
+    pub(crate) fn new(client: ElevenLabsTTSClient, inputs: Vec<DialogueInput>) -> Self {
+        Self {
+            client,
+            inputs,
+            model_id: None,
+            output_format: None,
+            language_code: None,
+            settings: None,
+            seed: None,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Append a speaker turn using a voice ID
+    pub fn turn<V: Into<String>, T: Into<String>>(mut self, voice_id: V, text: T) -> Self {
+        self.inputs.push(DialogueInput::new(voice_id, text));
+        self
+    }
+
+    /// Append a speaker turn using a static voice
+    pub fn voice_turn<T: Into<String>>(mut self, voice: &StaticVoice, text: T) -> Self {
+        self.inputs.push(DialogueInput::new(voice.voice_id, text));
+        self
+    }
+
+    /// Append a prepared speaker turn (e.g. one carrying audio tags)
+    pub fn input(mut self, input: DialogueInput) -> Self {
+        self.inputs.push(input);
+        self
+    }
+
+    /// Set the model to use, as a [`models::ModelId`] or a model ID string
+    pub fn model<M: Into<models::ModelId>>(mut self, model_id: M) -> Self {
+        self.model_id = Some(model_id.into().to_string());
+        self
+    }
+
+    /// Set the output format to use
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+
+    /// Set the language code to use, as a [`LanguageCode`] or an ISO 639-1 string
+    pub fn language_code<S: Into<String>>(mut self, language_code: S) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+
+    /// Set the dialogue settings (stability and speaker boost)
+    pub fn settings(mut self, settings: DialogueSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Set seeds to use
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Add a header to this request (e.g. a correlation ID), overriding client defaults
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.options.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Build the request body without sending it
+    pub fn build_request(&self) -> Result<TextToDialogueRequest, ElevenLabsTTSError> {
+        if self.inputs.is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Dialogue requires at least one input".to_string(),
+            ));
+        }
+        for input in &self.inputs {
+            ids::check_not_voice_name(&input.voice_id)?;
+        }
+
+        Ok(TextToDialogueRequest {
+            inputs: self.inputs.clone(),
+            model_id: self
+                .model_id
+                .clone()
+                .unwrap_or_else(|| models::elevanlabs_models::ELEVEN_V3.to_string()), // Default to: eleven_v3
+            output_format: self.output_format.unwrap_or_default(), // Default to: mp3_44100_128
+            language_code: self
+                .language_code
+                .as_deref()
+                .map(LanguageCode::new)
+                .transpose()?
+                .map(String::from),
+            settings: self.settings.clone(),
+            seed: self.seed,
+        })
+    }
+
+    /// Describe the HTTP request without sending it, with the API key redacted
+    pub fn to_http_parts(&self) -> Result<HttpRequestParts, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        let url = format!("{}/text-to-dialogue", self.client.inner.base_url);
+        let query = [("output_format", request.output_format.to_string())];
+        self.client.http_parts(url, &query, &request, &self.options)
+    }
+
+    /// Execute the text-to-dialogue request
+    pub async fn execute(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        self.client.execute_dialogue(request, &self.options).await
+    }
+}
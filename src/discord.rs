@@ -0,0 +1,66 @@
+//! Speaking generated audio into Discord voice channels with songbird (`songbird` feature)
+//!
+//! [`input`] turns a streaming response into a [`songbird::input::Input`] that starts
+//! playing while the rest of the audio is still being generated. Songbird decodes it and
+//! resamples to Discord's 48 kHz, so the default MP3 output works as well as Opus.
+
+use futures_util::StreamExt;
+use songbird::input::core::probe::Hint;
+use songbird::input::{
+    AsyncAdapterStream, AsyncReadOnlySource, AudioStream as SongbirdStream, Input, LiveInput,
+};
+use tokio::io::AsyncWriteExt;
+
+use crate::{AudioStream, ElevenLabsTTSError, OutputFormat};
+
+/// Bytes buffered between the HTTP response and songbird's decoder
+const BUFFER_LENGTH: usize = 64 * 1024;
+
+/// Wrap a streaming response in `format` as a songbird input. Only MP3 and Opus are
+/// supported, as the raw formats carry no header for songbird to detect their rate.
+/// An error midway through the stream ends the audio early.
+///
+/// ```rust,no_run
+/// # async fn example(
+/// #     client: elevenlabs_tts::ElevenLabsTTSClient,
+/// #     call: &mut songbird::Driver,
+/// # ) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let input = client.text_to_speech("Hello, voice channel!").execute_songbird().await?;
+/// call.play_input(input);
+/// # Ok(())
+/// # }
+/// ```
+pub fn input(mut stream: AudioStream, format: OutputFormat) -> Result<Input, ElevenLabsTTSError> {
+    let extension = match format.codec() {
+        "mp3" => "mp3",
+        "opus" => "ogg",
+        _ => {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Songbird input needs an MP3 or Opus output format, got {}",
+                format
+            )));
+        }
+    };
+
+    // The response stream isn't `Sync`, so a task copies it into a pipe songbird reads from
+    let (mut writer, reader) = tokio::io::duplex(BUFFER_LENGTH);
+    tokio::spawn(async move {
+        while let Some(Ok(chunk)) = stream.next().await {
+            // Songbird dropped the input, e.g. the track was stopped
+            if writer.write_all(&chunk).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let source = AsyncAdapterStream::new(Box::new(AsyncReadOnlySource::new(reader)), BUFFER_LENGTH);
+    let mut hint = Hint::new();
+    hint.with_extension(extension);
+    Ok(Input::Live(
+        LiveInput::Raw(SongbirdStream {
+            input: Box::new(source),
+            hint: Some(hint),
+        }),
+        None,
+    ))
+}
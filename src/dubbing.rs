@@ -0,0 +1,357 @@
+//! Editing dubs through the dubbing resource endpoints
+//!
+//! A dub made in Dubbing Studio is stored as a resource: speaker tracks, the transcript
+//! split into segments, and each segment's translation per target language. A review
+//! tool can fix a translated line with [`Dubbing::update_segment`], regenerate the audio
+//! of just the edited segments with [`Dubbing::dub_segments`], then render the language
+//! again with [`Dubbing::render`].
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ElevenLabsTTSClient, ElevenLabsTTSError, RequestOptions, ids};
+
+/// A dub with its speakers, segments and translations
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DubbingResource {
+    pub id: String,
+
+    /// Incremented by every edit, dub and render
+    #[serde(default)]
+    pub version: u64,
+
+    #[serde(default)]
+    pub source_language: Option<String>,
+
+    #[serde(default)]
+    pub target_languages: Vec<String>,
+
+    /// Speaker tracks by ID
+    #[serde(default)]
+    pub speaker_tracks: HashMap<String, SpeakerTrack>,
+
+    /// Transcript segments by ID
+    #[serde(default)]
+    pub speaker_segments: HashMap<String, SpeakerSegment>,
+}
+
+impl DubbingResource {
+    /// Speaker tracks, ordered by name
+    pub fn speakers(&self) -> Vec<&SpeakerTrack> {
+        let mut speakers: Vec<_> = self.speaker_tracks.values().collect();
+        speakers.sort_by(|a, b| (&a.speaker_name, &a.id).cmp(&(&b.speaker_name, &b.id)));
+        speakers
+    }
+
+    /// Segments in playback order
+    pub fn segments(&self) -> Vec<&SpeakerSegment> {
+        let mut segments: Vec<_> = self.speaker_segments.values().collect();
+        segments.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+        segments
+    }
+
+    /// Segments spoken by one speaker, in playback order
+    pub fn segments_of(&self, speaker_id: &str) -> Vec<&SpeakerSegment> {
+        let Some(speaker) = self.speaker_tracks.get(speaker_id) else {
+            return Vec::new();
+        };
+        let mut segments: Vec<_> = speaker
+            .segments
+            .iter()
+            .filter_map(|segment_id| self.speaker_segments.get(segment_id))
+            .collect();
+        segments.sort_by(|a, b| a.start_time.total_cmp(&b.start_time));
+        segments
+    }
+}
+
+/// A speaker in a dub
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SpeakerTrack {
+    pub id: String,
+
+    #[serde(default)]
+    pub speaker_name: String,
+
+    /// Voice ID used for each target language
+    #[serde(default)]
+    pub voices: HashMap<String, String>,
+
+    /// IDs of the segments this speaker says
+    #[serde(default)]
+    pub segments: Vec<String>,
+}
+
+/// A stretch of the source transcript, with its translation per target language
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SpeakerSegment {
+    pub id: String,
+
+    /// Start time in seconds
+    pub start_time: f64,
+
+    /// End time in seconds
+    pub end_time: f64,
+
+    /// Source-language text
+    #[serde(default)]
+    pub text: String,
+
+    /// Translation by target language
+    #[serde(default)]
+    pub dubs: HashMap<String, SegmentDub>,
+}
+
+/// A segment's translation into one language
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SegmentDub {
+    pub start_time: f64,
+    pub end_time: f64,
+
+    #[serde(default)]
+    pub text: String,
+
+    /// Whether the text or timing changed since the audio was generated, so the segment
+    /// needs [`Dubbing::dub_segments`]
+    #[serde(default)]
+    pub audio_stale: bool,
+}
+
+/// Changes to a segment's translation; fields left as `None` are kept
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SegmentUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Start time in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<f64>,
+
+    /// End time in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<f64>,
+}
+
+impl SegmentUpdate {
+    /// Replace the translated text
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Also move the segment
+    pub fn timing(mut self, start_time: f64, end_time: f64) -> Self {
+        self.start_time = Some(start_time);
+        self.end_time = Some(end_time);
+        self
+    }
+}
+
+/// File produced by rendering a language
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RenderType {
+    Mp4,
+    Aac,
+    Mp3,
+    Wav,
+    /// Advanced Authoring Format, for video editors
+    Aaf,
+    /// One audio file per speaker track, zipped
+    TracksZip,
+    /// One audio file per segment, zipped
+    ClipsZip,
+}
+
+impl RenderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RenderType::Mp4 => "mp4",
+            RenderType::Aac => "aac",
+            RenderType::Mp3 => "mp3",
+            RenderType::Wav => "wav",
+            RenderType::Aaf => "aaf",
+            RenderType::TracksZip => "tracks_zip",
+            RenderType::ClipsZip => "clips_zip",
+        }
+    }
+}
+
+impl fmt::Display for RenderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A render started by [`Dubbing::render`]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DubbingRender {
+    pub render_id: String,
+
+    /// Version of the resource the render was made from
+    #[serde(default)]
+    pub version: u64,
+}
+
+#[derive(Deserialize)]
+struct VersionResponse {
+    version: u64,
+}
+
+/// Request body for regenerating dubbed audio
+#[derive(Serialize)]
+struct DubSegmentsRequest<'a> {
+    segments: &'a [String],
+    languages: &'a [String],
+}
+
+/// Request body for rendering a language
+#[derive(Serialize)]
+struct RenderRequest {
+    render_type: RenderType,
+}
+
+/// Dubbing resource endpoints
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::dubbing::{RenderType, SegmentUpdate};
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let dubbing = client.dubbing();
+/// let resource = dubbing.resource("dub_123").await?;
+/// for segment in resource.segments() {
+///     println!("{:.1}s {} -> {:?}", segment.start_time, segment.text, segment.dubs.get("es"));
+/// }
+///
+/// // Fix one Spanish line, re-dub just that segment and render Spanish again
+/// let segment_id = resource.segments()[0].id.clone();
+/// dubbing
+///     .update_segment("dub_123", &segment_id, "es", &SegmentUpdate::text("¡Hola a todos!"))
+///     .await?;
+/// dubbing.dub_segments("dub_123", &[segment_id], &["es".to_string()]).await?;
+/// let render = dubbing.render("dub_123", "es", RenderType::Mp4).await?;
+/// println!("Rendering {}", render.render_id);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Dubbing {
+    client: ElevenLabsTTSClient,
+}
+
+impl Dubbing {
+    pub(crate) fn new(client: ElevenLabsTTSClient) -> Self {
+        Self { client }
+    }
+
+    /// Get a dub's speakers, segments and translations
+    pub async fn resource(&self, dubbing_id: &str) -> Result<DubbingResource, ElevenLabsTTSError> {
+        let url = self.url(dubbing_id, "")?;
+        self.client.get_json("dubbing-resource", &url, &[]).await
+    }
+
+    /// Change a segment's translation in one language, returning the new resource version.
+    /// The audio is only regenerated by [`dub_segments`](Self::dub_segments).
+    pub async fn update_segment(
+        &self,
+        dubbing_id: &str,
+        segment_id: &str,
+        language: &str,
+        update: &SegmentUpdate,
+    ) -> Result<u64, ElevenLabsTTSError> {
+        if let Some(text) = &update.text {
+            if text.trim().is_empty() {
+                return Err(ElevenLabsTTSError::ValidationError(
+                    "Segment text cannot be empty".to_string(),
+                ));
+            }
+        }
+        if let (Some(start), Some(end)) = (update.start_time, update.end_time) {
+            if start >= end {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Segment must end after it starts, got {} to {}",
+                    start, end
+                )));
+            }
+        }
+        let url = self.url(
+            dubbing_id,
+            &format!(
+                "/segment/{}/{}",
+                ids::check_path_id("Segment", segment_id)?,
+                ids::check_path_id("Language", language)?
+            ),
+        )?;
+        let response: VersionResponse = self
+            .client
+            .patch_for_json("dubbing-segment-update", &url, update)
+            .await?;
+        Ok(response.version)
+    }
+
+    /// Regenerate the dubbed audio of some segments in some languages, e.g. after
+    /// editing them, returning the new resource version
+    pub async fn dub_segments(
+        &self,
+        dubbing_id: &str,
+        segment_ids: &[String],
+        languages: &[String],
+    ) -> Result<u64, ElevenLabsTTSError> {
+        if segment_ids.is_empty() || languages.is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Dubbing needs at least one segment and one language".to_string(),
+            ));
+        }
+        let url = self.url(dubbing_id, "/dub")?;
+        let request = DubSegmentsRequest {
+            segments: segment_ids,
+            languages,
+        };
+        let response: VersionResponse = self
+            .client
+            .post_for_json(
+                "dubbing-segments-dub",
+                &url,
+                &[],
+                &request,
+                &RequestOptions::default(),
+            )
+            .await?;
+        Ok(response.version)
+    }
+
+    /// Start rendering one language of the dub into a file of `render_type`
+    pub async fn render(
+        &self,
+        dubbing_id: &str,
+        language: &str,
+        render_type: RenderType,
+    ) -> Result<DubbingRender, ElevenLabsTTSError> {
+        let url = self.url(
+            dubbing_id,
+            &format!("/render/{}", ids::check_path_id("Language", language)?),
+        )?;
+        self.client
+            .post_for_json(
+                "dubbing-render",
+                &url,
+                &[],
+                &RenderRequest { render_type },
+                &RequestOptions::default(),
+            )
+            .await
+    }
+
+    /// URL of a path under a dubbing resource
+    fn url(&self, dubbing_id: &str, path: &str) -> Result<String, ElevenLabsTTSError> {
+        Ok(format!(
+            "{}/dubbing/resource/{}{}",
+            self.client.inner.base_url,
+            ids::check_path_id("Dubbing", dubbing_id)?,
+            path
+        ))
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt;
 
+use reqwest::header::HeaderMap;
+
 /// All possible errors that can occur when using the ElevenLabs API
 #[derive(Debug)]
 pub enum ElevenLabsTTSError {
@@ -7,7 +9,15 @@ pub enum ElevenLabsTTSError {
     RequestError(reqwest::Error),
 
     /// API returned an error status code
-    ApiError { status: u16, message: String },
+    ApiError {
+        status: u16,
+        message: String,
+        /// Structured error payload, when the body could be parsed
+        /// (boxed to keep the error small)
+        detail: Option<Box<ApiErrorDetail>>,
+        /// The `request-id` response header, to quote in support tickets
+        request_id: Option<String>,
+    },
 
     /// Failed to parse JSON response
     ParseError(serde_json::Error),
@@ -19,22 +29,62 @@ pub enum ElevenLabsTTSError {
     RateLimitError {
         retry_after: Option<u64>, // seconds
         message: String,
+        /// The `request-id` response header, to quote in support tickets
+        request_id: Option<String>,
     },
 
-    /// Quota exceeded (not enough credits)
-    QuotaExceededError(String),
+    /// Quota exceeded (not enough credits). Usage is filled in when the API reports it.
+    QuotaExceededError {
+        /// Characters used in the current billing period
+        used: Option<u64>,
+        /// Character limit of the current billing period
+        limit: Option<u64>,
+        message: String,
+        /// The `request-id` response header, to quote in support tickets
+        request_id: Option<String>,
+    },
 
     /// Invalid input parameters
     ValidationError(String),
+
+    /// The circuit breaker is open after repeated failures; the request was not sent
+    CircuitOpenError {
+        retry_after: u64, // seconds
+    },
+
+    /// Writing the audio to a file or writer failed
+    IoError(std::io::Error),
+
+    /// Playing the audio on the output device failed (`playback` feature)
+    PlaybackError(String),
+
+    /// The audio could not be decoded to samples (`decode` feature)
+    DecodeError(String),
+
+    /// A webhook's signature is missing, does not match or is too old
+    WebhookError(String),
+
+    /// The WebSocket connection failed or the server reported an error (`websocket` feature)
+    WebSocketError(String),
+
+    /// The API closed the WebSocket session after receiving no text within its inactivity
+    /// timeout (`websocket` feature); see `WebSocketBuilder::keepalive`
+    WebSocketTimeout(String),
 }
 
 impl fmt::Display for ElevenLabsTTSError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ElevenLabsTTSError::RequestError(e) => write!(f, "Request failed: {}", e),
-            ElevenLabsTTSError::ApiError { status, message } => {
-                write!(f, "API error ({}): {}", status, message)
-            }
+            ElevenLabsTTSError::ApiError {
+                status,
+                message,
+                detail,
+                ..
+            } => match detail.as_ref().and_then(|d| d.status.as_deref()) {
+                Some(code) => write!(f, "API error ({}, {}): {}", status, code, message),
+                None => write!(f, "API error ({}): {}", status, message),
+            },
             ElevenLabsTTSError::ParseError(e) => write!(f, "Failed to parse response: {}", e),
             ElevenLabsTTSError::AuthenticationError(msg) => {
                 write!(f, "Authentication failed: {}", msg)
@@ -42,6 +92,7 @@ impl fmt::Display for ElevenLabsTTSError {
             ElevenLabsTTSError::RateLimitError {
                 retry_after,
                 message,
+                ..
             } => match retry_after {
                 Some(seconds) => write!(
                     f,
@@ -50,8 +101,39 @@ impl fmt::Display for ElevenLabsTTSError {
                 ),
                 None => write!(f, "Rate limit exceeded: {}", message),
             },
-            ElevenLabsTTSError::QuotaExceededError(msg) => write!(f, "Quota exceeded: {}", msg),
+            ElevenLabsTTSError::QuotaExceededError {
+                used: Some(used),
+                limit: Some(limit),
+                message,
+                ..
+            } => write!(
+                f,
+                "Quota exceeded ({} of {} characters used): {}",
+                used, limit, message
+            ),
+            ElevenLabsTTSError::QuotaExceededError { message, .. } => {
+                write!(f, "Quota exceeded: {}", message)
+            }
             ElevenLabsTTSError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
+            ElevenLabsTTSError::CircuitOpenError { retry_after } => write!(
+                f,
+                "Circuit breaker open after repeated API failures (retry in {}s)",
+                retry_after
+            ),
+            ElevenLabsTTSError::IoError(e) => write!(f, "I/O error: {}", e),
+            ElevenLabsTTSError::PlaybackError(msg) => write!(f, "Playback failed: {}", msg),
+            ElevenLabsTTSError::DecodeError(msg) => write!(f, "Decoding failed: {}", msg),
+            ElevenLabsTTSError::WebhookError(msg) => {
+                write!(f, "Webhook verification failed: {}", msg)
+            }
+            ElevenLabsTTSError::WebSocketError(msg) => write!(f, "WebSocket error: {}", msg),
+            ElevenLabsTTSError::WebSocketTimeout(msg) => {
+                write!(f, "WebSocket session timed out: {}", msg)
+            }
+        }?;
+        match self.request_id() {
+            Some(request_id) => write!(f, " (request-id: {})", request_id),
+            None => Ok(()),
         }
     }
 }
@@ -61,6 +143,7 @@ impl std::error::Error for ElevenLabsTTSError {
         match self {
             ElevenLabsTTSError::RequestError(e) => Some(e),
             ElevenLabsTTSError::ParseError(e) => Some(e),
+            ElevenLabsTTSError::IoError(e) => Some(e),
             _ => None,
         }
     }
@@ -78,12 +161,20 @@ impl From<reqwest::Error> for ElevenLabsTTSError {
                     ElevenLabsTTSError::RateLimitError {
                         retry_after: None, // Could be enhanced to parse Retry-After header
                         message: "Too many requests".to_string(),
+                        request_id: None,
                     }
                 }
-                402 => ElevenLabsTTSError::QuotaExceededError("Insufficient credits".to_string()),
+                402 => ElevenLabsTTSError::QuotaExceededError {
+                    used: None,
+                    limit: None,
+                    message: "Insufficient credits".to_string(),
+                    request_id: None,
+                },
                 _ => ElevenLabsTTSError::ApiError {
                     status: status_code,
                     message: error.to_string(),
+                    detail: None,
+                    request_id: None,
                 },
             }
         } else {
@@ -92,8 +183,436 @@ impl From<reqwest::Error> for ElevenLabsTTSError {
     }
 }
 
+impl From<std::io::Error> for ElevenLabsTTSError {
+    fn from(error: std::io::Error) -> Self {
+        ElevenLabsTTSError::IoError(error)
+    }
+}
+
+impl ElevenLabsTTSError {
+    /// Build the error for a non-success response from its status, headers and body
+    pub(crate) fn from_response_parts(status: u16, headers: &HeaderMap, body: String) -> Self {
+        let detail = ApiErrorDetail::parse(&body);
+        let (used, limit) = parse_quota_usage(&body);
+        let request_id = headers
+            .get("request-id")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let message = detail
+            .as_ref()
+            .map(|d| d.message.clone())
+            .filter(|m| !m.is_empty())
+            .unwrap_or(body);
+
+        match status {
+            429 => ElevenLabsTTSError::RateLimitError {
+                retry_after: parse_retry_after(headers),
+                message,
+                request_id,
+            },
+            401 | 402
+                if used.is_some()
+                    || limit.is_some()
+                    || detail.as_ref().is_some_and(|d| d.is("quota_exceeded")) =>
+            {
+                ElevenLabsTTSError::QuotaExceededError {
+                    used,
+                    limit,
+                    message,
+                    request_id,
+                }
+            }
+            _ => ElevenLabsTTSError::ApiError {
+                status,
+                message,
+                detail: detail.map(Box::new),
+                request_id,
+            },
+        }
+    }
+
+    /// The `request-id` of the failed response, to include in support tickets to ElevenLabs
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ElevenLabsTTSError::ApiError { request_id, .. }
+            | ElevenLabsTTSError::RateLimitError { request_id, .. }
+            | ElevenLabsTTSError::QuotaExceededError { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Structured error payload returned by the API, if any
+    pub fn api_error_detail(&self) -> Option<&ApiErrorDetail> {
+        match self {
+            ElevenLabsTTSError::ApiError { detail, .. } => detail.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Machine-readable error code returned by the API, e.g. `voice_not_found`
+    pub fn api_error_code(&self) -> Option<&str> {
+        self.api_error_detail().and_then(|d| d.status.as_deref())
+    }
+
+    /// HTTP status of the error response, when the error came from one.
+    /// Quota errors don't record theirs (the API answers them with 401 or 402).
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            ElevenLabsTTSError::RequestError(e) => e.status().map(|status| status.as_u16()),
+            ElevenLabsTTSError::ApiError { status, .. } => Some(*status),
+            ElevenLabsTTSError::AuthenticationError(_) => Some(401),
+            ElevenLabsTTSError::RateLimitError { .. } => Some(429),
+            _ => None,
+        }
+    }
+
+    /// Whether the API key was rejected or lacks permissions (401/403), so a new key is needed
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            ElevenLabsTTSError::AuthenticationError(_) => true,
+            ElevenLabsTTSError::ApiError { status, .. } => matches!(status, 401 | 403),
+            _ => false,
+        }
+    }
+
+    /// Whether the request itself or the account is at fault (4xx other than 429, quota,
+    /// local validation), so sending it again unchanged won't help
+    pub fn is_client_error(&self) -> bool {
+        match self {
+            ElevenLabsTTSError::ValidationError(_)
+            | ElevenLabsTTSError::AuthenticationError(_)
+            | ElevenLabsTTSError::QuotaExceededError { .. } => true,
+            _ => self
+                .status_code()
+                .is_some_and(|status| (400..500).contains(&status) && status != 429),
+        }
+    }
+
+    /// Characters left in the billing period, when a quota error reported its usage
+    pub fn quota_remaining(&self) -> Option<u64> {
+        match self {
+            ElevenLabsTTSError::QuotaExceededError {
+                used: Some(used),
+                limit: Some(limit),
+                ..
+            } => Some(limit.saturating_sub(*used)),
+            _ => None,
+        }
+    }
+
+    /// Whether the request certainly never reached generation, so retrying it can't
+    /// double-bill characters (connection refused, 429, 503)
+    pub fn is_safe_to_retry(&self) -> bool {
+        match self {
+            ElevenLabsTTSError::RequestError(e) => request_error_never_sent(e),
+            ElevenLabsTTSError::ApiError { status, .. } => status_is_safe_to_retry(*status),
+            ElevenLabsTTSError::RateLimitError { .. } => true,
+            ElevenLabsTTSError::CircuitOpenError { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the failure is transient and may succeed when retried.
+    /// Unlike [`is_safe_to_retry`](Self::is_safe_to_retry), this includes outcomes that are
+    /// undetermined (timeouts, resets, 500/502/504), where the audio may already have been billed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ElevenLabsTTSError::RequestError(e) => request_error_is_transient(e),
+            ElevenLabsTTSError::ApiError { status, .. } => status_is_transient(*status),
+            ElevenLabsTTSError::RateLimitError { .. } => true,
+            ElevenLabsTTSError::CircuitOpenError { .. } => true,
+            _ => false,
+        }
+    }
+}
+
+/// Statuses meaning the API rejected the request before doing any work
+pub(crate) fn status_is_safe_to_retry(status: u16) -> bool {
+    matches!(status, 429 | 503)
+}
+
+/// Statuses that describe a temporary condition on the API side
+pub(crate) fn status_is_transient(status: u16) -> bool {
+    matches!(status, 429 | 500 | 502 | 503 | 504)
+}
+
+/// The connection could not be established, so nothing was sent
+pub(crate) fn request_error_never_sent(error: &reqwest::Error) -> bool {
+    error.status().is_none() && error.is_connect()
+}
+
+/// The connection failed, reset or timed out; the request may or may not have been processed
+pub(crate) fn request_error_is_transient(error: &reqwest::Error) -> bool {
+    error.status().is_none() && (error.is_connect() || error.is_timeout() || error.is_request())
+}
+
+/// Structured error payload returned by the ElevenLabs API
+///
+/// The API answers errors with a `detail` field that is either an object
+/// (`{"status": "voice_not_found", "message": "..."}`), a list of validation
+/// problems (`[{"loc": ["body", "text"], "msg": "...", "type": "..."}]`) or a plain string.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApiErrorDetail {
+    /// Machine-readable error code, e.g. `voice_not_found` or `quota_exceeded`
+    pub status: Option<String>,
+
+    /// Human-readable error message
+    pub message: String,
+
+    /// Field-level problems reported by request validation (HTTP 422)
+    pub validation_errors: Vec<ValidationIssue>,
+}
+
+/// A single field-level validation problem reported by the API
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationIssue {
+    /// Path to the offending field, e.g. `["body", "voice_settings", "stability"]`
+    pub loc: Vec<String>,
+
+    /// Human-readable description of the problem
+    pub msg: String,
+
+    /// Machine-readable problem type, e.g. `value_error`
+    pub kind: Option<String>,
+}
+
+impl ApiErrorDetail {
+    /// Parse an error response body, returning `None` when it has no `detail` field
+    pub fn parse(body: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(body).ok()?;
+        let detail = value.get("detail")?;
+        let text = |v: &serde_json::Value| match v {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+
+        match detail {
+            serde_json::Value::String(message) => Some(Self {
+                message: message.clone(),
+                ..Self::default()
+            }),
+            serde_json::Value::Object(fields) => Some(Self {
+                status: fields.get("status").map(text),
+                message: fields.get("message").map(text).unwrap_or_default(),
+                validation_errors: Vec::new(),
+            }),
+            serde_json::Value::Array(items) => {
+                let validation_errors: Vec<ValidationIssue> = items
+                    .iter()
+                    .map(|item| ValidationIssue {
+                        loc: item
+                            .get("loc")
+                            .and_then(|loc| loc.as_array())
+                            .map(|loc| loc.iter().map(text).collect())
+                            .unwrap_or_default(),
+                        msg: item.get("msg").map(text).unwrap_or_default(),
+                        kind: item.get("type").map(text),
+                    })
+                    .collect();
+
+                let message = validation_errors
+                    .iter()
+                    .map(|issue| format!("{}: {}", issue.loc.join("."), issue.msg))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+
+                Some(Self {
+                    status: None,
+                    message,
+                    validation_errors,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the API reported the given error code
+    pub fn is(&self, status: &str) -> bool {
+        self.status.as_deref() == Some(status)
+    }
+}
+
+/// `character_used`/`character_limit` of a quota error, from `detail` or the top level
+fn parse_quota_usage(body: &str) -> (Option<u64>, Option<u64>) {
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(body) else {
+        return (None, None);
+    };
+    let field = |name: &str| {
+        value
+            .get("detail")
+            .and_then(|detail| detail.get(name))
+            .or_else(|| value.get(name))
+            .and_then(serde_json::Value::as_u64)
+    };
+    (field("character_used"), field("character_limit"))
+}
+
+/// Read how long to wait from `Retry-After` (delta-seconds or HTTP-date),
+/// falling back to the `x-ratelimit-reset` style headers when present
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<u64> {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    if let Some(value) = header("retry-after") {
+        let value = value.trim();
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(seconds);
+        }
+        if let Ok(date) = chrono::DateTime::parse_from_rfc2822(value) {
+            let seconds = date.timestamp() - chrono::Utc::now().timestamp();
+            return Some(seconds.max(0) as u64);
+        }
+    }
+
+    ["x-ratelimit-reset-after", "x-ratelimit-reset-requests"]
+        .iter()
+        .find_map(|name| header(name))
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .map(|seconds| seconds.max(0.0).ceil() as u64)
+}
+
 impl From<serde_json::Error> for ElevenLabsTTSError {
     fn from(error: serde_json::Error) -> Self {
         ElevenLabsTTSError::ParseError(error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_rate_limit_error_carries_retry_after() {
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("12"));
+
+        let error = ElevenLabsTTSError::from_response_parts(429, &headers, "slow down".into());
+        assert!(matches!(
+            error,
+            ElevenLabsTTSError::RateLimitError {
+                retry_after: Some(12),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_structured_error_detail() {
+        let body = r#"{"detail":{"status":"voice_not_found","message":"A voice with the voice_id abc was not found."}}"#;
+        let error = ElevenLabsTTSError::from_response_parts(404, &HeaderMap::new(), body.into());
+
+        assert_eq!(error.api_error_code(), Some("voice_not_found"));
+        assert!(error.to_string().contains("was not found"));
+        assert_eq!(error.request_id(), None);
+    }
+
+    #[test]
+    fn test_error_keeps_request_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert("request-id", HeaderValue::from_static("req_123"));
+
+        let error = ElevenLabsTTSError::from_response_parts(500, &headers, "boom".into());
+        assert_eq!(error.request_id(), Some("req_123"));
+        assert_eq!(
+            error.to_string(),
+            "API error (500): boom (request-id: req_123)"
+        );
+
+        let error = ElevenLabsTTSError::from_response_parts(429, &headers, String::new());
+        assert_eq!(error.request_id(), Some("req_123"));
+    }
+
+    #[test]
+    fn test_quota_error_usage() {
+        let body = r#"{"detail":{"status":"quota_exceeded","message":"This request exceeds your quota.","character_used":9950,"character_limit":10000}}"#;
+        let error = ElevenLabsTTSError::from_response_parts(401, &HeaderMap::new(), body.into());
+
+        assert!(matches!(
+            error,
+            ElevenLabsTTSError::QuotaExceededError {
+                used: Some(9950),
+                limit: Some(10000),
+                ..
+            }
+        ));
+        assert_eq!(error.quota_remaining(), Some(50));
+        assert_eq!(
+            error.to_string(),
+            "Quota exceeded (9950 of 10000 characters used): This request exceeds your quota."
+        );
+
+        let body = r#"{"detail":{"status":"quota_exceeded","message":"Out of credits"}}"#;
+        let error = ElevenLabsTTSError::from_response_parts(402, &HeaderMap::new(), body.into());
+        assert!(matches!(
+            error,
+            ElevenLabsTTSError::QuotaExceededError {
+                used: None,
+                limit: None,
+                ..
+            }
+        ));
+
+        // Other authentication failures stay API errors
+        let body = r#"{"detail":{"status":"invalid_api_key","message":"Invalid API key"}}"#;
+        let error = ElevenLabsTTSError::from_response_parts(401, &HeaderMap::new(), body.into());
+        assert_eq!(error.api_error_code(), Some("invalid_api_key"));
+    }
+
+    #[test]
+    fn test_validation_error_detail() {
+        let body = r#"{"detail":[{"loc":["body","voice_settings","stability"],"msg":"value is not a valid float","type":"type_error.float"}]}"#;
+        let detail = ApiErrorDetail::parse(body).unwrap();
+
+        assert_eq!(detail.validation_errors.len(), 1);
+        assert_eq!(
+            detail.validation_errors[0].loc,
+            vec!["body", "voice_settings", "stability"]
+        );
+        assert_eq!(
+            detail.message,
+            "body.voice_settings.stability: value is not a valid float"
+        );
+        assert!(ApiErrorDetail::parse("not json").is_none());
+    }
+
+    #[test]
+    fn test_retry_classification() {
+        let headers = HeaderMap::new();
+        let rate_limited = ElevenLabsTTSError::from_response_parts(429, &headers, String::new());
+        let server_error = ElevenLabsTTSError::from_response_parts(500, &headers, String::new());
+        let not_found = ElevenLabsTTSError::from_response_parts(404, &headers, String::new());
+
+        assert!(rate_limited.is_safe_to_retry() && rate_limited.is_retryable());
+        assert!(!server_error.is_safe_to_retry() && server_error.is_retryable());
+        assert!(!not_found.is_safe_to_retry() && !not_found.is_retryable());
+    }
+
+    #[test]
+    fn test_error_classification_helpers() {
+        let headers = HeaderMap::new();
+        let unauthorized = ElevenLabsTTSError::from_response_parts(401, &headers, String::new());
+        let rate_limited = ElevenLabsTTSError::from_response_parts(429, &headers, String::new());
+        let server_error = ElevenLabsTTSError::from_response_parts(502, &headers, String::new());
+        let invalid = ElevenLabsTTSError::ValidationError("Text cannot be empty".into());
+
+        assert_eq!(unauthorized.status_code(), Some(401));
+        assert!(unauthorized.is_auth_error() && unauthorized.is_client_error());
+        assert_eq!(rate_limited.status_code(), Some(429));
+        assert!(!rate_limited.is_client_error() && rate_limited.is_retryable());
+        assert_eq!(server_error.status_code(), Some(502));
+        assert!(!server_error.is_client_error() && !server_error.is_auth_error());
+        assert_eq!(invalid.status_code(), None);
+        assert!(invalid.is_client_error() && !invalid.is_retryable());
+    }
+
+    #[test]
+    fn test_retry_after_http_date_in_the_past() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(parse_retry_after(&headers), Some(0));
+        assert_eq!(parse_retry_after(&HeaderMap::new()), None);
+    }
+}
@@ -0,0 +1,297 @@
+use std::fmt;
+
+use futures_util::stream::BoxStream;
+use serde::Deserialize;
+
+use crate::models::ModelId;
+use crate::{ElevenLabsTTSClient, ElevenLabsTTSError, VoiceSettings, paginate};
+
+/// Largest page the history endpoint returns
+pub const MAX_HISTORY_PAGE_SIZE: u32 = 1000;
+
+/// A past generation stored in the account's history
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HistoryItem {
+    pub history_item_id: String,
+
+    /// ID of the original request, usable as `previous_request_ids` when stitching
+    #[serde(default)]
+    pub request_id: Option<String>,
+
+    #[serde(default)]
+    pub voice_id: Option<String>,
+
+    #[serde(default)]
+    pub voice_name: Option<String>,
+
+    #[serde(default)]
+    pub model_id: Option<ModelId>,
+
+    /// The text that was converted
+    #[serde(default)]
+    pub text: Option<String>,
+
+    /// When the audio was generated (Unix timestamp, seconds)
+    #[serde(default)]
+    pub date_unix: Option<i64>,
+
+    /// Character quota used before the generation
+    #[serde(default)]
+    pub character_count_change_from: Option<i64>,
+
+    /// Character quota used after the generation
+    #[serde(default)]
+    pub character_count_change_to: Option<i64>,
+
+    /// MIME type of the stored audio, e.g. `audio/mpeg`
+    #[serde(default)]
+    pub content_type: Option<String>,
+
+    #[serde(default)]
+    pub state: HistoryItemState,
+
+    /// The voice settings the audio was generated with
+    #[serde(default)]
+    pub settings: Option<VoiceSettings>,
+
+    /// What produced the item, e.g. `TTS` or `STS`
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl HistoryItem {
+    /// Characters the generation was billed for
+    pub fn character_count_change(&self) -> Option<i64> {
+        Some(self.character_count_change_to? - self.character_count_change_from?)
+    }
+}
+
+/// Processing state of a history item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryItemState {
+    Created,
+    Deleted,
+    Processing,
+    /// A state this version of the crate does not know about
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// One page of history items, newest first
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HistoryPage {
+    pub history: Vec<HistoryItem>,
+
+    /// Pass to [`HistoryList::start_after`] to get the next page
+    #[serde(default)]
+    pub last_history_item_id: Option<String>,
+
+    /// Whether more items follow this page
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// Endpoint that produced a history item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HistorySource {
+    /// Text to speech
+    TextToSpeech,
+    /// Speech to speech (voice changer)
+    SpeechToSpeech,
+}
+
+impl HistorySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HistorySource::TextToSpeech => "TTS",
+            HistorySource::SpeechToSpeech => "STS",
+        }
+    }
+}
+
+impl fmt::Display for HistorySource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Generation history endpoints
+pub struct History {
+    client: ElevenLabsTTSClient,
+}
+
+impl History {
+    pub(crate) fn new(client: ElevenLabsTTSClient) -> Self {
+        Self { client }
+    }
+
+    /// Start listing history items, newest first
+    pub fn list(&self) -> HistoryList {
+        HistoryList::new(self.client.clone())
+    }
+}
+
+/// Builder for a listing of the generation history
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::history::HistorySource;
+/// use futures_util::TryStreamExt;
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// // Everything generated for one narrator in September 2025
+/// let items: Vec<_> = client
+///     .history()
+///     .list()
+///     .voice_id("21m00Tcm4TlvDq8ikWAM")
+///     .source(HistorySource::TextToSpeech)
+///     .date_after(1_756_684_800)
+///     .date_before(1_759_276_800)
+///     .list_all()
+///     .try_collect()
+///     .await?;
+/// println!("{} generations", items.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct HistoryList {
+    client: ElevenLabsTTSClient,
+    page_size: Option<u32>,
+    start_after: Option<String>,
+    voice_id: Option<String>,
+    model_id: Option<ModelId>,
+    date_after: Option<i64>,
+    date_before: Option<i64>,
+    search: Option<String>,
+    source: Option<HistorySource>,
+}
+
+impl HistoryList {
+    fn new(client: ElevenLabsTTSClient) -> Self {
+        Self {
+            client,
+            page_size: None,
+            start_after: None,
+            voice_id: None,
+            model_id: None,
+            date_after: None,
+            date_before: None,
+            search: None,
+            source: None,
+        }
+    }
+
+    /// Only items generated with this voice
+    pub fn voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Only items generated with this model
+    pub fn model<M: Into<ModelId>>(mut self, model_id: M) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+
+    /// Only items generated at or after this Unix timestamp (seconds)
+    pub fn date_after(mut self, date_unix: i64) -> Self {
+        self.date_after = Some(date_unix);
+        self
+    }
+
+    /// Only items generated before this Unix timestamp (seconds)
+    pub fn date_before(mut self, date_unix: i64) -> Self {
+        self.date_before = Some(date_unix);
+        self
+    }
+
+    /// Only items whose text contains this term
+    pub fn search<S: Into<String>>(mut self, search: S) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    /// Only items produced by this endpoint
+    pub fn source(mut self, source: HistorySource) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Items per page (1 - 1000, the API defaults to 100)
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Continue after this item, e.g. a previous page's [`HistoryPage::last_history_item_id`]
+    pub fn start_after<S: Into<String>>(mut self, history_item_id: S) -> Self {
+        self.start_after = Some(history_item_id.into());
+        self
+    }
+
+    /// The query parameters of the listing
+    pub fn query(&self) -> Result<Vec<(&'static str, String)>, ElevenLabsTTSError> {
+        let mut query = Vec::new();
+        if let Some(page_size) = self.page_size {
+            if !(1..=MAX_HISTORY_PAGE_SIZE).contains(&page_size) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Page size must be between 1 and {}, got {}",
+                    MAX_HISTORY_PAGE_SIZE, page_size
+                )));
+            }
+            query.push(("page_size", page_size.to_string()));
+        }
+        if let (Some(after), Some(before)) = (self.date_after, self.date_before) {
+            if after >= before {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Date range is empty: after {} is not before {}",
+                    after, before
+                )));
+            }
+        }
+        if let Some(start_after) = &self.start_after {
+            query.push(("start_after_history_item_id", start_after.clone()));
+        }
+        if let Some(voice_id) = &self.voice_id {
+            query.push(("voice_id", voice_id.clone()));
+        }
+        if let Some(model_id) = &self.model_id {
+            query.push(("model_id", model_id.to_string()));
+        }
+        if let Some(after) = self.date_after {
+            query.push(("date_after_unix", after.to_string()));
+        }
+        if let Some(before) = self.date_before {
+            query.push(("date_before_unix", before.to_string()));
+        }
+        if let Some(search) = &self.search {
+            query.push(("search", search.clone()));
+        }
+        if let Some(source) = self.source {
+            query.push(("source", source.to_string()));
+        }
+        Ok(query)
+    }
+
+    /// Fetch one page of history items
+    pub async fn execute(&self) -> Result<HistoryPage, ElevenLabsTTSError> {
+        let query = self.query()?;
+        let url = format!("{}/history", self.client.inner.base_url);
+        self.client.get_json("history", &url, &query).await
+    }
+
+    /// Every matching item, fetching the following pages as the stream is consumed.
+    /// The stream ends after the first error.
+    pub fn list_all(self) -> BoxStream<'static, Result<HistoryItem, ElevenLabsTTSError>> {
+        paginate(self, |list| async move {
+            let page = list.execute().await?;
+            let next = page
+                .last_history_item_id
+                .filter(|_| page.has_more)
+                .map(|last| list.start_after(last));
+            Ok((page.history, next))
+        })
+    }
+}
@@ -0,0 +1,189 @@
+//! Validated identifiers for voices and generations
+//!
+//! Both convert into `String`, so every builder setter that takes a voice or request ID
+//! accepts them as well as plain strings.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ElevenLabsTTSError, StaticVoice, voices};
+
+/// Length of every ElevenLabs voice ID
+pub const VOICE_ID_LENGTH: usize = 20;
+
+/// Longest request ID accepted by [`RequestId::new`]
+pub const MAX_REQUEST_ID_LENGTH: usize = 64;
+
+/// The ID of a voice: 20 ASCII letters and digits, such as `21m00Tcm4TlvDq8ikWAM`
+///
+/// Parsing rejects voice names, which are the most common mix-up:
+///
+/// ```rust
+/// use elevenlabs_tts::VoiceId;
+///
+/// assert!("21m00Tcm4TlvDq8ikWAM".parse::<VoiceId>().is_ok());
+/// let error = "Rachel".parse::<VoiceId>().unwrap_err();
+/// assert!(error.to_string().contains("21m00Tcm4TlvDq8ikWAM"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VoiceId(String);
+
+impl VoiceId {
+    /// Validate a voice ID
+    pub fn new<S: AsRef<str>>(voice_id: S) -> Result<Self, ElevenLabsTTSError> {
+        let voice_id = voice_id.as_ref().trim();
+        if is_voice_id(voice_id) {
+            return Ok(VoiceId(voice_id.to_string()));
+        }
+        Err(ElevenLabsTTSError::ValidationError(
+            match voices::find_by_name(voice_id) {
+                Some(voice) => voice_name_error(voice_id, voice),
+                None => format!(
+                    "Invalid voice ID {:?}: expected {} ASCII letters and digits",
+                    voice_id, VOICE_ID_LENGTH
+                ),
+            },
+        ))
+    }
+
+    /// The voice ID as sent in request paths and bodies
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The ID of a generation, from the `request-id` response header, used to stitch
+/// generations together with `previous_request_ids`/`next_request_ids`
+///
+/// ```rust
+/// use elevenlabs_tts::RequestId;
+///
+/// assert!("5Ntc2Q9sYqDhHgn1GxRA".parse::<RequestId>().is_ok());
+/// assert!("not a request id".parse::<RequestId>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestId(String);
+
+impl RequestId {
+    /// Validate a request ID: 1 to 64 ASCII letters, digits, `-` or `_`
+    pub fn new<S: AsRef<str>>(request_id: S) -> Result<Self, ElevenLabsTTSError> {
+        let request_id = request_id.as_ref().trim();
+        let valid = (1..=MAX_REQUEST_ID_LENGTH).contains(&request_id.len())
+            && request_id
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+        if !valid {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Invalid request ID {:?}: expected up to {} ASCII letters, digits, '-' or '_'",
+                request_id, MAX_REQUEST_ID_LENGTH
+            )));
+        }
+        Ok(RequestId(request_id.to_string()))
+    }
+
+    /// The request ID as sent in `previous_request_ids`/`next_request_ids`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+fn is_voice_id(voice_id: &str) -> bool {
+    voice_id.len() == VOICE_ID_LENGTH && voice_id.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+fn voice_name_error(name: &str, voice: &StaticVoice) -> String {
+    format!(
+        "{:?} is a voice name, not a voice ID; {}'s ID is {}",
+        name, voice.name, voice.voice_id
+    )
+}
+
+/// Catch a pre-made voice's name passed where its ID belongs. Other strings are left to
+/// the API, so IDs of test servers and proxies keep working.
+pub(crate) fn check_not_voice_name(voice_id: &str) -> Result<(), ElevenLabsTTSError> {
+    match voices::find_by_name(voice_id) {
+        Some(voice) if !is_voice_id(voice_id) => Err(ElevenLabsTTSError::ValidationError(
+            voice_name_error(voice_id, voice),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reject an ID that can't be a single URL path segment: an empty ID, or one with `/`,
+/// `?` or `#`, would silently address a different endpoint
+pub(crate) fn check_path_id<'a>(kind: &str, id: &'a str) -> Result<&'a str, ElevenLabsTTSError> {
+    if id.trim().is_empty() {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "{} ID cannot be empty",
+            kind
+        )));
+    }
+    if let Some(c) = id.chars().find(|c| matches!(c, '/' | '?' | '#')) {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "{} ID {:?} cannot contain {:?}",
+            kind, id, c
+        )));
+    }
+    Ok(id)
+}
+
+impl From<&StaticVoice> for VoiceId {
+    fn from(voice: &StaticVoice) -> Self {
+        VoiceId(voice.voice_id.to_string())
+    }
+}
+
+macro_rules! string_id {
+    ($id:ident) => {
+        impl fmt::Display for $id {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl FromStr for $id {
+            type Err = ElevenLabsTTSError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $id::new(s)
+            }
+        }
+
+        impl AsRef<str> for $id {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        // Lets the builders' setters take the ID or a plain string
+        impl From<$id> for String {
+            fn from(id: $id) -> Self {
+                id.0
+            }
+        }
+
+        impl From<&$id> for String {
+            fn from(id: &$id) -> Self {
+                id.0.clone()
+            }
+        }
+
+        impl Serialize for $id {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $id {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                value.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+string_id!(VoiceId);
+string_id!(RequestId);
@@ -0,0 +1,166 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ElevenLabsTTSError;
+
+/// An ISO 639-1 language code, used to enforce the language of a generation
+///
+/// Only controls pronunciation and accent; the text is never translated.
+/// Constants are provided for the languages supported by the multilingual models.
+///
+/// ```rust
+/// use elevenlabs_tts::LanguageCode;
+///
+/// assert_eq!(LanguageCode::FR.as_str(), "fr");
+/// assert!("de".parse::<LanguageCode>().is_ok());
+/// assert!("french".parse::<LanguageCode>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LanguageCode(Cow<'static, str>);
+
+impl LanguageCode {
+    pub const AR: LanguageCode = LanguageCode::known("ar"); // Arabic
+    pub const BG: LanguageCode = LanguageCode::known("bg"); // Bulgarian
+    pub const CS: LanguageCode = LanguageCode::known("cs"); // Czech
+    pub const DA: LanguageCode = LanguageCode::known("da"); // Danish
+    pub const DE: LanguageCode = LanguageCode::known("de"); // German
+    pub const EL: LanguageCode = LanguageCode::known("el"); // Greek
+    pub const EN: LanguageCode = LanguageCode::known("en"); // English
+    pub const ES: LanguageCode = LanguageCode::known("es"); // Spanish
+    pub const FI: LanguageCode = LanguageCode::known("fi"); // Finnish
+    pub const FIL: LanguageCode = LanguageCode::known("fil"); // Filipino (no ISO 639-1 code)
+    pub const FR: LanguageCode = LanguageCode::known("fr"); // French
+    pub const HI: LanguageCode = LanguageCode::known("hi"); // Hindi
+    pub const HR: LanguageCode = LanguageCode::known("hr"); // Croatian
+    pub const HU: LanguageCode = LanguageCode::known("hu"); // Hungarian
+    pub const ID: LanguageCode = LanguageCode::known("id"); // Indonesian
+    pub const IT: LanguageCode = LanguageCode::known("it"); // Italian
+    pub const JA: LanguageCode = LanguageCode::known("ja"); // Japanese
+    pub const KO: LanguageCode = LanguageCode::known("ko"); // Korean
+    pub const MS: LanguageCode = LanguageCode::known("ms"); // Malay
+    pub const NL: LanguageCode = LanguageCode::known("nl"); // Dutch
+    pub const NO: LanguageCode = LanguageCode::known("no"); // Norwegian
+    pub const PL: LanguageCode = LanguageCode::known("pl"); // Polish
+    pub const PT: LanguageCode = LanguageCode::known("pt"); // Portuguese
+    pub const RO: LanguageCode = LanguageCode::known("ro"); // Romanian
+    pub const RU: LanguageCode = LanguageCode::known("ru"); // Russian
+    pub const SK: LanguageCode = LanguageCode::known("sk"); // Slovak
+    pub const SV: LanguageCode = LanguageCode::known("sv"); // Swedish
+    pub const TA: LanguageCode = LanguageCode::known("ta"); // Tamil
+    pub const TR: LanguageCode = LanguageCode::known("tr"); // Turkish
+    pub const UK: LanguageCode = LanguageCode::known("uk"); // Ukrainian
+    pub const VI: LanguageCode = LanguageCode::known("vi"); // Vietnamese
+    pub const ZH: LanguageCode = LanguageCode::known("zh"); // Chinese
+
+    const fn known(code: &'static str) -> Self {
+        LanguageCode(Cow::Borrowed(code))
+    }
+
+    /// Validate a language code: two ASCII letters (ISO 639-1), case-insensitive,
+    /// or one of the extra codes the API accepts such as `fil`
+    pub fn new<S: AsRef<str>>(code: S) -> Result<Self, ElevenLabsTTSError> {
+        let code = code.as_ref().trim().to_ascii_lowercase();
+        let valid = (code.len() == 2 && code.bytes().all(|b| b.is_ascii_lowercase()))
+            || code == LanguageCode::FIL.as_str();
+        if !valid {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Invalid language code {:?}: expected a two-letter ISO 639-1 code such as \"en\"",
+                code
+            )));
+        }
+        Ok(LanguageCode(Cow::Owned(code)))
+    }
+
+    /// The code sent to the API, e.g. `fr`
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Detect the language of `text` (`langdetect` feature). Returns `None` when the
+    /// detection is not reliable, typically for very short text, or when the language
+    /// has no constant above.
+    ///
+    /// ```rust
+    /// use elevenlabs_tts::LanguageCode;
+    ///
+    /// let text = "Le petit chat dort tranquillement sur le canapé du salon depuis ce matin.";
+    /// assert_eq!(LanguageCode::detect(text), Some(LanguageCode::FR));
+    /// ```
+    #[cfg(feature = "langdetect")]
+    pub fn detect(text: &str) -> Option<LanguageCode> {
+        use whatlang::Lang;
+
+        let info = whatlang::detect(text).filter(|info| info.is_reliable())?;
+        Some(match info.lang() {
+            Lang::Ara => LanguageCode::AR,
+            Lang::Bul => LanguageCode::BG,
+            Lang::Ces => LanguageCode::CS,
+            Lang::Dan => LanguageCode::DA,
+            Lang::Deu => LanguageCode::DE,
+            Lang::Ell => LanguageCode::EL,
+            Lang::Eng => LanguageCode::EN,
+            Lang::Spa => LanguageCode::ES,
+            Lang::Fin => LanguageCode::FI,
+            Lang::Tgl => LanguageCode::FIL,
+            Lang::Fra => LanguageCode::FR,
+            Lang::Hin => LanguageCode::HI,
+            Lang::Hrv => LanguageCode::HR,
+            Lang::Hun => LanguageCode::HU,
+            Lang::Ind => LanguageCode::ID,
+            Lang::Ita => LanguageCode::IT,
+            Lang::Jpn => LanguageCode::JA,
+            Lang::Kor => LanguageCode::KO,
+            Lang::Nld => LanguageCode::NL,
+            Lang::Nob => LanguageCode::NO,
+            Lang::Pol => LanguageCode::PL,
+            Lang::Por => LanguageCode::PT,
+            Lang::Ron => LanguageCode::RO,
+            Lang::Rus => LanguageCode::RU,
+            Lang::Slk => LanguageCode::SK,
+            Lang::Swe => LanguageCode::SV,
+            Lang::Tam => LanguageCode::TA,
+            Lang::Tur => LanguageCode::TR,
+            Lang::Ukr => LanguageCode::UK,
+            Lang::Vie => LanguageCode::VI,
+            Lang::Cmn => LanguageCode::ZH,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LanguageCode {
+    type Err = ElevenLabsTTSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LanguageCode::new(s)
+    }
+}
+
+// Lets the builders' `language_code()` setters take a `LanguageCode` or a plain string
+impl From<LanguageCode> for String {
+    fn from(code: LanguageCode) -> Self {
+        code.0.into_owned()
+    }
+}
+
+impl Serialize for LanguageCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LanguageCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -24,82 +24,1164 @@
 //! }
 //! ```
 
+#[cfg(not(any(
+    feature = "default-tls",
+    feature = "native-tls",
+    feature = "rustls-tls"
+)))]
+compile_error!(
+    "elevenlabs_tts needs a TLS backend: enable one of the `default-tls`, `native-tls` or `rustls-tls` features"
+);
+
+use std::fmt;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures_util::stream::BoxStream;
+use futures_util::{Stream, StreamExt, TryStreamExt, stream as futures_stream};
 use reqwest::Client;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use serde::{Serialize, de::DeserializeOwned};
+use tokio::io::AsyncWrite;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore, mpsc};
+
+use cache::Expiring;
+use circuit_breaker::CircuitBreaker;
+use models::Model;
 
+pub mod api;
+pub mod audio;
+pub mod audio_tags;
+pub mod audiobook;
+pub mod audition;
+pub mod auth;
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cache;
+pub mod circuit_breaker;
+pub mod client_builder;
+#[cfg(feature = "content")]
+pub mod content;
+pub mod conversational_ai;
+pub mod cost;
+#[cfg(feature = "decode")]
+pub mod decode;
+pub mod dialogue;
+#[cfg(feature = "songbird")]
+pub mod discord;
+pub mod dubbing;
 pub mod error;
+pub mod history;
+pub mod ids;
+pub mod language;
+pub mod llm;
+pub mod long_form;
+pub mod metrics;
+#[cfg(feature = "test-utils")]
+mod mock;
 pub mod models;
+mod multipart;
+pub mod music;
+#[cfg(feature = "playback")]
+pub mod playback;
+pub mod pronunciation;
+pub mod queue;
+pub mod retry;
+pub mod speech_to_text;
+pub mod stream;
+pub mod studio;
+pub mod subscription;
+pub mod subtitles;
+#[cfg(feature = "tags")]
+pub mod tags;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod text_input;
 pub mod types;
+pub mod usage;
+pub mod voice_design;
+pub mod voice_search;
 pub mod voices;
+#[cfg(feature = "wav")]
+pub mod wav;
+#[cfg(any(feature = "axum", feature = "actix-web"))]
+pub mod web;
+pub mod webhooks;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
-pub use error::ElevenLabsTTSError;
+pub use api::TtsApi;
+pub use audio_tags::{AudioTag, TaggedText};
+pub use audiobook::{AudiobookBuilder, AudiobookManifest, Chapter};
+pub use audition::{VoiceAudition, VoiceComparison};
+pub use auth::{ApiKeyProvider, StaticApiKey};
+pub use batch::{BatchBuilder, BatchJob};
+pub use cache::{DiskCache, MemoryCache, TtsCache};
+pub use circuit_breaker::{CircuitBreakerConfig, CircuitState};
+pub use client_builder::ClientBuilder;
+pub use conversational_ai::ConversationalAi;
+pub use cost::CostEstimate;
+pub use dialogue::{DialogueInput, DialogueSettings, TextToDialogueBuilder};
+pub use dubbing::Dubbing;
+pub use error::{ApiErrorDetail, ElevenLabsTTSError, ValidationIssue};
+pub use history::{History, HistoryItem, HistoryItemState, HistoryPage};
+pub use ids::{RequestId, VoiceId};
+pub use language::LanguageCode;
+pub use long_form::{LongFormBuilder, StitchedClip, StitchedSession};
+pub use metrics::MetricsObserver;
+pub use music::MusicBuilder;
+pub use pronunciation::{PhonemeAlphabet, PronunciationRules};
+pub use queue::{JobHandle, Priority, QueueEvent, TtsQueue};
+pub use retry::{RetryEvent, RetryPolicy};
+pub use speech_to_text::SpeechToTextBuilder;
+pub use stream::{AudioReader, AudioStream};
+pub use studio::Studio;
+pub use subscription::Subscription;
 pub use types::*;
+pub use usage::UsageTracker;
+pub use voice_design::{VoicePreview, VoicePreviews, VoiceRemixBuilder};
+pub use voice_search::{Voice, VoiceSearch, Voices, VoicesPage};
+
+// Re-exported so custom API key providers don't need their own dependency
+pub use async_trait::async_trait;
+
+// Re-exported so proxies and custom clients can be built against the same version
+pub use reqwest;
+
+// Re-exported so secret API keys can be built against the same version
+#[cfg(feature = "secrecy")]
+pub use secrecy;
+
+/// Default ElevenLabs API base URL
+pub const DEFAULT_BASE_URL: &str = "https://api.elevenlabs.io/v1";
+
+/// User-Agent sent by default, e.g. `elevenlabs_tts-rs/0.2.1`
+pub const DEFAULT_USER_AGENT: &str = concat!("elevenlabs_tts-rs/", env!("CARGO_PKG_VERSION"));
+
+/// Environment variable holding the API key, read by [`ElevenLabsTTSClient::from_env`]
+pub const API_KEY_ENV: &str = "ELEVENLABS_API_KEY";
+
+/// Environment variable overriding the base URL, read by [`ElevenLabsTTSClient::from_env`]
+pub const BASE_URL_ENV: &str = "ELEVENLABS_BASE_URL";
+
+/// Maximum number of `previous_request_ids`/`next_request_ids` the API accepts
+pub const MAX_REQUEST_IDS: usize = 3;
+
+/// Per-request options threaded through the HTTP helpers
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestOptions {
+    /// Timeout for each HTTP attempt, overriding the client-level timeout
+    pub(crate) timeout: Option<Duration>,
+
+    /// Extra headers for this request, overriding the client's default headers
+    pub(crate) headers: Vec<(String, String)>,
+
+    /// Told about every chunk of audio received
+    pub(crate) progress: Option<stream::Progress>,
+}
+
+/// Request defaults configured on the client and inherited by new builders
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestDefaults {
+    pub(crate) voice_id: Option<String>,
+    pub(crate) model_id: Option<String>,
+    pub(crate) output_format: Option<OutputFormat>,
+    pub(crate) voice_settings: Option<VoiceSettings>,
+}
+
+/// Convert `(name, value)` pairs into a header map, rejecting invalid names or values.
+/// A later pair replaces an earlier one with the same name.
+pub(crate) fn header_map(headers: &[(String, String)]) -> Result<HeaderMap, ElevenLabsTTSError> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+            ElevenLabsTTSError::ValidationError(format!("Invalid header name: {}", name))
+        })?;
+        let header_value = HeaderValue::from_str(value).map_err(|_| {
+            ElevenLabsTTSError::ValidationError(format!("Invalid value for header {}", name))
+        })?;
+        map.insert(header_name, header_value);
+    }
+    Ok(map)
+}
+
+/// Follow a cursor through the pages of a list endpoint: `fetch` returns a page of items
+/// and the request for the next page, if any
+pub(crate) fn paginate<R, T, F, Fut>(
+    first: R,
+    fetch: F,
+) -> BoxStream<'static, Result<T, ElevenLabsTTSError>>
+where
+    R: Send + 'static,
+    T: Send + 'static,
+    F: Fn(R) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<(Vec<T>, Option<R>), ElevenLabsTTSError>> + Send + 'static,
+{
+    futures_stream::try_unfold((Some(first), fetch), |(request, fetch)| async move {
+        let Some(request) = request else {
+            return Ok(None);
+        };
+        let (items, next) = fetch(request).await?;
+        let items = futures_stream::iter(items.into_iter().map(Ok));
+        Ok::<_, ElevenLabsTTSError>(Some((items, (next, fetch))))
+    })
+    .try_flatten()
+    .boxed()
+}
 
 /// Main client for interacting with ElevenLabs API
+///
+/// Cloning is cheap: clones share the same configuration and connection pool.
 #[derive(Clone)]
 pub struct ElevenLabsTTSClient {
+    inner: Arc<ClientInner>,
+}
+
+// Only the base URL is shown, so logging a client never leaks the API key
+impl fmt::Debug for ElevenLabsTTSClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ElevenLabsTTSClient")
+            .field("base_url", &self.inner.base_url)
+            .field("api_key", &"[REDACTED]")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Configuration shared by a client, its clones and the request builders it creates
+struct ClientInner {
     client: Client,
-    api_key: String,
+    api_key: Arc<dyn ApiKeyProvider>,
     base_url: String,
+    retry_policy: RetryPolicy,
+    limiter: Option<Arc<Semaphore>>,
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    cache: Option<Arc<dyn TtsCache>>,
+    default_headers: HeaderMap,
+    defaults: RequestDefaults,
+    // Check requests against the account's models before sending them
+    model_catalog: Option<Expiring<Arc<[Model]>>>,
+    usage: Option<UsageTracker>,
+    // Subscription consulted by `execute_checked`
+    subscription: Expiring<Subscription>,
+    // Answer every request with canned data instead of calling the API
+    #[cfg(feature = "test-utils")]
+    mock: bool,
 }
 
 impl ElevenLabsTTSClient {
     /// Create a new ElevenLabs client with API key
     pub fn new<S: Into<String>>(api_key: S) -> Self {
-        Self {
-            client: Client::new(),
-            api_key: api_key.into(),
-            base_url: "https://api.elevenlabs.io/v1".to_string(),
+        Self::with_base_url(api_key.into(), DEFAULT_BASE_URL.to_string())
+    }
+
+    /// Create a new client from the `ELEVENLABS_API_KEY` environment variable,
+    /// honoring `ELEVENLABS_BASE_URL` when it is set
+    pub fn from_env() -> Result<Self, ElevenLabsTTSError> {
+        let api_key = std::env::var(API_KEY_ENV)
+            .ok()
+            .filter(|key| !key.trim().is_empty())
+            .ok_or_else(|| {
+                ElevenLabsTTSError::ValidationError(format!(
+                    "{} environment variable is not set",
+                    API_KEY_ENV
+                ))
+            })?;
+
+        let mut builder = Self::builder().api_key(api_key);
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            if !base_url.trim().is_empty() {
+                builder = builder.base_url(base_url);
+            }
         }
+
+        builder.build()
+    }
+
+    /// Start building a client with HTTP configuration (timeouts, proxy, custom `reqwest::Client`)
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
     }
 
     /// Create a new client with custom base URL (for testing/enterprise)
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Self {
-        Self {
+        Self::from_inner(ClientInner {
             client: Client::new(),
-            api_key: api_key.into(),
+            api_key: Arc::new(StaticApiKey::new(api_key)),
             base_url: base_url.into(),
+            retry_policy: RetryPolicy::none(),
+            limiter: None,
+            metrics: None,
+            circuit_breaker: None,
+            cache: None,
+            default_headers: HeaderMap::from_iter([(
+                USER_AGENT,
+                HeaderValue::from_static(DEFAULT_USER_AGENT),
+            )]),
+            defaults: RequestDefaults::default(),
+            model_catalog: None,
+            usage: None,
+            subscription: Expiring::new(subscription::SUBSCRIPTION_CACHE_TTL),
+            #[cfg(feature = "test-utils")]
+            mock: false,
+        })
+    }
+
+    /// A client that never touches the network: every endpoint answers with deterministic
+    /// fake audio (a sine wave for PCM, μ-law and A-law, silent frames for MP3) and
+    /// synthesized metadata such as request IDs and character costs. For demos and
+    /// frontend development without credentials.
+    #[cfg(feature = "test-utils")]
+    pub fn mock() -> Self {
+        let client = Self::with_base_url("mock-key", "https://mock.elevenlabs.invalid/v1");
+        let mut inner = Arc::into_inner(client.inner).expect("client was just created");
+        inner.mock = true;
+        Self::from_inner(inner)
+    }
+
+    pub(crate) fn from_inner(inner: ClientInner) -> Self {
+        Self {
+            inner: Arc::new(inner),
         }
     }
 
+    /// Current state of the circuit breaker, if one is configured
+    pub fn circuit_state(&self) -> Option<CircuitState> {
+        self.inner
+            .circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.state())
+    }
+
     /// Start building a text-to-speech request
     pub fn text_to_speech<S: Into<String>>(&self, text: S) -> TextToSpeechBuilder {
-        TextToSpeechBuilder::new(self.clone(), text.into())
+        TextToSpeechBuilder::new(self.clone(), text.into()).with_defaults(&self.inner.defaults)
+    }
+
+    /// Start a request with the text of a file (UTF-8, UTF-16 or Windows-1252, at most
+    /// [`text_input::MAX_TEXT_INPUT_BYTES`])
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// client
+    ///     .text_to_speech_from_path("script.txt")
+    ///     .await?
+    ///     .long_form()
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn text_to_speech_from_path<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> Result<TextToSpeechBuilder, ElevenLabsTTSError> {
+        let text = text_input::read_text_file(path, text_input::MAX_TEXT_INPUT_BYTES).await?;
+        Ok(self.text_to_speech(text))
+    }
+
+    /// Start a request with the text read from `reader` (UTF-8, UTF-16 or Windows-1252,
+    /// at most [`text_input::MAX_TEXT_INPUT_BYTES`])
+    pub async fn text_to_speech_from_reader<R>(
+        &self,
+        reader: R,
+    ) -> Result<TextToSpeechBuilder, ElevenLabsTTSError>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        let text = text_input::read_text(reader, text_input::MAX_TEXT_INPUT_BYTES).await?;
+        Ok(self.text_to_speech(text))
+    }
+
+    /// Start a request that regenerates a history item with its original text, voice,
+    /// model and settings, ready to be tweaked before executing
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// let page = client.history().list().page_size(1).execute().await?;
+    /// if let Some(item) = page.history.first() {
+    ///     let audio = client.text_to_speech_from_history(item).seed(42).execute().await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn text_to_speech_from_history(&self, item: &HistoryItem) -> TextToSpeechBuilder {
+        let mut builder = self.text_to_speech(item.text.clone().unwrap_or_default());
+        if let Some(voice_id) = &item.voice_id {
+            builder = builder.voice_id(voice_id.clone());
+        }
+        if let Some(model_id) = &item.model_id {
+            builder = builder.model(model_id.clone());
+        }
+        if let Some(settings) = &item.settings {
+            builder = builder.voice_settings(settings.clone());
+        }
+        builder
+    }
+
+    /// Generate speech with the client defaults and play it on the default output device,
+    /// returning once playback has finished
+    #[cfg(feature = "playback")]
+    pub async fn speak<S: Into<String>>(&self, text: S) -> Result<(), ElevenLabsTTSError> {
+        let output = self.text_to_speech(text).execute_output().await?;
+        tokio::task::spawn_blocking(move || output.play())
+            .await
+            .map_err(playback::playback_error)?
+    }
+
+    /// Generate many clips with bounded concurrency, returning per-job results in input order.
+    /// Jobs are texts, `(text, &voice)` or `(text, &voice, settings)` tuples, or [`BatchJob`]s.
+    pub fn batch<I, J>(&self, jobs: I) -> BatchBuilder
+    where
+        I: IntoIterator<Item = J>,
+        J: Into<BatchJob>,
+    {
+        let jobs = jobs.into_iter().map(Into::into).collect();
+        BatchBuilder::new(self.text_to_speech(String::new()), jobs)
+    }
+
+    /// Generate the same text with each voice concurrently, for previews in voice pickers
+    pub fn compare_voices<S, I, V>(&self, text: S, voice_ids: I) -> VoiceComparison
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let voice_ids = voice_ids
+            .into_iter()
+            .map(|voice_id| voice_id.as_ref().to_string())
+            .collect();
+        VoiceComparison::new(self.text_to_speech(String::new()), text.into(), voice_ids)
+    }
+
+    /// Start a session of clips stitched together through request IDs.
+    /// `configure` sets up the voice, model and settings shared by every clip.
+    pub fn stitched_session<F>(&self, configure: F) -> StitchedSession
+    where
+        F: FnOnce(TextToSpeechBuilder) -> TextToSpeechBuilder,
+    {
+        StitchedSession::new(configure(self.text_to_speech(String::new())))
+    }
+
+    /// Start an audiobook written to `output_dir`, one file per chapter plus a manifest.
+    /// `configure` sets up the voice, model, format and settings shared by every chapter.
+    pub fn audiobook<P, F>(&self, output_dir: P, configure: F) -> AudiobookBuilder
+    where
+        P: Into<PathBuf>,
+        F: FnOnce(TextToSpeechBuilder) -> TextToSpeechBuilder,
+    {
+        AudiobookBuilder::new(
+            configure(self.text_to_speech(String::new())),
+            output_dir.into(),
+        )
+    }
+
+    /// Start building a multi-speaker text-to-dialogue request from `(voice_id, text)` turns
+    pub fn dialogue<I, T>(&self, turns: I) -> TextToDialogueBuilder
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<DialogueInput>,
+    {
+        let mut builder =
+            TextToDialogueBuilder::new(self.clone(), turns.into_iter().map(Into::into).collect());
+        if let Some(output_format) = self.inner.defaults.output_format {
+            builder = builder.output_format(output_format);
+        }
+        builder
+    }
+
+    /// Access the Conversational AI (agents) endpoints
+    pub fn conversational_ai(&self) -> ConversationalAi {
+        ConversationalAi::new(self.clone())
+    }
+
+    /// Access the dubbing resource endpoints, for editing and re-rendering dubs
+    pub fn dubbing(&self) -> Dubbing {
+        Dubbing::new(self.clone())
+    }
+
+    /// Access the generation history endpoints
+    pub fn history(&self) -> History {
+        History::new(self.clone())
+    }
+
+    /// Access the voice endpoints: lookups and preview clips
+    pub fn voices(&self) -> Voices {
+        Voices::new(self.clone())
+    }
+
+    /// Access the Studio (projects) endpoints
+    pub fn studio(&self) -> Studio {
+        Studio::new(self.clone())
+    }
+
+    /// Search the voices available to the account (cloned, generated, library...)
+    pub fn search_voices(&self) -> VoiceSearch {
+        VoiceSearch::new(self.clone())
+    }
+
+    /// Start a remix of an existing voice: previews of it changed as described
+    pub fn remix_voice<V: Into<String>, S: Into<String>>(
+        &self,
+        voice_id: V,
+        description: S,
+    ) -> VoiceRemixBuilder {
+        VoiceRemixBuilder::new(self.clone(), voice_id.into(), description.into())
+    }
+
+    /// Save a generated preview (e.g. from [`remix_voice`](Self::remix_voice)) as a voice
+    /// in the account's voice library
+    pub async fn create_voice_from_preview(
+        &self,
+        name: &str,
+        description: &str,
+        generated_voice_id: &str,
+    ) -> Result<Voice, ElevenLabsTTSError> {
+        if name.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Voice name cannot be empty".to_string(),
+            ));
+        }
+        let url = format!("{}/text-to-voice", self.inner.base_url);
+        let request = voice_design::CreateVoiceRequest {
+            voice_name: name,
+            voice_description: description,
+            generated_voice_id,
+        };
+        self.post_for_json(
+            "text-to-voice-create",
+            &url,
+            &[],
+            &request,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Start building a speech-to-text request transcribing a recording
+    pub fn speech_to_text<A: Into<Vec<u8>>>(&self, audio: A) -> SpeechToTextBuilder {
+        SpeechToTextBuilder::new(self.clone(), audio.into())
+    }
+
+    /// Start building an Eleven Music generation request from a prompt
+    pub fn music<S: Into<String>>(&self, prompt: S) -> MusicBuilder {
+        let builder = MusicBuilder::new(self.clone(), prompt.into());
+        match self.inner.defaults.output_format {
+            Some(output_format) => builder.output_format(output_format),
+            None => builder,
+        }
+    }
+
+    /// List the models available to the account, with their capabilities and languages
+    pub async fn models(&self) -> Result<Vec<Model>, ElevenLabsTTSError> {
+        let url = format!("{}/models", self.inner.base_url);
+        self.get_json("models", &url, &[]).await
+    }
+
+    /// Get the account's subscription: tier, characters used and character limit
+    pub async fn subscription(&self) -> Result<Subscription, ElevenLabsTTSError> {
+        let url = format!("{}/user/subscription", self.inner.base_url);
+        self.get_json("user-subscription", &url, &[]).await
+    }
+
+    /// Internal method to check a request against the cached models list, when enabled.
+    /// If the list can't be fetched the request is sent unchecked.
+    async fn check_model(&self, request: &TTSRequest) -> Result<(), ElevenLabsTTSError> {
+        let Some(catalog) = &self.inner.model_catalog else {
+            return Ok(());
+        };
+        let fetch = || async { Ok(self.models().await?.into()) };
+        let Ok(models) = catalog.get(fetch).await else {
+            return Ok(());
+        };
+        match models
+            .iter()
+            .find(|model| model.model_id.as_str() == request.model_id)
+        {
+            Some(model) => model.check_request(request),
+            None => Err(ElevenLabsTTSError::ValidationError(format!(
+                "Model {} is not available to this account",
+                request.model_id
+            ))),
+        }
+    }
+
+    /// Internal method to count `(voice_id, model_id, text)` parts against the usage
+    /// tracker's budgets, when one is configured
+    fn charge_usage(&self, parts: &[(&str, &str, &str)]) -> Result<(), ElevenLabsTTSError> {
+        match &self.inner.usage {
+            Some(usage) => usage.charge(parts),
+            None => Ok(()),
+        }
+    }
+
+    /// Start building a WebSocket session that turns text into speech as it is sent
+    #[cfg(feature = "websocket")]
+    pub fn text_to_speech_websocket<S: Into<String>>(
+        &self,
+        voice_id: S,
+    ) -> websocket::WebSocketBuilder {
+        websocket::WebSocketBuilder::new(self.clone(), voice_id.into())
     }
 
     /// Internal method to execute TTS request
     pub(crate) async fn execute_tts(
         &self,
         request: TTSRequest,
-    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
-        let url = format!("{}/text-to-speech/{}", self.base_url, request.voice_id);
+        options: &RequestOptions,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        self.check_model(&request).await?;
+        let url = format!(
+            "{}/text-to-speech/{}",
+            self.inner.base_url, request.voice_id
+        );
+        let usage = [(
+            request.voice_id.as_str(),
+            request.model_id.as_str(),
+            request.text.as_str(),
+        )];
+        self.post_for_audio(
+            "text-to-speech",
+            &url,
+            &request.query(),
+            &request,
+            &usage,
+            options,
+        )
+        .await
+    }
 
+    /// Internal method to execute streaming TTS request
+    pub(crate) async fn execute_tts_stream(
+        &self,
+        request: TTSRequest,
+        options: &RequestOptions,
+    ) -> Result<AudioStream, ElevenLabsTTSError> {
+        self.check_model(&request).await?;
+        self.charge_usage(&[(&request.voice_id, &request.model_id, &request.text)])?;
+        let url = format!(
+            "{}/text-to-speech/{}/stream",
+            self.inner.base_url, request.voice_id
+        );
+        self.post_for_stream(
+            "text-to-speech-stream",
+            &url,
+            &request.query(),
+            &request,
+            options,
+        )
+        .await
+    }
+
+    /// Internal method to execute TTS request returning character timing
+    pub(crate) async fn execute_tts_with_timestamps(
+        &self,
+        request: TTSRequest,
+        options: &RequestOptions,
+    ) -> Result<TimestampedAudio, ElevenLabsTTSError> {
+        self.check_model(&request).await?;
+        self.charge_usage(&[(&request.voice_id, &request.model_id, &request.text)])?;
+        let endpoint = "text-to-speech-timestamps";
+        let url = format!(
+            "{}/text-to-speech/{}/with-timestamps",
+            self.inner.base_url, request.voice_id
+        );
+        let _permit = self.acquire_permit().await;
+        let started = Instant::now();
         let response = self
+            .post_json(endpoint, &url, &request.query(), &request, options)
+            .await?;
+        let headers = response.headers().clone();
+        let body = Self::read_body(response, options, started).await?;
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.bytes_received(endpoint, body.len());
+        }
+        let response: TimestampsResponse = serde_json::from_slice(&body)?;
+        Ok(response.into_audio(&headers))
+    }
+
+    /// Internal method to execute text-to-dialogue request
+    pub(crate) async fn execute_dialogue(
+        &self,
+        request: dialogue::TextToDialogueRequest,
+        options: &RequestOptions,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let parts: Vec<_> = request
+            .inputs
+            .iter()
+            .map(|input| {
+                (
+                    input.voice_id.as_str(),
+                    request.model_id.as_str(),
+                    input.text.as_str(),
+                )
+            })
+            .collect();
+        let url = format!("{}/text-to-dialogue", self.inner.base_url);
+        let query = [("output_format", request.output_format.to_string())];
+        Ok(self
+            .post_for_audio("text-to-dialogue", &url, &query, &request, &parts, options)
+            .await?
+            .audio)
+    }
+
+    /// Internal method to execute music generation request
+    pub(crate) async fn execute_music(
+        &self,
+        request: music::MusicRequest,
+        options: &RequestOptions,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let url = format!("{}/music", self.inner.base_url);
+        let query = [("output_format", request.output_format.to_string())];
+        Ok(self
+            .post_for_audio("music", &url, &query, &request, &[], options)
+            .await?
+            .audio)
+    }
+
+    /// Internal method to execute streaming music generation request
+    pub(crate) async fn execute_music_stream(
+        &self,
+        request: music::MusicRequest,
+        options: &RequestOptions,
+    ) -> Result<AudioStream, ElevenLabsTTSError> {
+        let url = format!("{}/music/stream", self.inner.base_url);
+        let query = [("output_format", request.output_format.to_string())];
+        self.post_for_stream("music-stream", &url, &query, &request, options)
+            .await
+    }
+
+    /// Internal method to describe a JSON POST without sending it, with the API key redacted
+    pub(crate) fn http_parts<B: Serialize>(
+        &self,
+        url: String,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<HttpRequestParts, ElevenLabsTTSError> {
+        let mut headers = self.inner.default_headers.clone();
+        headers.extend(header_map(&options.headers)?);
+        headers.insert("xi-api-key", HeaderValue::from_static("[REDACTED]"));
+        headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        Ok(HttpRequestParts {
+            method: "POST".to_string(),
+            url,
+            query: query
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+            headers: headers
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                    )
+                })
+                .collect(),
+            body: serde_json::to_value(body)?,
+        })
+    }
+
+    /// Internal method to POST a JSON body and collect the audio response. `usage` is
+    /// charged to the usage tracker only when the response cache can't answer.
+    pub(crate) async fn post_for_audio<B: Serialize>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        usage: &[(&str, &str, &str)],
+        options: &RequestOptions,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let cache_key = match &self.inner.cache {
+            Some(cache) => {
+                let key = cache::cache_key(url, query, body)?;
+                if let Some(audio) = cache.get(&key).await {
+                    if let Some(progress) = &options.progress {
+                        progress.report(audio.len() as u64, Instant::now());
+                    }
+                    let mut response = AudioResponse::from_parts(audio, &HeaderMap::new());
+                    // Replays from the cache aren't billed
+                    response.character_cost = Some(0);
+                    return Ok(response);
+                }
+                Some(key)
+            }
+            None => None,
+        };
+
+        self.charge_usage(usage)?;
+        let _permit = self.acquire_permit().await;
+        let started = Instant::now();
+        let response = self.post_json(endpoint, url, query, body, options).await?;
+        let headers = response.headers().clone();
+        let audio = Self::read_body(response, options, started).await?;
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.bytes_received(endpoint, audio.len());
+        }
+        if let (Some(cache), Some(key)) = (&self.inner.cache, cache_key) {
+            cache.put(&key, &audio).await;
+        }
+        Ok(AudioResponse::from_parts(audio.to_vec(), &headers))
+    }
+
+    /// Internal method to POST a JSON body and stream the audio response
+    pub(crate) async fn post_for_stream<B: Serialize>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<AudioStream, ElevenLabsTTSError> {
+        // The permit travels with the stream so the slot stays taken until it is dropped
+        let permit = self.acquire_permit().await;
+        let started = Instant::now();
+        let response = self.post_json(endpoint, url, query, body, options).await?;
+        let audio = stream::from_response(
+            response,
+            permit,
+            self.inner
+                .metrics
+                .clone()
+                .map(|metrics| (endpoint, metrics)),
+        );
+        Ok(match &options.progress {
+            Some(progress) => stream::with_progress(audio, progress.clone(), started),
+            None => audio,
+        })
+    }
+
+    /// Internal method to read a whole response body, reporting progress if requested
+    async fn read_body(
+        response: reqwest::Response,
+        options: &RequestOptions,
+        started: Instant,
+    ) -> Result<Bytes, ElevenLabsTTSError> {
+        match &options.progress {
+            Some(progress) => stream::read_with_progress(response, progress, started).await,
+            None => Ok(response.bytes().await?),
+        }
+    }
+
+    /// Internal method to POST a JSON body, mapping error statuses to errors
+    async fn post_json<B: Serialize>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<reqwest::Response, ElevenLabsTTSError> {
+        self.send_json(reqwest::Method::POST, endpoint, url, query, body, options)
+            .await
+    }
+
+    /// Internal method to send a JSON body with any method, mapping error statuses to errors
+    async fn send_json<B: Serialize>(
+        &self,
+        method: reqwest::Method,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<reqwest::Response, ElevenLabsTTSError> {
+        let mut request = self
+            .inner
             .client
-            .post(&url)
-            .header("xi-api-key", &self.api_key)
+            .request(method, url)
+            .headers(self.inner.default_headers.clone())
+            .headers(header_map(&options.headers)?)
+            .query(query)
             .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
+            .json(body);
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
+
+        self.send(endpoint, request).await
+    }
+
+    /// URL of a `/v2` endpoint, next to the `/v1` base URL
+    pub(crate) fn v2_url(&self, path: &str) -> String {
+        let base_url = self.inner.base_url.trim_end_matches('/');
+        let root = base_url.strip_suffix("/v1").unwrap_or(base_url);
+        format!("{}/v2{}", root, path)
+    }
+
+    /// Internal method to GET a JSON resource
+    pub(crate) async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, ElevenLabsTTSError> {
+        let _permit = self.acquire_permit().await;
+        let response = self
+            .send(
+                endpoint,
+                self.inner
+                    .client
+                    .get(url)
+                    .headers(self.inner.default_headers.clone())
+                    .query(query),
+            )
             .await?;
+        let body = response.bytes().await?;
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.bytes_received(endpoint, body.len());
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Internal method to download a file hosted outside the API (e.g. a voice preview),
+    /// without the API key or default headers
+    pub(crate) async fn download(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let _permit = self.acquire_permit().await;
+        let response = self
+            .send_attempt(endpoint, self.inner.client.get(url))
+            .await?;
+        let response = Self::check_status(response).await?;
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.bytes_received(endpoint, body.len());
+        }
+        Ok(AudioResponse::from_parts(body.to_vec(), &headers))
+    }
+
+    /// Internal method to POST a JSON body and parse the JSON response
+    pub(crate) async fn post_for_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<T, ElevenLabsTTSError> {
+        self.send_for_json(reqwest::Method::POST, endpoint, url, query, body, options)
+            .await
+    }
+
+    /// Internal method to PATCH a JSON body and parse the JSON response
+    pub(crate) async fn patch_for_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        body: &B,
+    ) -> Result<T, ElevenLabsTTSError> {
+        self.send_for_json(
+            reqwest::Method::PATCH,
+            endpoint,
+            url,
+            &[],
+            body,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Internal method to send a JSON body and parse the JSON response
+    async fn send_for_json<B: Serialize, T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        endpoint: &'static str,
+        url: &str,
+        query: &[(&str, String)],
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<T, ElevenLabsTTSError> {
+        let _permit = self.acquire_permit().await;
+        let response = self
+            .send_json(method, endpoint, url, query, body, options)
+            .await?;
+        let body = response.bytes().await?;
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.bytes_received(endpoint, body.len());
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
 
-        if !response.status().is_success() {
-            return Err(ElevenLabsTTSError::ApiError {
-                status: response.status().as_u16(),
-                message: response.text().await.unwrap_or_default(),
+    /// Internal method to POST a `multipart/form-data` body and parse the JSON response
+    pub(crate) async fn post_multipart<T: DeserializeOwned>(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+        form: multipart::Form,
+        options: &RequestOptions,
+    ) -> Result<T, ElevenLabsTTSError> {
+        let (content_type, body) = form.encode();
+        let mut request = self
+            .inner
+            .client
+            .post(url)
+            .headers(self.inner.default_headers.clone())
+            .headers(header_map(&options.headers)?)
+            .header("Content-Type", content_type)
+            .body(body);
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let _permit = self.acquire_permit().await;
+        let response = self.send(endpoint, request).await?;
+        let body = response.bytes().await?;
+        if let Some(metrics) = &self.inner.metrics {
+            metrics.bytes_received(endpoint, body.len());
+        }
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Internal method to wait for a free slot when a concurrency limit is configured
+    async fn acquire_permit(&self) -> Option<OwnedSemaphorePermit> {
+        match &self.inner.limiter {
+            // The semaphore is never closed, so acquiring can't fail
+            Some(limiter) => limiter.clone().acquire_owned().await.ok(),
+            None => None,
+        }
+    }
+
+    /// Internal method to authenticate and send a request, retrying transient failures
+    /// according to the retry policy and mapping error statuses to errors
+    async fn send(
+        &self,
+        endpoint: &'static str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ElevenLabsTTSError> {
+        #[cfg(feature = "test-utils")]
+        if self.inner.mock {
+            let response = mock::respond(endpoint, request.build()?)?;
+            return Self::check_status(response).await;
+        }
+
+        let api_key = self.inner.api_key.get_key().await?;
+        let mut pending = Some(request.header("xi-api-key", auth::api_key_header(&api_key)?));
+        let mut attempt = 1;
+
+        loop {
+            // Bodies that can't be cloned (streams) are sent once, without retries
+            let (current, retryable) = match pending.as_ref().and_then(|r| r.try_clone()) {
+                Some(current) => (current, attempt < self.inner.retry_policy.max_attempts),
+                None => (pending.take().expect("request is sent once"), false),
+            };
+
+            let permit = self
+                .inner
+                .circuit_breaker
+                .as_ref()
+                .map(|breaker| breaker.before_request())
+                .transpose()?;
+
+            let result = self.send_attempt(endpoint, current).await;
+            if let Some(permit) = permit {
+                match &result {
+                    Ok(r) if CircuitBreaker::is_failure_status(r.status().as_u16()) => {
+                        permit.record_failure()
+                    }
+                    Err(e) if error::request_error_is_transient(e) => permit.record_failure(),
+                    _ => permit.record_success(),
+                }
+            }
+
+            let policy = &self.inner.retry_policy;
+            let (status, retry_after) = match result {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    if !(retryable && policy.should_retry_status(status)) {
+                        return Self::check_status(response).await;
+                    }
+                    let retry_after = error::parse_retry_after(response.headers());
+                    (Some(status), retry_after.map(Duration::from_secs))
+                }
+                Err(error) => {
+                    if !(retryable && policy.should_retry_error(&error)) {
+                        return Err(error.into());
+                    }
+                    (None, None)
+                }
+            };
+
+            let delay = policy.retry_delay(attempt, retry_after);
+            policy.notify(RetryEvent {
+                endpoint,
+                attempt,
+                delay,
+                status,
+                retry_after,
             });
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Internal method to send a single HTTP attempt, reporting it to the metrics observer
+    async fn send_attempt(
+        &self,
+        endpoint: &'static str,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let Some(metrics) = &self.inner.metrics else {
+            return request.send().await;
+        };
+
+        metrics.request_started(endpoint);
+        let started = Instant::now();
+        let result = request.send().await;
+
+        let status = result.as_ref().ok().map(|r| r.status().as_u16());
+        metrics.request_finished(endpoint, status, started.elapsed());
+        if let Some(characters) = result
+            .as_ref()
+            .ok()
+            .filter(|r| r.status().is_success())
+            .and_then(|r| metrics::character_cost(r.headers()))
+        {
+            metrics.characters_billed(endpoint, characters);
         }
 
-        Ok(response.bytes().await?.to_vec())
+        result
+    }
+
+    /// Internal method to turn an error status into an error
+    async fn check_status(
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, ElevenLabsTTSError> {
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status().as_u16();
+        let headers = response.headers().clone();
+        let body = response.text().await.unwrap_or_default();
+        Err(ElevenLabsTTSError::from_response_parts(
+            status, &headers, body,
+        ))
     }
 }
 
 /// Builder for text-to-speech requests
-pub struct TextToSpeechBuilder {
-    client: ElevenLabsTTSClient,
+#[derive(Clone)]
+pub struct TextToSpeechBuilder<C = ElevenLabsTTSClient> {
+    client: C,
     text: String,
     voice_id: Option<String>,
     model_id: Option<String>,
-    output_format: Option<String>,
+    output_format: Option<OutputFormat>,
     language_code: Option<String>,
     seed: Option<u32>,
     previous_text: Option<String>,
@@ -109,10 +1191,14 @@ pub struct TextToSpeechBuilder {
     apply_text_normalization: Option<String>,
     apply_language_text_normalization: Option<bool>,
     voice_settings: Option<VoiceSettings>,
+    enable_logging: Option<bool>,
+    #[cfg(feature = "langdetect")]
+    detect_language: bool,
+    options: RequestOptions,
 }
 
-impl TextToSpeechBuilder {
-    fn new(client: ElevenLabsTTSClient, text: String) -> Self {
+impl<C> TextToSpeechBuilder<C> {
+    fn new(client: C, text: String) -> Self {
         Self {
             client,
             text,
@@ -128,9 +1214,22 @@ impl TextToSpeechBuilder {
             apply_text_normalization: None,
             apply_language_text_normalization: None,
             voice_settings: None,
+            enable_logging: None,
+            #[cfg(feature = "langdetect")]
+            detect_language: true,
+            options: RequestOptions::default(),
         }
     }
 
+    /// Start from the client's default voice, model, output format and voice settings
+    fn with_defaults(mut self, defaults: &RequestDefaults) -> Self {
+        self.voice_id = defaults.voice_id.clone();
+        self.model_id = defaults.model_id.clone();
+        self.output_format = defaults.output_format;
+        self.voice_settings = defaults.voice_settings.clone();
+        self
+    }
+
     /// Set the voice to use (accepts StaticVoice reference)
     pub fn voice(mut self, voice: &StaticVoice) -> Self {
         self.voice_id = Some(voice.voice_id.to_string());
@@ -144,24 +1243,35 @@ impl TextToSpeechBuilder {
     }
 
     /// Set the output format to use
-    pub fn output_format<S: Into<String>>(mut self, output_format: S) -> Self {
-        self.output_format = Some(output_format.into());
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
         self
     }
 
-    /// Set the model to use
-    pub fn model<S: Into<String>>(mut self, model_id: S) -> Self {
-        self.model_id = Some(model_id.into());
+    /// Set the model to use, as a [`models::ModelId`] or a model ID string
+    pub fn model<M: Into<models::ModelId>>(mut self, model_id: M) -> Self {
+        self.model_id = Some(model_id.into().to_string());
         self
     }
 
-    /// Set the language code to use
+    /// Set the language code to use, as a [`LanguageCode`] or an ISO 639-1 string.
+    /// Only models where [`models::ModelId::supports_language_code`] is true accept it.
     pub fn language_code<S: Into<String>>(mut self, language_code: S) -> Self {
         self.language_code = Some(language_code.into());
         self
     }
 
+    /// Detect the language of the text and send it as the language code when none is
+    /// set and the model is Flash or Turbo v2.5 (`langdetect` feature, on by default).
+    /// Text whose language cannot be detected reliably is sent without a code.
+    #[cfg(feature = "langdetect")]
+    pub fn detect_language(mut self, detect_language: bool) -> Self {
+        self.detect_language = detect_language;
+        self
+    }
+
     /// Set voice settings (stability, similarity_boost, style, user_speaker_boost and speed).
+    /// Without them the settings stored for the voice apply.
     pub fn voice_settings(mut self, settings: VoiceSettings) -> Self {
         self.voice_settings = Some(settings);
         self
@@ -173,31 +1283,51 @@ impl TextToSpeechBuilder {
         self
     }
 
-    /// Set the previous text
+    /// Set the text that came before this one, for continuity.
+    /// Cannot be combined with `previous_request_ids`.
     pub fn previous_text<S: Into<String>>(mut self, previous_text: S) -> Self {
         self.previous_text = Some(previous_text.into());
         self
     }
 
-    /// Set the next text
+    /// Set the text that comes after this one, for continuity.
+    /// Cannot be combined with `next_request_ids`.
     pub fn next_text<S: Into<String>>(mut self, next_text: S) -> Self {
         self.next_text = Some(next_text.into());
         self
     }
 
-    /// Set the prebious requests ids
-    pub fn previous_request_ids<S: Into<Vec<String>>>(mut self, previous_request_ids: S) -> Self {
-        self.previous_request_ids = Some(previous_request_ids.into());
+    /// Set the request IDs of up to [`MAX_REQUEST_IDS`] generations that came before this one.
+    /// Cannot be combined with `previous_text`.
+    pub fn previous_request_ids<I, S>(mut self, previous_request_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.previous_request_ids =
+            Some(previous_request_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the request IDs of up to [`MAX_REQUEST_IDS`] generations that come after this one.
+    /// Cannot be combined with `next_text`.
+    pub fn next_request_ids<I, S>(mut self, next_request_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.next_request_ids = Some(next_request_ids.into_iter().map(Into::into).collect());
         self
     }
 
-    /// Set the next text to use
-    pub fn next_request_ids<S: Into<Vec<String>>>(mut self, next_request_ids: S) -> Self {
-        self.next_request_ids = Some(next_request_ids.into());
+    /// Set the text normalization mode
+    pub fn text_normalization(mut self, text_normalization: TextNormalization) -> Self {
+        self.apply_text_normalization = Some(text_normalization.as_str().to_string());
         self
     }
 
-    /// Set the apply text normalization
+    /// Set the apply text normalization from a string (`auto`, `on` or `off`).
+    /// Other values are rejected when the request is executed.
     pub fn apply_text_normalization<S: Into<String>>(
         mut self,
         apply_text_normalization: S,
@@ -215,41 +1345,427 @@ impl TextToSpeechBuilder {
         self
     }
 
-    /// Execute the text-to-speech request
-    pub async fn execute(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+    /// Apply per-word pronunciation overrides (phoneme tags and aliases) to the text
+    pub fn pronunciation(mut self, rules: &PronunciationRules) -> Self {
+        self.text = rules.apply(&self.text);
+        self
+    }
+
+    /// Set to false to use zero retention mode: the generation isn't stored in history
+    /// and no logs are kept (Enterprise only)
+    pub fn enable_logging(mut self, enable_logging: bool) -> Self {
+        self.enable_logging = Some(enable_logging);
+        self
+    }
+
+    /// Set a timeout for this request, overriding the client-level timeout.
+    /// It applies to each HTTP attempt, from sending until the whole audio is received.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request (e.g. a correlation ID), overriding client defaults
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.options.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Check the request locally (text length for the model, request ID counts,
+    /// voice setting bounds, language code...) instead of waiting for a 422 from the API.
+    /// `execute()` runs it automatically.
+    pub fn validate(&self) -> Result<(), ElevenLabsTTSError> {
+        if self.text.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Text cannot be empty".to_string(),
+            ));
+        }
+
+        if let Some(voice_id) = &self.voice_id {
+            ids::check_not_voice_name(voice_id)?;
+        }
+
+        let model = self.resolved_model();
+        let characters = self.text.chars().count();
+        if let Some(max) = model.max_characters() {
+            if characters > max {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Text is {} characters long, {} accepts at most {}",
+                    characters, model, max
+                )));
+            }
+        }
+
+        for (name, ids, text_name, text) in [
+            (
+                "previous_request_ids",
+                &self.previous_request_ids,
+                "previous_text",
+                &self.previous_text,
+            ),
+            (
+                "next_request_ids",
+                &self.next_request_ids,
+                "next_text",
+                &self.next_text,
+            ),
+        ] {
+            if let Some(ids) = ids {
+                if ids.len() > MAX_REQUEST_IDS {
+                    return Err(ElevenLabsTTSError::ValidationError(format!(
+                        "At most {} {} can be sent, got {}",
+                        MAX_REQUEST_IDS,
+                        name,
+                        ids.len()
+                    )));
+                }
+                // The API silently ignores the text when request IDs are given
+                if !ids.is_empty() && text.is_some() {
+                    return Err(ElevenLabsTTSError::ValidationError(format!(
+                        "{} and {} are mutually exclusive, set only one of them",
+                        name, text_name
+                    )));
+                }
+            }
+        }
+
+        if let Some(settings) = &self.voice_settings {
+            settings.validate()?;
+        }
+
+        if let Some(mode) = &self.apply_text_normalization {
+            mode.parse::<TextNormalization>()?;
+        }
+
+        if let Some(language_code) = &self.language_code {
+            LanguageCode::new(language_code)?;
+            if !model.supports_language_code() {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Model {} doesn't support language_code",
+                    model
+                )));
+            }
+        }
+
+        if self.text.contains("<phoneme") && !model.supports_phoneme_tags() {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Model {} doesn't support phoneme tags",
+                model
+            )));
+        }
+
+        // Seeds are u32, so they're always within the API's 0..=4294967295 range
+        Ok(())
+    }
+
+    /// The detected language of the text, when detection applies to this request
+    #[cfg(feature = "langdetect")]
+    fn detected_language(&self) -> Option<String> {
+        let model = self.resolved_model();
+        let applies = matches!(
+            model,
+            models::ModelId::ElevenFlashV2_5 | models::ModelId::ElevenTurboV2_5
+        );
+        if !self.detect_language || !applies {
+            return None;
+        }
+        LanguageCode::detect(&self.text).map(String::from)
+    }
+
+    /// The model the request will use once defaults are applied
+    fn resolved_model(&self) -> models::ModelId {
+        self.model_id
+            .as_deref()
+            .unwrap_or(models::elevanlabs_models::ELEVEN_MULTILINGUAL_V2) // Default to: eleven_multilingual_v2
+            .into()
+    }
+
+    /// Resolve defaults and turn the builder into the request to send
+    fn into_request(self) -> Result<(C, TTSRequest, RequestOptions), ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        Ok((self.client, request, self.options))
+    }
+
+    /// Validate the builder and build the request body without sending it
+    pub fn build_request(&self) -> Result<TTSRequest, ElevenLabsTTSError> {
+        self.validate()?;
+
+        let apply_text_normalization = self
+            .apply_text_normalization
+            .as_deref()
+            .map(str::parse)
+            .transpose()?
+            .unwrap_or_default(); // Default to: auto
+
+        let model_id = self.resolved_model().to_string();
+
+        let language_code = self
+            .language_code
+            .as_deref()
+            .map(LanguageCode::new)
+            .transpose()?
+            .map(String::from);
+        #[cfg(feature = "langdetect")]
+        let language_code = language_code.or_else(|| self.detected_language());
+
         let voice_id = self
             .voice_id
+            .clone()
             .unwrap_or_else(|| voices::all_voices::RACHEL.voice_id.to_string()); // Default to: Rachel
 
-        let output_format = self
-            .output_format
-            .unwrap_or_else(|| "mp3_44100_128".to_string()); // Default to: mp3_44100_128
-
         let request = TTSRequest {
-            text: self.text,
-            voice_id: voice_id.clone(),
-            output_format: Some(output_format.clone()),
-            model_id: self
-                .model_id
-                .unwrap_or_else(|| models::elevanlabs_models::ELEVEN_MULTILINGUAL_V2.to_string()), // Default to: eleven_multilingual_v2
-            language_code: self.language_code.or(None), // Default to null
-            voice_settings: self.voice_settings.unwrap_or_default(), // Default voice settings
-            seed: self.seed.or(None),                   // Default to null
-            previous_text: self.previous_text.or(None), // Default to null
-            next_text: self.next_text.or(None),         // Default to null
-            previous_request_ids: self.previous_request_ids.or(None), // Default to null
-            next_request_ids: self.next_request_ids.or(None), // Default to null
-            apply_text_normalization: Some(
-                self.apply_text_normalization
-                    .unwrap_or_else(|| "auto".to_string()),
-            ), // Default to: auto
+            text: self.text.clone(),
+            voice_id,
+            output_format: self.output_format.unwrap_or_default(), // Default to: mp3_44100_128
+            model_id,
+            language_code,                                           // Default to null
+            voice_settings: self.voice_settings.clone(), // Default to null (stored settings)
+            seed: self.seed,                             // Default to null
+            previous_text: self.previous_text.clone(),   // Default to null
+            next_text: self.next_text.clone(),           // Default to null
+            previous_request_ids: self.previous_request_ids.clone(), // Default to null
+            next_request_ids: self.next_request_ids.clone(), // Default to null
+            apply_text_normalization: Some(apply_text_normalization),
             apply_language_text_normalization: Some(
-                self.apply_language_text_normalization
-                    .unwrap_or_else(|| false),
+                self.apply_language_text_normalization.unwrap_or(false),
             ), // Default to: false
+            enable_logging: self.enable_logging, // Default to null (logging enabled)
         };
 
-        self.client.execute_tts(request).await
+        Ok(request)
+    }
+
+    /// Predict the characters and credits this request will consume, without sending it
+    pub fn estimate_cost(&self) -> Result<CostEstimate, ElevenLabsTTSError> {
+        Ok(cost::estimate_cost(&self.build_request()?))
+    }
+}
+
+impl TextToSpeechBuilder {
+    /// Call `callback` with the bytes received so far and the time elapsed since the request
+    /// was sent, after every chunk of audio. Works for buffered and streamed execution.
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// let audio = client
+    ///     .text_to_speech("A long chapter...")
+    ///     .on_progress(|bytes_received, elapsed| {
+    ///         println!("{} KB after {:.1}s", bytes_received / 1024, elapsed.as_secs_f32());
+    ///     })
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn on_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(u64, Duration) + Send + Sync + 'static,
+    {
+        self.options.progress = Some(stream::Progress::new(callback));
+        self
+    }
+
+    /// Execute the text-to-speech request.
+    ///
+    /// Dropping the returned future (e.g. via `tokio::select!` or aborting its task)
+    /// cancels the generation and closes the underlying connection.
+    pub async fn execute(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        Ok(client.execute_tts(request, &options).await?.audio)
+    }
+
+    /// Execute the request after checking that the subscription has enough characters
+    /// left for it, failing fast with [`ElevenLabsTTSError::QuotaExceededError`] instead of
+    /// partway through a batch. The subscription is fetched once and reused for
+    /// [`SUBSCRIPTION_CACHE_TTL`](subscription::SUBSCRIPTION_CACHE_TTL), deducting what
+    /// each checked request was billed.
+    pub async fn execute_checked(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        let estimate = cost::estimate_cost(&request);
+        let needed = estimate
+            .credits
+            .map_or(estimate.characters, |credits| credits.ceil() as u64);
+        let subscription = &client.inner.subscription;
+        subscription
+            .get(|| client.subscription())
+            .await?
+            .check_quota(needed)?;
+
+        let response = client.execute_tts(request, &options).await?;
+        let billed = response.character_cost.unwrap_or(needed);
+        subscription
+            .update(|subscription| subscription.character_count += billed)
+            .await;
+        Ok(response.audio)
+    }
+
+    /// Execute the text-to-speech request and keep the response metadata
+    /// (request ID for stitching, history item ID, character cost, content type)
+    pub async fn execute_detailed(self) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        client.execute_tts(request, &options).await
+    }
+
+    /// Generate text longer than the model's limit in sentence-aligned chunks,
+    /// passing neighbouring chunks as `previous_text`/`next_text`
+    pub fn long_form(self) -> LongFormBuilder {
+        LongFormBuilder::new(self)
+    }
+
+    /// Execute the text-to-speech request, returning the audio with the timing of every
+    /// character (for captions, karaoke highlighting or lip sync)
+    pub async fn execute_with_timestamps(self) -> Result<TimestampedAudio, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        client.execute_tts_with_timestamps(request, &options).await
+    }
+
+    /// Execute the request as raw PCM and return it wrapped in a playable WAV file.
+    /// Uses the selected `pcm_*` output format, or `pcm_24000` when none is set.
+    pub async fn execute_wav(mut self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let format = *self.output_format.get_or_insert(OutputFormat::Pcm_24000);
+        if format.codec() != "pcm" {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "execute_wav needs a pcm_* output format, got {}",
+                format
+            )));
+        }
+        let pcm = self.execute().await?;
+        audio::wrap_wav(&pcm, format.sample_rate(), format.channels())
+    }
+
+    /// Execute the text-to-speech request and return the audio with its format
+    /// (sample rate, channels, suggested file extension)
+    pub async fn execute_output(self) -> Result<AudioOutput, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        let format = request.output_format;
+        let response = client.execute_tts(request, &options).await?;
+        Ok(AudioOutput::new(response.audio, format))
+    }
+
+    /// Describe the HTTP request without sending it: URL, query parameters,
+    /// headers (with the API key redacted) and JSON body
+    pub fn to_http_parts(&self) -> Result<HttpRequestParts, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        let url = format!(
+            "{}/text-to-speech/{}",
+            self.client.inner.base_url, request.voice_id
+        );
+        self.client
+            .http_parts(url, &request.query(), &request, &self.options)
+    }
+
+    /// Speak text as it is produced, e.g. by an LLM: tokens are buffered into sentences
+    /// ([`llm::SentenceBuffer`]) and each sentence is generated as soon as it is complete,
+    /// with the one before it as `previous_text`. The audio of all sentences comes out as
+    /// one continuous stream. The builder's own text is ignored.
+    ///
+    /// ```rust,no_run
+    /// use futures_util::{StreamExt, stream};
+    ///
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// let tokens = stream::iter(["Hel", "lo the", "re. How", " are you?"].map(String::from));
+    /// let mut audio = client.text_to_speech("").synthesize_stream(tokens);
+    /// while let Some(chunk) = audio.next().await {
+    ///     let chunk = chunk?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn synthesize_stream<S>(self, tokens: S) -> AudioStream
+    where
+        S: Stream<Item = String> + Send + 'static,
+    {
+        let mut previous_text: Option<String> = None;
+        let audio = llm::sentences(tokens)
+            .then(move |sentence| {
+                let mut builder = self.clone();
+                builder.text = sentence.clone();
+                builder.previous_text = previous_text.replace(sentence);
+                builder.execute_stream()
+            })
+            .map(|stream| match stream {
+                Ok(stream) => stream,
+                Err(error) => Box::pin(futures_stream::once(async { Err(error) })),
+            })
+            .flatten();
+        Box::pin(audio)
+    }
+
+    /// Execute the text-to-speech request and stream audio chunks as they are generated
+    pub async fn execute_stream(self) -> Result<AudioStream, ElevenLabsTTSError> {
+        let (client, request, options) = self.into_request()?;
+        client.execute_tts_stream(request, &options).await
+    }
+
+    /// Start playing the audio on the default output device as soon as the first chunks
+    /// arrive, returning a handle to pause, stop or wait for playback.
+    /// Needs a PCM, μ-law or A-law output format; `pcm_24000` is used when none is set.
+    #[cfg(feature = "playback")]
+    pub async fn play_stream(mut self) -> Result<playback::PlaybackHandle, ElevenLabsTTSError> {
+        let format = *self.output_format.get_or_insert(OutputFormat::Pcm_24000);
+        playback::play_stream(self.execute_stream().await?, format).await
+    }
+
+    /// Stream the audio as a response to an HTTP client (`axum` or `actix-web` feature),
+    /// with the output format's `Content-Type`; see [`web::SpeechResponse`]
+    #[cfg(any(feature = "axum", feature = "actix-web"))]
+    pub async fn execute_response(self) -> Result<web::SpeechResponse, ElevenLabsTTSError> {
+        let format = self.output_format.unwrap_or_default();
+        Ok(web::SpeechResponse::new(
+            self.execute_stream().await?,
+            format,
+        ))
+    }
+
+    /// Stream the audio into a songbird input for a Discord voice channel (`songbird`
+    /// feature); playback starts while the rest is still being generated. Needs an MP3
+    /// or Opus output format (MP3 by default).
+    #[cfg(feature = "songbird")]
+    pub async fn execute_songbird(self) -> Result<songbird::input::Input, ElevenLabsTTSError> {
+        let format = self.output_format.unwrap_or_default();
+        discord::input(self.execute_stream().await?, format)
+    }
+
+    /// Stream the generated audio into a writer (file, socket...) chunk by chunk,
+    /// without holding the whole audio in memory. Returns the number of bytes written.
+    pub async fn execute_to_writer<W>(self, writer: &mut W) -> Result<u64, ElevenLabsTTSError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        stream::write_to(self.execute_stream().await?, writer).await
+    }
+
+    /// Stream the generated audio as an `AsyncRead`, e.g. to pipe it into ffmpeg's stdin
+    pub async fn execute_reader(self) -> Result<AudioReader, ElevenLabsTTSError> {
+        Ok(AudioReader::new(self.execute_stream().await?))
+    }
+
+    /// Forward the audio chunks into a channel as they are generated, waiting whenever the
+    /// channel is full. Returns the number of bytes sent; the sender is dropped at the end,
+    /// which closes the channel unless other senders remain.
+    pub async fn execute_stream_to_channel(
+        self,
+        sender: mpsc::Sender<Bytes>,
+    ) -> Result<u64, ElevenLabsTTSError> {
+        stream::send_to(self.execute_stream().await?, &sender).await
+    }
+
+    /// Stream the generated audio into a file, creating or truncating it.
+    /// The partial file is removed if the generation fails midway.
+    pub async fn execute_to_file<P: AsRef<Path>>(self, path: P) -> Result<u64, ElevenLabsTTSError> {
+        stream::write_to_file(self.execute_stream().await?, path.as_ref()).await
+    }
+
+    /// Stream the generated audio to `base` plus the output format's
+    /// [`file_extension`](OutputFormat::file_extension) (`.mp3`, `.wav`, `.ogg`, `.ulaw`,
+    /// `.alaw`), creating parent directories. Raw PCM gets a WAV header. Returns the path
+    /// written.
+    pub async fn execute_to_path<P: AsRef<Path>>(
+        self,
+        base: P,
+    ) -> Result<PathBuf, ElevenLabsTTSError> {
+        let format = self.output_format.unwrap_or_default();
+        stream::write_to_path(self.execute_stream().await?, base.as_ref(), format).await
     }
 }
 
@@ -260,7 +1776,16 @@ mod tests {
     #[tokio::test]
     async fn test_client_creation() {
         let client = ElevenLabsTTSClient::new("test-key");
-        assert_eq!(client.api_key, "test-key");
+        assert_eq!(client.inner.api_key.get_key().await.unwrap(), "test-key");
+    }
+
+    #[test]
+    fn test_builders_share_client_configuration() {
+        let client = ElevenLabsTTSClient::new("test-key");
+        let builder = client.text_to_speech("Hello");
+
+        // Builders hold a handle to the same configuration rather than a deep copy
+        assert!(Arc::ptr_eq(&client.inner, &builder.client.inner));
     }
 
     #[test]
@@ -275,4 +1800,28 @@ mod tests {
         assert_eq!(builder.text, "Hello");
         assert_eq!(builder.voice_id, Some("voice-123".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_concurrency_limit_queues_requests() {
+        let client = ElevenLabsTTSClient::builder()
+            .api_key("test-key")
+            .max_concurrent_requests(2)
+            .build()
+            .unwrap();
+
+        let first = client.acquire_permit().await;
+        let _second = client.clone().acquire_permit().await;
+
+        // A third request waits rather than failing...
+        let third = tokio::time::timeout(
+            std::time::Duration::from_millis(20),
+            client.acquire_permit(),
+        )
+        .await;
+        assert!(third.is_err());
+
+        // ...and proceeds as soon as a slot frees up
+        drop(first);
+        assert!(client.acquire_permit().await.is_some());
+    }
 }
@@ -0,0 +1,151 @@
+//! Speech from LLM token streams
+//!
+//! Tokens are buffered until a sentence is complete, so each request (or WebSocket
+//! message) carries whole sentences and the voice gets the context it needs for natural
+//! prosody. See [`TextToSpeechBuilder::synthesize_stream`](crate::TextToSpeechBuilder::synthesize_stream)
+//! and, with the `websocket` feature, `WebSocketBuilder::synthesize_stream`.
+
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::long_form::{self, SENTENCE_ENDS};
+
+/// Longest text held back while waiting for the end of a sentence
+pub const DEFAULT_MAX_SENTENCE_CHARACTERS: usize = 300;
+
+/// Collects streamed tokens and hands out complete sentences
+///
+/// A sentence ends at a terminator (`.`, `!`, `?`...) followed by whitespace, or at a
+/// newline. A terminator at the very end of the buffer is not enough, since the next
+/// token may continue it (`3.` then `5`). Text that grows past the maximum without a
+/// sentence end is cut at the last whitespace.
+///
+/// ```rust
+/// use elevenlabs_tts::llm::SentenceBuffer;
+///
+/// let mut buffer = SentenceBuffer::new();
+/// assert!(buffer.push("Hello there").is_empty());
+/// assert_eq!(buffer.push("! It costs 3."), ["Hello there!"]);
+/// assert_eq!(buffer.push("5 dollars. Bye"), ["It costs 3.5 dollars."]);
+/// assert_eq!(buffer.finish(), Some("Bye".to_string()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SentenceBuffer {
+    buffer: String,
+    max_characters: usize,
+}
+
+impl Default for SentenceBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SentenceBuffer {
+    pub fn new() -> Self {
+        Self {
+            buffer: String::new(),
+            max_characters: DEFAULT_MAX_SENTENCE_CHARACTERS,
+        }
+    }
+
+    /// Set the longest text held back while waiting for the end of a sentence
+    pub fn max_characters(mut self, max_characters: usize) -> Self {
+        self.max_characters = max_characters.max(1);
+        self
+    }
+
+    /// Add a token, returning the sentences it completed (trimmed)
+    pub fn push(&mut self, token: &str) -> Vec<String> {
+        self.buffer.push_str(token);
+
+        let pieces = long_form::sentences(&self.buffer);
+        let last = pieces.len().saturating_sub(1);
+        let mut consumed = 0;
+        let mut sentences = Vec::new();
+        for (index, piece) in pieces.iter().enumerate() {
+            if index == last && !is_complete(piece) {
+                break;
+            }
+            consumed += piece.len();
+            push_trimmed(&mut sentences, piece);
+        }
+        self.buffer.drain(..consumed);
+
+        // No sentence end in sight: cut at the last word boundary
+        if self.buffer.chars().count() > self.max_characters {
+            if let Some(end) = self
+                .buffer
+                .rfind(char::is_whitespace)
+                .filter(|end| *end > 0)
+            {
+                let cut: String = self.buffer.drain(..end).collect();
+                push_trimmed(&mut sentences, &cut);
+            }
+        }
+        sentences
+    }
+
+    /// Take whatever text is left once the token stream has ended
+    pub fn finish(&mut self) -> Option<String> {
+        let rest = std::mem::take(&mut self.buffer);
+        let rest = rest.trim();
+        (!rest.is_empty()).then(|| rest.to_string())
+    }
+}
+
+/// Turn a stream of tokens into a stream of sentences
+pub fn sentences<S>(tokens: S) -> impl Stream<Item = String> + Send + 'static
+where
+    S: Stream<Item = String> + Send + 'static,
+{
+    let mut buffer = SentenceBuffer::new();
+    tokens
+        .map(Some)
+        .chain(stream::once(async { None }))
+        .flat_map(move |token| {
+            let sentences = match token {
+                Some(token) => buffer.push(&token),
+                None => buffer.finish().into_iter().collect(),
+            };
+            stream::iter(sentences)
+        })
+}
+
+/// Whether the last piece of the buffer ends a sentence, rather than just the buffer
+fn is_complete(piece: &str) -> bool {
+    let trimmed = piece.trim_end();
+    trimmed.len() < piece.len()
+        && (trimmed.ends_with(SENTENCE_ENDS) || piece[trimmed.len()..].contains('\n'))
+}
+
+fn push_trimmed(sentences: &mut Vec<String>, piece: &str) {
+    let sentence = piece.trim();
+    if !sentence.is_empty() {
+        sentences.push(sentence.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentence_buffer_splits_on_newlines_and_long_text() {
+        let mut buffer = SentenceBuffer::new();
+        assert_eq!(buffer.push("- first item\n- second"), ["- first item"]);
+        assert_eq!(buffer.push(" item\n\n"), ["- second item"]);
+        assert_eq!(buffer.finish(), None);
+
+        let mut buffer = SentenceBuffer::new().max_characters(10);
+        assert!(buffer.push("one two").is_empty());
+        assert_eq!(buffer.push(" three four"), ["one two three"]);
+        assert_eq!(buffer.finish(), Some("four".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_sentences_from_token_stream() {
+        let tokens = stream::iter(["Hi", "! Ready", "? Go", "."].map(String::from));
+        let sentences: Vec<String> = sentences(tokens).collect().await;
+        assert_eq!(sentences, ["Hi!", "Ready?", "Go."]);
+    }
+}
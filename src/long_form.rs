@@ -0,0 +1,329 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    AudioResponse, ElevenLabsTTSError, MAX_REQUEST_IDS, TextToSpeechBuilder, audio, stream,
+};
+
+/// Maximum chunk size when the model's character limit is unknown
+const DEFAULT_CHUNK_CHARACTERS: usize = 5_000;
+
+// Characters that end a sentence when followed by whitespace
+pub(crate) const SENTENCE_ENDS: [char; 7] = ['.', '!', '?', '…', '。', '！', '？'];
+
+/// Builder for texts longer than the model's character limit
+///
+/// The text is split into sentence-aligned chunks that are generated one after
+/// the other, each with its neighbours sent as `previous_text`/`next_text` so the
+/// prosody flows across chunk boundaries. Created by [`TextToSpeechBuilder::long_form`].
+pub struct LongFormBuilder {
+    builder: TextToSpeechBuilder,
+    max_chunk_characters: Option<usize>,
+}
+
+impl LongFormBuilder {
+    pub(crate) fn new(builder: TextToSpeechBuilder) -> Self {
+        Self {
+            builder,
+            max_chunk_characters: None,
+        }
+    }
+
+    /// Set the maximum characters per chunk (defaults to the model's limit)
+    pub fn max_chunk_characters(mut self, max_chunk_characters: usize) -> Self {
+        self.max_chunk_characters = Some(max_chunk_characters);
+        self
+    }
+
+    /// The chunks the text will be generated in
+    pub fn chunks(&self) -> Vec<String> {
+        let model_max = self
+            .builder
+            .resolved_model()
+            .max_characters()
+            .unwrap_or(DEFAULT_CHUNK_CHARACTERS);
+        let max = self
+            .max_chunk_characters
+            .map_or(model_max, |max| max.min(model_max));
+        split_text(&self.builder.text, max)
+    }
+
+    /// One request builder per chunk, each validated before anything is sent
+    fn chunk_builders(&self) -> Result<Vec<TextToSpeechBuilder>, ElevenLabsTTSError> {
+        let chunks = self.chunks();
+        if chunks.is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Text cannot be empty".to_string(),
+            ));
+        }
+
+        let builders = chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let mut builder = self.builder.clone();
+                builder.text = chunk.clone();
+                if index > 0 {
+                    builder.previous_text = Some(chunks[index - 1].clone());
+                }
+                if let Some(next) = chunks.get(index + 1) {
+                    builder.next_text = Some(next.clone());
+                }
+                builder.validate()?;
+                Ok(builder)
+            })
+            .collect::<Result<Vec<_>, ElevenLabsTTSError>>()?;
+        Ok(builders)
+    }
+
+    /// Generate every chunk in order, returning one clip per chunk
+    pub async fn execute_clips(self) -> Result<Vec<AudioResponse>, ElevenLabsTTSError> {
+        let mut clips = Vec::new();
+        for builder in self.chunk_builders()? {
+            clips.push(builder.execute_detailed().await?);
+        }
+        Ok(clips)
+    }
+
+    /// Generate every chunk in order and join the audio into one playable file,
+    /// see [`audio::concat`]
+    pub async fn execute(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let format = self.builder.output_format.unwrap_or_default();
+        let clips = self.execute_clips().await?;
+        audio::concat(clips.iter().map(|clip| &clip.audio), format)
+    }
+
+    /// Generate every chunk and save the joined audio to `base` plus the output format's
+    /// [`file_extension`](crate::OutputFormat::file_extension), creating parent
+    /// directories. Raw PCM gets a WAV header. Returns the path written.
+    pub async fn execute_to_path<P: AsRef<Path>>(
+        self,
+        base: P,
+    ) -> Result<PathBuf, ElevenLabsTTSError> {
+        let format = self.builder.output_format.unwrap_or_default();
+        let joined = self.execute().await?;
+        let path = stream::path_with_extension(base.as_ref(), format);
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let bytes = if format.codec() == "pcm" {
+            audio::wrap_wav(&joined, format.sample_rate(), format.channels())?
+        } else {
+            joined
+        };
+        tokio::fs::write(&path, bytes).await?;
+        Ok(path)
+    }
+}
+
+/// A clip generated within a [`StitchedSession`]
+#[derive(Debug, Clone)]
+pub struct StitchedClip {
+    /// The text this clip was generated from
+    pub text: String,
+
+    /// The generated audio and its request ID
+    pub response: AudioResponse,
+}
+
+/// Generate clips that are stitched together through request IDs
+///
+/// Each clip is generated with the `request-id`s of up to three preceding clips in
+/// `previous_request_ids`, which the API uses to match their prosody. Regenerating a
+/// clip also sends the IDs of the clips after it in `next_request_ids`, so a fixed
+/// clip blends with both neighbours. Created by [`ElevenLabsTTSClient::stitched_session`].
+///
+/// [`ElevenLabsTTSClient::stitched_session`]: crate::ElevenLabsTTSClient::stitched_session
+pub struct StitchedSession {
+    template: TextToSpeechBuilder,
+    clips: Vec<StitchedClip>,
+}
+
+impl StitchedSession {
+    pub(crate) fn new(template: TextToSpeechBuilder) -> Self {
+        Self {
+            template,
+            clips: Vec::new(),
+        }
+    }
+
+    /// Generate the next clip, continuing from the clips generated so far
+    pub async fn generate<S: Into<String>>(
+        &mut self,
+        text: S,
+    ) -> Result<&StitchedClip, ElevenLabsTTSError> {
+        let text = text.into();
+        let index = self.clips.len();
+        let response = self
+            .builder_for(index, text.clone())
+            .execute_detailed()
+            .await?;
+        self.clips.push(StitchedClip { text, response });
+        Ok(&self.clips[index])
+    }
+
+    /// Generate the clip at `index` again, stitched to the clips before and after it
+    pub async fn regenerate(&mut self, index: usize) -> Result<&StitchedClip, ElevenLabsTTSError> {
+        let text = self
+            .clips
+            .get(index)
+            .map(|clip| clip.text.clone())
+            .ok_or_else(|| {
+                ElevenLabsTTSError::ValidationError(format!(
+                    "No clip at index {} (session has {})",
+                    index,
+                    self.clips.len()
+                ))
+            })?;
+        let response = self.builder_for(index, text).execute_detailed().await?;
+        self.clips[index].response = response;
+        Ok(&self.clips[index])
+    }
+
+    /// Request builder for the clip at `index`, carrying its neighbours' request IDs
+    fn builder_for(&self, index: usize, text: String) -> TextToSpeechBuilder {
+        let mut builder = self.template.clone();
+        builder.text = text;
+
+        let previous = &self.clips[index.saturating_sub(MAX_REQUEST_IDS)..index];
+        let previous_ids = request_ids(previous);
+        if !previous_ids.is_empty() {
+            builder.previous_request_ids = Some(previous_ids);
+            builder.previous_text = None;
+        } else if let Some(clip) = previous.last() {
+            // No request ID came back, fall back to text context
+            builder.previous_text = Some(clip.text.clone());
+        }
+
+        let next_end = self.clips.len().min(index + 1 + MAX_REQUEST_IDS);
+        let next_ids = request_ids(self.clips.get(index + 1..next_end).unwrap_or_default());
+        if !next_ids.is_empty() {
+            builder.next_request_ids = Some(next_ids);
+            builder.next_text = None;
+        }
+        builder
+    }
+
+    /// The clips generated so far, in order
+    pub fn clips(&self) -> &[StitchedClip] {
+        &self.clips
+    }
+
+    /// The audio of every clip, concatenated in order
+    pub fn audio(&self) -> Vec<u8> {
+        self.clips
+            .iter()
+            .flat_map(|clip| clip.response.audio.iter().copied())
+            .collect()
+    }
+
+    /// Take the generated clips
+    pub fn into_clips(self) -> Vec<StitchedClip> {
+        self.clips
+    }
+}
+
+fn request_ids(clips: &[StitchedClip]) -> Vec<String> {
+    clips
+        .iter()
+        .filter_map(|clip| clip.response.request_id.clone())
+        .collect()
+}
+
+/// Split text into chunks of at most `max_characters` characters, breaking between
+/// sentences where possible, then between words, and only as a last resort inside a word
+///
+/// ```rust
+/// let chunks = elevenlabs_tts::long_form::split_text("One. Two! Three?", 10);
+/// assert_eq!(chunks, ["One. Two!", "Three?"]);
+/// ```
+pub fn split_text(text: &str, max_characters: usize) -> Vec<String> {
+    let max = max_characters.max(1);
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for sentence in sentences(text) {
+        if char_len(sentence.trim_end()) <= max {
+            push_piece(&mut chunks, &mut current, sentence, max);
+            continue;
+        }
+        for word in sentence.split_inclusive(char::is_whitespace) {
+            if char_len(word.trim_end()) <= max {
+                push_piece(&mut chunks, &mut current, word, max);
+                continue;
+            }
+            for piece in hard_split(word, max) {
+                push_piece(&mut chunks, &mut current, piece, max);
+            }
+        }
+    }
+    flush(&mut chunks, &mut current);
+    chunks
+}
+
+/// Split after sentence terminators and newlines, keeping the trailing whitespace
+pub(crate) fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((index, c)) = chars.next() {
+        let ends_sentence = c == '\n'
+            || (SENTENCE_ENDS.contains(&c)
+                && !matches!(chars.peek(), Some((_, next)) if !next.is_whitespace()));
+        if !ends_sentence {
+            continue;
+        }
+
+        let mut end = index + c.len_utf8();
+        while let Some(&(next_index, next)) = chars.peek() {
+            if !next.is_whitespace() {
+                break;
+            }
+            end = next_index + next.len_utf8();
+            chars.next();
+        }
+        sentences.push(&text[start..end]);
+        start = end;
+    }
+
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+    sentences
+}
+
+/// Split a single word into pieces of at most `max` characters
+fn hard_split(word: &str, max: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for (count, (index, _)) in word.char_indices().enumerate() {
+        if count > 0 && count % max == 0 {
+            pieces.push(&word[start..index]);
+            start = index;
+        }
+    }
+    pieces.push(&word[start..]);
+    pieces
+}
+
+fn push_piece(chunks: &mut Vec<String>, current: &mut String, piece: &str, max: usize) {
+    if char_len(current) + char_len(piece.trim_end()) > max {
+        flush(chunks, current);
+    }
+    current.push_str(piece);
+}
+
+fn flush(chunks: &mut Vec<String>, current: &mut String) {
+    let chunk = current.trim();
+    if !chunk.is_empty() {
+        chunks.push(chunk.to_string());
+    }
+    current.clear();
+}
+
+fn char_len(text: &str) -> usize {
+    text.chars().count()
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+
+/// Hooks for feeding request metrics into any metrics system (Prometheus, StatsD, logs...)
+///
+/// Every method has a no-op default, so implementors only override what they need.
+/// `endpoint` is a stable, low-cardinality label such as `text-to-speech` (IDs are never included).
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicU64, Ordering};
+/// use elevenlabs_tts::MetricsObserver;
+///
+/// #[derive(Default)]
+/// struct Counters {
+///     characters: AtomicU64,
+/// }
+///
+/// impl MetricsObserver for Counters {
+///     fn characters_billed(&self, _endpoint: &str, characters: u64) {
+///         self.characters.fetch_add(characters, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+pub trait MetricsObserver: Send + Sync {
+    /// An HTTP attempt is about to be sent (called once per retry attempt)
+    fn request_started(&self, _endpoint: &str) {}
+
+    /// An HTTP attempt finished. `status` is `None` when no response was received.
+    fn request_finished(&self, _endpoint: &str, _status: Option<u16>, _latency: Duration) {}
+
+    /// Body bytes were received (called per chunk when streaming)
+    fn bytes_received(&self, _endpoint: &str, _bytes: usize) {}
+
+    /// The API reported the number of characters billed for a request
+    fn characters_billed(&self, _endpoint: &str, _characters: u64) {}
+}
+
+// Lets callers keep a handle to their observer after passing it to the client builder
+impl<T: MetricsObserver + ?Sized> MetricsObserver for Arc<T> {
+    fn request_started(&self, endpoint: &str) {
+        (**self).request_started(endpoint)
+    }
+
+    fn request_finished(&self, endpoint: &str, status: Option<u16>, latency: Duration) {
+        (**self).request_finished(endpoint, status, latency)
+    }
+
+    fn bytes_received(&self, endpoint: &str, bytes: usize) {
+        (**self).bytes_received(endpoint, bytes)
+    }
+
+    fn characters_billed(&self, endpoint: &str, characters: u64) {
+        (**self).characters_billed(endpoint, characters)
+    }
+}
+
+/// Read the billed character count from the response headers
+pub(crate) fn character_cost(headers: &HeaderMap) -> Option<u64> {
+    ["character-cost", "x-character-count"]
+        .iter()
+        .find_map(|name| headers.get(*name))
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+}
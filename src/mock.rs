@@ -0,0 +1,209 @@
+//! Canned responses for [`ElevenLabsTTSClient::mock`](crate::ElevenLabsTTSClient::mock)
+
+use std::f64::consts::TAU;
+
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::{ElevenLabsTTSError, OutputFormat, audio};
+
+// Fake speech lasts this long per character of text, within the bounds below
+const MILLIS_PER_CHARACTER: u64 = 60;
+const MIN_DURATION_MS: u64 = 500;
+const MAX_DURATION_MS: u64 = 10_000;
+
+// A silent MPEG-1 Layer III frame: 128 kbps, 44.1 kHz, mono, 1152 samples
+const MP3_FRAME_HEADER: [u8; 4] = [0xFF, 0xFB, 0x90, 0xC4];
+const MP3_FRAME_LEN: usize = 417;
+const MP3_FRAME_MS: f64 = 1152.0 / 44.1;
+
+/// Answer a request without sending it: deterministic audio derived from the request
+/// (a sine wave whose pitch depends on the voice and text) plus plausible headers
+pub(crate) fn respond(
+    endpoint: &str,
+    request: reqwest::Request,
+) -> Result<reqwest::Response, ElevenLabsTTSError> {
+    let body: Value = request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .and_then(|bytes| serde_json::from_slice(bytes).ok())
+        .unwrap_or(Value::Null);
+    let query = |name: &str| {
+        request
+            .url()
+            .query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+    let format: OutputFormat = query("output_format")
+        .and_then(|format| format.parse().ok())
+        .unwrap_or_default();
+
+    let (text, duration_ms) = match endpoint {
+        "text-to-speech" | "text-to-speech-stream" | "text-to-speech-timestamps" => {
+            let text = body["text"].as_str().unwrap_or_default().to_string();
+            let duration = speech_duration(&text);
+            (text, duration)
+        }
+        "text-to-dialogue" => {
+            let text = body["inputs"]
+                .as_array()
+                .map(|inputs| {
+                    inputs
+                        .iter()
+                        .filter_map(|input| input["text"].as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .unwrap_or_default();
+            let duration = speech_duration(&text);
+            (text, duration)
+        }
+        "music" | "music-stream" => {
+            let prompt = body["prompt"].as_str().unwrap_or_default().to_string();
+            let duration = body["music_length_ms"]
+                .as_u64()
+                .unwrap_or(MAX_DURATION_MS)
+                .clamp(MIN_DURATION_MS, MAX_DURATION_MS);
+            (prompt, duration)
+        }
+        "voices-search" => return json_response(200, voices_page(&query)),
+        "convai-signed-url" => {
+            let agent_id = query("agent_id").unwrap_or_default();
+            return json_response(
+                200,
+                json!({ "signed_url": format!("wss://mock.elevenlabs.invalid/convai?agent_id={}", agent_id) }),
+            );
+        }
+        _ => {
+            return json_response(
+                501,
+                json!({ "detail": { "status": "not_mocked", "message": format!("The mock client has no canned response for {}", endpoint) } }),
+            );
+        }
+    };
+
+    let seed = digest(&[request.url().path(), &text]);
+    let frequency = 220.0 + (seed % 440) as f64;
+    let audio = encode(format, duration_ms, frequency);
+
+    if endpoint == "text-to-speech-timestamps" {
+        use base64::Engine;
+
+        // Spread the characters evenly over the audio
+        let characters: Vec<String> = text.chars().map(String::from).collect();
+        let step = duration_ms as f64 / 1000.0 / characters.len().max(1) as f64;
+        let starts: Vec<f64> = (0..characters.len()).map(|i| i as f64 * step).collect();
+        let ends: Vec<f64> = starts.iter().map(|start| start + step).collect();
+        let alignment = json!({
+            "characters": characters,
+            "character_start_times_seconds": starts,
+            "character_end_times_seconds": ends,
+        });
+        return json_response(
+            200,
+            json!({
+                "audio_base64": base64::engine::general_purpose::STANDARD.encode(&audio),
+                "alignment": alignment,
+                "normalized_alignment": alignment,
+            }),
+        );
+    }
+
+    let response = http::Response::builder()
+        .status(200)
+        .header("content-type", content_type(format))
+        .header("request-id", format!("mock-{:016x}", seed))
+        .header("character-cost", text.chars().count().to_string())
+        .body(audio)
+        .map_err(|err| ElevenLabsTTSError::ValidationError(err.to_string()))?;
+    Ok(response.into())
+}
+
+fn json_response(status: u16, body: Value) -> Result<reqwest::Response, ElevenLabsTTSError> {
+    let response = http::Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(body.to_string())
+        .map_err(|err| ElevenLabsTTSError::ValidationError(err.to_string()))?;
+    Ok(response.into())
+}
+
+/// The pre-made voices matching the search, paged with the offset as the page token
+fn voices_page(query: &dyn Fn(&str) -> Option<String>) -> Value {
+    let search = query("search").unwrap_or_default().to_lowercase();
+    let matching: Vec<_> = crate::voices::all()
+        .iter()
+        .filter(|voice| voice.name.to_lowercase().contains(&search))
+        .collect();
+    let start: usize = query("next_page_token")
+        .and_then(|token| token.parse().ok())
+        .unwrap_or(0);
+    let page_size: usize = query("page_size")
+        .and_then(|size| size.parse().ok())
+        .unwrap_or(10);
+    let end = (start + page_size).min(matching.len());
+    let voices: Vec<Value> = matching[start.min(end)..end]
+        .iter()
+        .map(|voice| {
+            json!({
+                "voice_id": voice.voice_id,
+                "name": voice.name,
+                "category": "premade",
+                "labels": { "gender": voice.gender, "accent": voice.accent, "use_case": voice.use_case },
+            })
+        })
+        .collect();
+    let has_more = end < matching.len();
+    json!({
+        "voices": voices,
+        "has_more": has_more,
+        "total_count": matching.len(),
+        "next_page_token": has_more.then(|| end.to_string()),
+    })
+}
+
+fn speech_duration(text: &str) -> u64 {
+    (text.chars().count() as u64 * MILLIS_PER_CHARACTER).clamp(MIN_DURATION_MS, MAX_DURATION_MS)
+}
+
+fn digest(parts: &[&str]) -> u64 {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        hasher.update([0]);
+    }
+    let hash = hasher.finalize();
+    u64::from_be_bytes(hash[..8].try_into().unwrap_or_default())
+}
+
+fn content_type(format: OutputFormat) -> &'static str {
+    match format.codec() {
+        "mp3" => "audio/mpeg",
+        "ulaw" => "audio/basic",
+        "alaw" => "audio/x-alaw-basic",
+        // Opus formats get the raw PCM samples, there is no Opus encoder here
+        _ => "audio/pcm",
+    }
+}
+
+/// Audio of the given length: silent MP3 frames, or a sine wave for the sample formats
+fn encode(format: OutputFormat, duration_ms: u64, frequency: f64) -> Vec<u8> {
+    if format.codec() == "mp3" {
+        let frames = (duration_ms as f64 / MP3_FRAME_MS).ceil() as usize;
+        let mut frame = vec![0u8; MP3_FRAME_LEN];
+        frame[..4].copy_from_slice(&MP3_FRAME_HEADER);
+        return frame.repeat(frames);
+    }
+
+    let sample_rate = format.sample_rate();
+    let samples = (0..sample_rate as u64 * duration_ms / 1000).map(|index| {
+        let phase = TAU * frequency * index as f64 / f64::from(sample_rate);
+        (phase.sin() * 0.3 * f64::from(i16::MAX)) as i16
+    });
+    match format.codec() {
+        "ulaw" => samples.map(audio::linear_to_ulaw).collect(),
+        "alaw" => samples.map(audio::linear_to_alaw).collect(),
+        _ => samples.flat_map(i16::to_le_bytes).collect(),
+    }
+}
@@ -1,3 +1,15 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ElevenLabsTTSError, TTSRequest};
+
+/// How long the models list fetched for capability checks is reused
+pub const MODEL_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
 /// Elevanlabs common model IDs as constants for convenience
 pub mod elevanlabs_models {
     pub const ELEVEN_V3: &str = "eleven_v3";
@@ -10,4 +22,259 @@ pub mod elevanlabs_models {
     pub const ELEVEN_MULTILINGUAL_STS_V2: &str = "eleven_multilingual_sts_v2";
     pub const ELEVEN_ENGLISH_STS_V2: &str = "eleven_english_sts_v2";
     pub const ELEVEN_MONOLINGUAL_V1: &str = "eleven_monolingual_v1";
+    pub const MUSIC_V1: &str = "music_v1";
+}
+
+/// Typed model identifier, accepted by every builder's `.model()`
+///
+/// Known models have their own variant so typos fail to compile; models released after
+/// this crate can still be used through `Custom` (or by passing the ID as a string).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ModelId {
+    ElevenV3,
+    ElevenFlashV2_5,
+    ElevenFlashV2,
+    ElevenTurboV2_5,
+    ElevenTurboV2,
+    ElevenMultilingualV2,
+    ElevenMultilingualV1,
+    ElevenMultilingualStsV2,
+    ElevenEnglishStsV2,
+    ElevenMonolingualV1,
+    MusicV1,
+    Custom(String),
+}
+
+impl ModelId {
+    /// Every known model, excluding `Custom`
+    pub const KNOWN: &'static [ModelId] = &[
+        ModelId::ElevenV3,
+        ModelId::ElevenFlashV2_5,
+        ModelId::ElevenFlashV2,
+        ModelId::ElevenTurboV2_5,
+        ModelId::ElevenTurboV2,
+        ModelId::ElevenMultilingualV2,
+        ModelId::ElevenMultilingualV1,
+        ModelId::ElevenMultilingualStsV2,
+        ModelId::ElevenEnglishStsV2,
+        ModelId::ElevenMonolingualV1,
+        ModelId::MusicV1,
+    ];
+
+    /// The model ID sent to the API, e.g. `eleven_multilingual_v2`
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModelId::ElevenV3 => elevanlabs_models::ELEVEN_V3,
+            ModelId::ElevenFlashV2_5 => elevanlabs_models::ELEVEN_FLASH_V2_5,
+            ModelId::ElevenFlashV2 => elevanlabs_models::ELEVEN_FLASH_V2,
+            ModelId::ElevenTurboV2_5 => elevanlabs_models::ELEVEN_TURBO_V2_5,
+            ModelId::ElevenTurboV2 => elevanlabs_models::ELEVEN_TURBO_V2,
+            ModelId::ElevenMultilingualV2 => elevanlabs_models::ELEVEN_MULTILINGUAL_V2,
+            ModelId::ElevenMultilingualV1 => elevanlabs_models::ELEVEN_MULTILINGUAL_V1,
+            ModelId::ElevenMultilingualStsV2 => elevanlabs_models::ELEVEN_MULTILINGUAL_STS_V2,
+            ModelId::ElevenEnglishStsV2 => elevanlabs_models::ELEVEN_ENGLISH_STS_V2,
+            ModelId::ElevenMonolingualV1 => elevanlabs_models::ELEVEN_MONOLINGUAL_V1,
+            ModelId::MusicV1 => elevanlabs_models::MUSIC_V1,
+            ModelId::Custom(id) => id,
+        }
+    }
+
+    /// Maximum number of characters in a single text-to-speech request, when known
+    pub fn max_characters(&self) -> Option<usize> {
+        match self {
+            ModelId::ElevenV3 => Some(5_000),
+            ModelId::ElevenFlashV2_5 | ModelId::ElevenTurboV2_5 => Some(40_000),
+            ModelId::ElevenFlashV2 | ModelId::ElevenTurboV2 => Some(30_000),
+            ModelId::ElevenMultilingualV2
+            | ModelId::ElevenMultilingualV1
+            | ModelId::ElevenMonolingualV1 => Some(10_000),
+            _ => None,
+        }
+    }
+
+    /// Credits charged per character of text, when known.
+    /// Flash and Turbo models bill half a credit per character.
+    pub fn credits_per_character(&self) -> Option<f64> {
+        match self {
+            ModelId::ElevenFlashV2_5
+            | ModelId::ElevenTurboV2_5
+            | ModelId::ElevenFlashV2
+            | ModelId::ElevenTurboV2 => Some(0.5),
+            ModelId::ElevenV3
+            | ModelId::ElevenMultilingualV2
+            | ModelId::ElevenMultilingualV1
+            | ModelId::ElevenMonolingualV1 => Some(1.0),
+            _ => None,
+        }
+    }
+
+    /// Whether the model honours `<phoneme>` tags in the text.
+    /// Custom models are assumed to, leaving the decision to the API.
+    pub fn supports_phoneme_tags(&self) -> bool {
+        matches!(
+            self,
+            ModelId::ElevenFlashV2
+                | ModelId::ElevenTurboV2
+                | ModelId::ElevenMonolingualV1
+                | ModelId::Custom(_)
+        )
+    }
+
+    /// Whether the model accepts `language_code` to enforce a language.
+    /// Custom models are assumed to, leaving the decision to the API.
+    pub fn supports_language_code(&self) -> bool {
+        matches!(
+            self,
+            ModelId::ElevenV3
+                | ModelId::ElevenFlashV2_5
+                | ModelId::ElevenTurboV2_5
+                | ModelId::Custom(_)
+        )
+    }
+}
+
+impl fmt::Display for ModelId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ModelId {
+    fn from(id: &str) -> Self {
+        ModelId::KNOWN
+            .iter()
+            .find(|model| model.as_str() == id)
+            .cloned()
+            .unwrap_or_else(|| ModelId::Custom(id.to_string()))
+    }
+}
+
+impl From<String> for ModelId {
+    fn from(id: String) -> Self {
+        ModelId::from(id.as_str())
+    }
+}
+
+impl From<&ModelId> for ModelId {
+    fn from(model: &ModelId) -> Self {
+        model.clone()
+    }
+}
+
+impl FromStr for ModelId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(ModelId::from(s))
+    }
+}
+
+impl Serialize for ModelId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModelId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ModelId::from(String::deserialize(deserializer)?))
+    }
+}
+
+/// A model available to the account, as returned by `GET /v1/models`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Model {
+    pub model_id: ModelId,
+    pub name: String,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    #[serde(default = "default_true")]
+    pub can_do_text_to_speech: bool,
+
+    #[serde(default)]
+    pub can_do_voice_conversion: bool,
+
+    /// Whether the `style` voice setting has an effect
+    #[serde(default = "default_true")]
+    pub can_use_style: bool,
+
+    #[serde(default = "default_true")]
+    pub can_use_speaker_boost: bool,
+
+    /// Languages the model speaks; `language_code` must be one of them
+    #[serde(default)]
+    pub languages: Vec<ModelLanguage>,
+
+    /// Longest text accepted in a single request
+    #[serde(default)]
+    pub maximum_text_length_per_request: Option<usize>,
+}
+
+/// A language spoken by a [`Model`]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ModelLanguage {
+    /// ISO 639-1 code, e.g. `en`
+    pub language_id: String,
+    pub name: String,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Model {
+    /// Whether the model speaks the language with the given ISO 639-1 code
+    pub fn supports_language(&self, language_code: &str) -> bool {
+        self.languages
+            .iter()
+            .any(|language| language.language_id.eq_ignore_ascii_case(language_code))
+    }
+
+    /// Check that the model can serve a text-to-speech request, explaining what it can't do
+    pub fn check_request(&self, request: &TTSRequest) -> Result<(), ElevenLabsTTSError> {
+        let unsupported = |what: String| {
+            Err(ElevenLabsTTSError::ValidationError(format!(
+                "Model {} {}",
+                self.model_id, what
+            )))
+        };
+
+        if !self.can_do_text_to_speech {
+            return unsupported("doesn't support text-to-speech".to_string());
+        }
+        if let Some(max) = self.maximum_text_length_per_request {
+            let length = request.text.chars().count();
+            if length > max {
+                return unsupported(format!(
+                    "accepts at most {} characters per request, got {}",
+                    max, length
+                ));
+            }
+        }
+        if let Some(language_code) = &request.language_code {
+            if !self.languages.is_empty() && !self.supports_language(language_code) {
+                let supported: Vec<&str> = self
+                    .languages
+                    .iter()
+                    .map(|language| language.language_id.as_str())
+                    .collect();
+                return unsupported(format!(
+                    "doesn't support language_code {} (supported: {})",
+                    language_code,
+                    supported.join(", ")
+                ));
+            }
+        }
+        let style = request
+            .voice_settings
+            .as_ref()
+            .and_then(|settings| settings.style)
+            .unwrap_or_default();
+        if style > 0.0 && !self.can_use_style {
+            return unsupported(format!("doesn't support the style setting (got {})", style));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,114 @@
+//! Minimal `multipart/form-data` bodies for the upload endpoints
+//!
+//! The body is built in memory rather than streamed, so it can be cloned and retried
+//! like the JSON requests.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static BOUNDARY_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// One field of a form
+struct Part {
+    name: String,
+    file: Option<(String, String)>,
+    value: Vec<u8>,
+}
+
+/// A `multipart/form-data` body under construction
+pub(crate) struct Form {
+    parts: Vec<Part>,
+}
+
+impl Form {
+    pub(crate) fn new() -> Self {
+        Self { parts: Vec::new() }
+    }
+
+    /// Add a text field
+    pub(crate) fn text<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.parts.push(Part {
+            name: name.into(),
+            file: None,
+            value: value.into().into_bytes(),
+        });
+        self
+    }
+
+    /// Add a file field with its file name and MIME type
+    pub(crate) fn file<N: Into<String>>(
+        mut self,
+        name: N,
+        file_name: &str,
+        content_type: &str,
+        value: Vec<u8>,
+    ) -> Self {
+        self.parts.push(Part {
+            name: name.into(),
+            file: Some((file_name.to_string(), content_type.to_string())),
+            value,
+        });
+        self
+    }
+
+    /// The `Content-Type` header value and the encoded body
+    pub(crate) fn encode(self) -> (String, Vec<u8>) {
+        let boundary = self.boundary();
+        let mut body =
+            Vec::with_capacity(self.parts.iter().map(|part| part.value.len() + 160).sum());
+        for part in &self.parts {
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            let name = escape(&part.name);
+            match &part.file {
+                Some((file_name, content_type)) => body.extend_from_slice(
+                    format!(
+                        "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                        name,
+                        escape(file_name),
+                        content_type
+                    )
+                    .as_bytes(),
+                ),
+                None => body.extend_from_slice(
+                    format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n", name)
+                        .as_bytes(),
+                ),
+            }
+            body.extend_from_slice(&part.value);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+        (format!("multipart/form-data; boundary={}", boundary), body)
+    }
+
+    /// A boundary that appears in none of the field values
+    fn boundary(&self) -> String {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.subsec_nanos());
+        loop {
+            let count = BOUNDARY_COUNTER.fetch_add(1, Ordering::Relaxed);
+            let boundary = format!("elevenlabs-tts-{:08x}{:016x}", nanos, count);
+            if !self
+                .parts
+                .iter()
+                .any(|part| contains(&part.value, boundary.as_bytes()))
+            {
+                return boundary;
+            }
+        }
+    }
+}
+
+/// Quote-safe field and file names; browsers percent-encode the same characters
+fn escape(name: &str) -> String {
+    name.replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
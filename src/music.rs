@@ -0,0 +1,181 @@
+use std::path::Path;
+use std::time::Duration;
+
+use bytes::Bytes;
+use serde::Serialize;
+use tokio::io::AsyncWrite;
+use tokio::sync::mpsc;
+
+use crate::{
+    AudioReader, AudioStream, ElevenLabsTTSClient, ElevenLabsTTSError, HttpRequestParts,
+    OutputFormat, RequestOptions, models, stream,
+};
+
+/// Shortest composition the music API accepts, in milliseconds
+pub const MIN_MUSIC_LENGTH_MS: u32 = 10_000;
+
+/// Longest composition the music API accepts, in milliseconds
+pub const MAX_MUSIC_LENGTH_MS: u32 = 300_000;
+
+/// Request body for Eleven Music API calls
+#[derive(Debug, Clone, Serialize)]
+pub struct MusicRequest {
+    // A simple text prompt describing the music to generate, e.g. "calm lo-fi beat with soft piano".
+    pub prompt: String,
+
+    // The length of the composition in milliseconds, between 10000 and 300000.
+    // If omitted, the model chooses a length based on the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub music_length_ms: Option<u32>,
+
+    // Identifier of the music model. Defaults to: music_v1
+    pub model_id: String,
+
+    // If true, the generated music is guaranteed not to contain vocals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_instrumental: Option<bool>,
+
+    // Output format of the generated audio, sent as a query parameter.
+    #[serde(skip_serializing)]
+    pub output_format: OutputFormat,
+}
+
+/// Builder for Eleven Music generation requests
+pub struct MusicBuilder {
+    client: ElevenLabsTTSClient,
+    prompt: String,
+    music_length_ms: Option<u32>,
+    model_id: Option<String>,
+    force_instrumental: Option<bool>,
+    output_format: Option<OutputFormat>,
+    options: RequestOptions,
+}
+
+impl MusicBuilder {
+    pub(crate) fn new(client: ElevenLabsTTSClient, prompt: String) -> Self {
+        Self {
+            client,
+            prompt,
+            music_length_ms: None,
+            model_id: None,
+            force_instrumental: None,
+            output_format: None,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Set the composition length (10 seconds to 5 minutes)
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.music_length_ms = Some(duration.as_millis().min(u32::MAX as u128) as u32);
+        self
+    }
+
+    /// Set the composition length in milliseconds (10000 - 300000)
+    pub fn duration_ms(mut self, music_length_ms: u32) -> Self {
+        self.music_length_ms = Some(music_length_ms);
+        self
+    }
+
+    /// Guarantee the generated music has no vocals
+    pub fn instrumental(mut self, instrumental: bool) -> Self {
+        self.force_instrumental = Some(instrumental);
+        self
+    }
+
+    /// Set the model to use, as a [`models::ModelId`] or a model ID string
+    pub fn model<M: Into<models::ModelId>>(mut self, model_id: M) -> Self {
+        self.model_id = Some(model_id.into().to_string());
+        self
+    }
+
+    /// Set the output format to use
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+
+    /// Add a header to this request (e.g. a correlation ID), overriding client defaults
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.options.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Build the request body without sending it
+    pub fn build_request(&self) -> Result<MusicRequest, ElevenLabsTTSError> {
+        if self.prompt.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Music prompt cannot be empty".to_string(),
+            ));
+        }
+
+        if let Some(length) = self.music_length_ms {
+            if !(MIN_MUSIC_LENGTH_MS..=MAX_MUSIC_LENGTH_MS).contains(&length) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Music length must be between {} and {} ms, got {}",
+                    MIN_MUSIC_LENGTH_MS, MAX_MUSIC_LENGTH_MS, length
+                )));
+            }
+        }
+
+        Ok(MusicRequest {
+            prompt: self.prompt.clone(),
+            music_length_ms: self.music_length_ms,
+            model_id: self
+                .model_id
+                .clone()
+                .unwrap_or_else(|| models::elevanlabs_models::MUSIC_V1.to_string()), // Default to: music_v1
+            force_instrumental: self.force_instrumental,
+            output_format: self.output_format.unwrap_or_default(), // Default to: mp3_44100_128
+        })
+    }
+
+    /// Describe the HTTP request without sending it, with the API key redacted
+    pub fn to_http_parts(&self) -> Result<HttpRequestParts, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        let url = format!("{}/music", self.client.inner.base_url);
+        let query = [("output_format", request.output_format.to_string())];
+        self.client.http_parts(url, &query, &request, &self.options)
+    }
+
+    /// Execute the music request and collect the whole audio
+    pub async fn execute(self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        self.client.execute_music(request, &self.options).await
+    }
+
+    /// Execute the music request and stream audio chunks as they are generated
+    pub async fn execute_stream(self) -> Result<AudioStream, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        self.client
+            .execute_music_stream(request, &self.options)
+            .await
+    }
+
+    /// Stream the generated music into a writer chunk by chunk. Returns the number of bytes written.
+    pub async fn execute_to_writer<W>(self, writer: &mut W) -> Result<u64, ElevenLabsTTSError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        stream::write_to(self.execute_stream().await?, writer).await
+    }
+
+    /// Stream the generated music as an `AsyncRead`
+    pub async fn execute_reader(self) -> Result<AudioReader, ElevenLabsTTSError> {
+        Ok(AudioReader::new(self.execute_stream().await?))
+    }
+
+    /// Forward the music chunks into a channel as they are generated, waiting whenever the
+    /// channel is full. Returns the number of bytes sent; the sender is dropped at the end.
+    pub async fn execute_stream_to_channel(
+        self,
+        sender: mpsc::Sender<Bytes>,
+    ) -> Result<u64, ElevenLabsTTSError> {
+        stream::send_to(self.execute_stream().await?, &sender).await
+    }
+
+    /// Stream the generated music into a file, creating or truncating it.
+    /// The partial file is removed if the generation fails midway.
+    pub async fn execute_to_file<P: AsRef<Path>>(self, path: P) -> Result<u64, ElevenLabsTTSError> {
+        stream::write_to_file(self.execute_stream().await?, path.as_ref()).await
+    }
+}
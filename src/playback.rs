@@ -0,0 +1,210 @@
+//! Local playback of generated audio on the default output device (`playback` feature)
+//!
+//! MP3 is decoded in-process, PCM and G.711 (μ-law/A-law) are played as samples.
+//! Opus output cannot be played, request an MP3 or PCM format instead.
+//! Playing while streaming ([`PlaybackHandle`]) needs a raw format (PCM, μ-law, A-law),
+//! since MP3 cannot be decoded chunk by chunk here.
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+
+use futures_util::StreamExt;
+use rodio::buffer::SamplesBuffer;
+use rodio::{Decoder, OutputStream, Sink};
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+
+use crate::{AudioStream, ElevenLabsTTSError, OutputFormat, audio};
+
+/// Play audio on the default output device, blocking until playback has finished
+pub fn play(bytes: &[u8], format: OutputFormat) -> Result<(), ElevenLabsTTSError> {
+    // The stream must stay alive for as long as the sink is playing
+    let (_stream, handle) = OutputStream::try_default().map_err(playback_error)?;
+    let sink = Sink::try_new(&handle).map_err(playback_error)?;
+    append(&sink, bytes, format)?;
+    sink.sleep_until_end();
+    Ok(())
+}
+
+/// Queue audio on a sink, decoding it according to its format
+pub(crate) fn append(
+    sink: &Sink,
+    bytes: &[u8],
+    format: OutputFormat,
+) -> Result<(), ElevenLabsTTSError> {
+    if format.codec() == "mp3" {
+        let decoder = Decoder::new_mp3(Cursor::new(bytes.to_vec())).map_err(playback_error)?;
+        sink.append(decoder);
+        return Ok(());
+    }
+    let samples = decode_raw(bytes, format).ok_or_else(|| {
+        ElevenLabsTTSError::ValidationError(format!(
+            "Playback of {} is not supported, use an MP3 or PCM output format",
+            format
+        ))
+    })?;
+    sink.append(SamplesBuffer::new(
+        format.channels(),
+        format.sample_rate(),
+        samples,
+    ));
+    Ok(())
+}
+
+/// Samples of a raw format, `None` for formats that need a decoder
+fn decode_raw(bytes: &[u8], format: OutputFormat) -> Option<Vec<i16>> {
+    match format.codec() {
+        "pcm" => Some(
+            bytes
+                .chunks_exact(2)
+                .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+                .collect(),
+        ),
+        "ulaw" => Some(
+            bytes
+                .iter()
+                .map(|&byte| audio::ulaw_to_linear(byte))
+                .collect(),
+        ),
+        "alaw" => Some(
+            bytes
+                .iter()
+                .map(|&byte| audio::alaw_to_linear(byte))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Control over audio that starts playing while it is still being generated
+///
+/// Dropping the handle lets playback run to the end; call [`stop`](Self::stop) to cut it short.
+pub struct PlaybackHandle {
+    sink: Arc<Sink>,
+    stopped: Arc<AtomicBool>,
+    download: JoinHandle<Result<(), ElevenLabsTTSError>>,
+    finished: oneshot::Receiver<()>,
+}
+
+impl PlaybackHandle {
+    /// Pause playback; the rest of the audio keeps downloading
+    pub fn pause(&self) {
+        self.sink.pause();
+    }
+
+    /// Resume paused playback
+    pub fn resume(&self) {
+        self.sink.play();
+    }
+
+    /// Whether playback is paused
+    pub fn is_paused(&self) -> bool {
+        self.sink.is_paused()
+    }
+
+    /// Set the volume, `1.0` being the original level
+    pub fn set_volume(&self, volume: f32) {
+        self.sink.set_volume(volume);
+    }
+
+    /// Stop playback and cancel the rest of the generation
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        self.sink.stop();
+        self.download.abort();
+    }
+
+    /// Wait until all audio has been received and played (or playback was stopped).
+    /// Returns the error if the stream failed midway; what was received is still played.
+    pub async fn wait(self) -> Result<(), ElevenLabsTTSError> {
+        let downloaded = match self.download.await {
+            Ok(result) => result,
+            Err(error) if error.is_cancelled() => Ok(()),
+            Err(error) => Err(playback_error(error)),
+        };
+        let _ = self.finished.await;
+        downloaded
+    }
+}
+
+/// Play a raw audio stream as its chunks arrive
+pub(crate) async fn play_stream(
+    mut stream: AudioStream,
+    format: OutputFormat,
+) -> Result<PlaybackHandle, ElevenLabsTTSError> {
+    if decode_raw(&[], format).is_none() {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "Playing while streaming needs a PCM, μ-law or A-law output format, got {}",
+            format
+        )));
+    }
+    let bytes_per_sample = if format.codec() == "pcm" { 2 } else { 1 };
+
+    let (opened_tx, opened_rx) = oneshot::channel();
+    let (finished_tx, finished) = oneshot::channel();
+    let (samples_tx, samples_rx) = mpsc::channel::<Vec<i16>>();
+    let stopped = Arc::new(AtomicBool::new(false));
+
+    // The output stream is not `Send` on every platform, so it lives on its own thread
+    let player_stopped = stopped.clone();
+    std::thread::spawn(move || {
+        let opened = OutputStream::try_default()
+            .map_err(playback_error)
+            .and_then(|(stream, handle)| {
+                let sink = Sink::try_new(&handle).map_err(playback_error)?;
+                Ok((stream, Arc::new(sink)))
+            });
+        let (_stream, sink) = match opened {
+            Ok(opened) => opened,
+            Err(error) => {
+                let _ = opened_tx.send(Err(error));
+                return;
+            }
+        };
+        let _ = opened_tx.send(Ok(sink.clone()));
+
+        while let Ok(samples) = samples_rx.recv() {
+            if player_stopped.load(Ordering::SeqCst) {
+                break;
+            }
+            sink.append(SamplesBuffer::new(
+                format.channels(),
+                format.sample_rate(),
+                samples,
+            ));
+        }
+        if !player_stopped.load(Ordering::SeqCst) {
+            sink.sleep_until_end();
+        }
+        let _ = finished_tx.send(());
+    });
+    let sink = opened_rx.await.map_err(playback_error)??;
+
+    let download = tokio::spawn(async move {
+        // A chunk may end in the middle of a sample, keep the remainder for the next one
+        let mut pending = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            pending.extend_from_slice(&chunk?);
+            let complete = pending.len() - pending.len() % bytes_per_sample;
+            let samples = decode_raw(&pending[..complete], format).unwrap_or_default();
+            pending.drain(..complete);
+            if samples_tx.send(samples).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    });
+
+    Ok(PlaybackHandle {
+        sink,
+        stopped,
+        download,
+        finished,
+    })
+}
+
+pub(crate) fn playback_error(error: impl std::fmt::Display) -> ElevenLabsTTSError {
+    ElevenLabsTTSError::PlaybackError(error.to_string())
+}
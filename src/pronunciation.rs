@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ElevenLabsTTSError;
+
+// ARPAbet phones accepted by the CMU alphabet; vowels may carry a 0/1/2 stress digit
+const ARPABET_VOWELS: [&str; 15] = [
+    "AA", "AE", "AH", "AO", "AW", "AY", "EH", "ER", "EY", "IH", "IY", "OW", "OY", "UH", "UW",
+];
+const ARPABET_CONSONANTS: [&str; 24] = [
+    "B", "CH", "D", "DH", "F", "G", "HH", "JH", "K", "L", "M", "N", "NG", "P", "R", "S", "SH", "T",
+    "TH", "V", "W", "Y", "Z", "ZH",
+];
+
+// IPA symbols outside the alphabetic range: stress, length, syllable and tie marks
+const IPA_MARKS: [char; 8] = ['ˈ', 'ˌ', 'ː', 'ˑ', '.', '‿', '͡', '\''];
+
+/// Phoneme alphabet of a `<phoneme>` tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhonemeAlphabet {
+    /// CMU ARPAbet, e.g. `T AH0 M EY1 T OW2`
+    CmuArpabet,
+    /// International Phonetic Alphabet, e.g. `təˈmeɪtoʊ`
+    Ipa,
+}
+
+impl PhonemeAlphabet {
+    /// The value of the tag's `alphabet` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhonemeAlphabet::CmuArpabet => "cmu-arpabet",
+            PhonemeAlphabet::Ipa => "ipa",
+        }
+    }
+
+    /// Check that `phonemes` only uses symbols of this alphabet
+    pub fn validate(&self, phonemes: &str) -> Result<(), ElevenLabsTTSError> {
+        let phonemes = phonemes.trim();
+        let valid = !phonemes.is_empty()
+            && match self {
+                PhonemeAlphabet::CmuArpabet => phonemes.split_whitespace().all(is_arpabet_phone),
+                PhonemeAlphabet::Ipa => phonemes.chars().all(|c| {
+                    c.is_alphabetic()
+                        || c == ' '
+                        || IPA_MARKS.contains(&c)
+                        || ('\u{0300}'..='\u{036F}').contains(&c) // combining diacritics
+                        || ('\u{02B0}'..='\u{02FF}').contains(&c) // modifier letters
+                }),
+            };
+        if !valid {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Invalid {} phonemes {:?}",
+                self, phonemes
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PhonemeAlphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn is_arpabet_phone(phone: &str) -> bool {
+    if ARPABET_CONSONANTS.contains(&phone) {
+        return true;
+    }
+    let base = phone.strip_suffix(['0', '1', '2']).unwrap_or(phone);
+    ARPABET_VOWELS.contains(&base)
+}
+
+/// Wrap `word` in a `<phoneme>` tag after validating the phonemes.
+/// Phoneme tags are honoured by eleven_flash_v2, eleven_turbo_v2 and eleven_monolingual_v1.
+///
+/// ```rust
+/// use elevenlabs_tts::pronunciation::{PhonemeAlphabet, phoneme};
+///
+/// let markup = phoneme("tomato", PhonemeAlphabet::CmuArpabet, "T AH0 M EY1 T OW2").unwrap();
+/// assert_eq!(markup, r#"<phoneme alphabet="cmu-arpabet" ph="T AH0 M EY1 T OW2">tomato</phoneme>"#);
+/// ```
+pub fn phoneme(
+    word: &str,
+    alphabet: PhonemeAlphabet,
+    phonemes: &str,
+) -> Result<String, ElevenLabsTTSError> {
+    alphabet.validate(phonemes)?;
+    validate_word(word)?;
+    Ok(format!(
+        r#"<phoneme alphabet="{}" ph="{}">{}</phoneme>"#,
+        alphabet,
+        phonemes.split_whitespace().collect::<Vec<_>>().join(" "),
+        word.trim()
+    ))
+}
+
+fn validate_word(word: &str) -> Result<(), ElevenLabsTTSError> {
+    let word = word.trim();
+    if word.is_empty() || !word.chars().all(is_word_char) {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "Pronunciation overrides apply to single words, got {:?}",
+            word
+        )));
+    }
+    Ok(())
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '\''
+}
+
+#[derive(Debug, Clone)]
+enum Rule {
+    Phoneme(String),
+    Alias(String),
+}
+
+/// Per-word pronunciation overrides applied to request text
+///
+/// Phoneme rules wrap each occurrence of the word in a `<phoneme>` tag; alias rules
+/// replace the word with text that is read instead (e.g. `UN` → `United Nations`).
+/// Words are matched whole and case-sensitively.
+///
+/// ```rust
+/// use elevenlabs_tts::pronunciation::{PhonemeAlphabet, PronunciationRules};
+///
+/// let rules = PronunciationRules::new()
+///     .phoneme("Nguyen", PhonemeAlphabet::Ipa, "ŋwiən")?
+///     .alias("UN", "United Nations")?;
+/// assert_eq!(
+///     rules.apply("Nguyen spoke at the UN."),
+///     r#"<phoneme alphabet="ipa" ph="ŋwiən">Nguyen</phoneme> spoke at the United Nations."#
+/// );
+/// # Ok::<(), elevenlabs_tts::ElevenLabsTTSError>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PronunciationRules {
+    rules: HashMap<String, Rule>,
+}
+
+impl PronunciationRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pronounce `word` with the given phonemes
+    pub fn phoneme(
+        mut self,
+        word: &str,
+        alphabet: PhonemeAlphabet,
+        phonemes: &str,
+    ) -> Result<Self, ElevenLabsTTSError> {
+        let markup = phoneme(word, alphabet, phonemes)?;
+        self.rules
+            .insert(word.trim().to_string(), Rule::Phoneme(markup));
+        Ok(self)
+    }
+
+    /// Read `alias` wherever `word` appears
+    pub fn alias(mut self, word: &str, alias: &str) -> Result<Self, ElevenLabsTTSError> {
+        validate_word(word)?;
+        if alias.trim().is_empty() || alias.contains(['<', '>']) {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Invalid alias {:?} for {:?}",
+                alias, word
+            )));
+        }
+        self.rules.insert(
+            word.trim().to_string(),
+            Rule::Alias(alias.trim().to_string()),
+        );
+        Ok(self)
+    }
+
+    /// Apply the overrides to `text` in a single pass, so replacements are never rewritten
+    pub fn apply(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut word_start = None;
+        for (index, c) in text.char_indices().chain([(text.len(), ' ')]) {
+            match (word_start, is_word_char(c) && index < text.len()) {
+                (None, true) => word_start = Some(index),
+                (Some(start), false) => {
+                    self.push_word(&mut output, &text[start..index]);
+                    word_start = None;
+                    if index < text.len() {
+                        output.push(c);
+                    }
+                }
+                (None, false) if index < text.len() => output.push(c),
+                _ => {}
+            }
+        }
+        output
+    }
+
+    fn push_word(&self, output: &mut String, word: &str) {
+        match self.rules.get(word) {
+            Some(Rule::Phoneme(markup)) => output.push_str(markup),
+            Some(Rule::Alias(alias)) => output.push_str(alias),
+            None => output.push_str(word),
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! A shared, prioritised queue of text-to-speech jobs
+//!
+//! Interactive requests and background batches that share one API key compete for the
+//! same concurrency. A [`TtsQueue`] runs a bounded number of jobs at a time and always
+//! starts the highest-priority waiting job next, so a user-facing request jumps ahead of
+//! the rest of an audiobook instead of waiting behind it.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use tokio::sync::{Semaphore, broadcast, oneshot};
+
+use crate::{
+    AudioResponse, ElevenLabsTTSError, RetryEvent, RetryPolicy, TextToSpeechBuilder, retry,
+};
+
+/// Number of events kept for subscribers that fall behind
+const EVENT_CAPACITY: usize = 256;
+
+/// How urgently a queued job should run. Higher priorities start first; jobs of the same
+/// priority start in the order they were queued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Priority {
+    /// Bulk work such as audiobooks and pre-rendering
+    Background,
+    #[default]
+    Normal,
+    /// Someone is waiting for the audio
+    Interactive,
+}
+
+/// Progress of the jobs in a [`TtsQueue`], received through [`TtsQueue::subscribe`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueueEvent {
+    Queued {
+        job_id: u64,
+        priority: Priority,
+    },
+    Started {
+        job_id: u64,
+        attempt: u32,
+    },
+    /// The attempt failed and the job goes back in the queue after the retry delay
+    Retrying {
+        job_id: u64,
+        attempt: u32,
+        error: String,
+    },
+    Completed {
+        job_id: u64,
+    },
+    Failed {
+        job_id: u64,
+        error: String,
+    },
+    /// The job's handle was dropped before it started, so it was never sent
+    Cancelled {
+        job_id: u64,
+    },
+}
+
+/// Queue running text-to-speech jobs by priority with bounded concurrency
+///
+/// Cloning is cheap and clones share the same queue. Jobs run on the tokio runtime
+/// they were queued from.
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::queue::{Priority, TtsQueue};
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let queue = TtsQueue::new(4);
+/// // Keep the handles: dropping one before its job starts cancels the job
+/// let paragraphs: Vec<_> = ["First paragraph.", "Second paragraph."]
+///     .into_iter()
+///     .map(|paragraph| queue.enqueue(client.text_to_speech(paragraph), Priority::Background))
+///     .collect();
+///
+/// // Starts before the waiting background jobs
+/// let reply = queue
+///     .enqueue(client.text_to_speech("Sure, here you go!"), Priority::Interactive)
+///     .await?;
+/// for paragraph in paragraphs {
+///     let audio = paragraph.await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TtsQueue {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    waiting: Mutex<BinaryHeap<QueuedJob>>,
+    slots: Arc<Semaphore>,
+    retry_policy: RetryPolicy,
+    events: broadcast::Sender<QueueEvent>,
+    next_id: AtomicU64,
+}
+
+struct QueuedJob {
+    id: u64,
+    priority: Priority,
+    // Position in the queue, so equal priorities run first-in first-out
+    sequence: u64,
+    attempt: u32,
+    builder: TextToSpeechBuilder,
+    done: oneshot::Sender<Result<AudioResponse, ElevenLabsTTSError>>,
+}
+
+impl PartialEq for QueuedJob {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedJob {}
+
+impl PartialOrd for QueuedJob {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedJob {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl TtsQueue {
+    /// Create a queue running at most `concurrency` jobs at the same time
+    pub fn new(concurrency: usize) -> Self {
+        Self::with_retry_policy(concurrency, RetryPolicy::none())
+    }
+
+    /// Create a queue that retries failed jobs according to `retry_policy`. A job waiting
+    /// to be retried frees its slot and competes by priority again.
+    pub fn with_retry_policy(concurrency: usize, retry_policy: RetryPolicy) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                waiting: Mutex::new(BinaryHeap::new()),
+                slots: Arc::new(Semaphore::new(concurrency.max(1))),
+                retry_policy,
+                events: broadcast::channel(EVENT_CAPACITY).0,
+                next_id: AtomicU64::new(1),
+            }),
+        }
+    }
+
+    /// Queue a request. Await the returned handle for its audio; dropping the handle
+    /// before the job starts cancels it.
+    pub fn enqueue(&self, builder: TextToSpeechBuilder, priority: Priority) -> JobHandle {
+        let id = self.shared.next_id.fetch_add(1, AtomicOrdering::Relaxed);
+        let (done, receiver) = oneshot::channel();
+        self.shared.emit(QueueEvent::Queued {
+            job_id: id,
+            priority,
+        });
+        Shared::push(
+            &self.shared,
+            QueuedJob {
+                id,
+                priority,
+                sequence: id,
+                attempt: 1,
+                builder,
+                done,
+            },
+        );
+        JobHandle { id, receiver }
+    }
+
+    /// Receive an event for every job queued, started, retried, completed or failed from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<QueueEvent> {
+        self.shared.events.subscribe()
+    }
+
+    /// Number of jobs waiting for a free slot
+    pub fn waiting(&self) -> usize {
+        self.shared.waiting.lock().unwrap().len()
+    }
+}
+
+impl Shared {
+    fn emit(&self, event: QueueEvent) {
+        // Nobody listening is fine
+        let _ = self.events.send(event);
+    }
+
+    /// Add a job and start a worker for it. The worker runs whichever job has the
+    /// highest priority once a slot is free, not necessarily this one.
+    fn push(shared: &Arc<Self>, job: QueuedJob) {
+        shared.waiting.lock().unwrap().push(job);
+        let shared = shared.clone();
+        tokio::spawn(async move {
+            // The semaphore is never closed, so acquiring can't fail
+            let Ok(_slot) = shared.slots.clone().acquire_owned().await else {
+                return;
+            };
+            let Some(job) = shared.waiting.lock().unwrap().pop() else {
+                return;
+            };
+            shared.run(job).await;
+        });
+    }
+
+    async fn run(self: &Arc<Self>, job: QueuedJob) {
+        if job.done.is_closed() {
+            self.emit(QueueEvent::Cancelled { job_id: job.id });
+            return;
+        }
+        self.emit(QueueEvent::Started {
+            job_id: job.id,
+            attempt: job.attempt,
+        });
+
+        let policy = &self.retry_policy;
+        match job.builder.clone().execute_detailed().await {
+            Err(error) if job.attempt < policy.max_attempts && policy.should_retry(&error) => {
+                let delay = policy.delay_after(job.attempt, &error);
+                policy.notify(RetryEvent {
+                    endpoint: "text-to-speech",
+                    attempt: job.attempt,
+                    delay,
+                    status: error.status_code(),
+                    retry_after: retry::retry_after(&error),
+                });
+                self.emit(QueueEvent::Retrying {
+                    job_id: job.id,
+                    attempt: job.attempt,
+                    error: error.to_string(),
+                });
+                let shared = self.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    let job = QueuedJob {
+                        attempt: job.attempt + 1,
+                        ..job
+                    };
+                    Shared::push(&shared, job);
+                });
+            }
+            result => {
+                self.emit(match &result {
+                    Ok(_) => QueueEvent::Completed { job_id: job.id },
+                    Err(error) => QueueEvent::Failed {
+                        job_id: job.id,
+                        error: error.to_string(),
+                    },
+                });
+                let _ = job.done.send(result);
+            }
+        }
+    }
+}
+
+/// A queued job: await it for the generated audio
+pub struct JobHandle {
+    id: u64,
+    receiver: oneshot::Receiver<Result<AudioResponse, ElevenLabsTTSError>>,
+}
+
+impl JobHandle {
+    /// ID of the job in [`QueueEvent`]s
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Future for JobHandle {
+    type Output = Result<AudioResponse, ElevenLabsTTSError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver).poll(cx).map(|result| {
+            // The worker only goes away without answering if the runtime shuts down
+            result.unwrap_or_else(|_| {
+                Err(ElevenLabsTTSError::ValidationError(
+                    "The queue stopped before the job finished".to_string(),
+                ))
+            })
+        })
+    }
+}
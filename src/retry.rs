@@ -0,0 +1,331 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{ElevenLabsTTSError, error};
+
+/// Retry policy for transient failures (rate limits, server errors, dropped connections)
+///
+/// Retries are disabled by default. Enable them on the client builder:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use elevenlabs_tts::{ElevenLabsTTSClient, RetryPolicy};
+///
+/// # fn main() -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let client = ElevenLabsTTSClient::builder()
+///     .api_key("your-api-key")
+///     .retry_policy(RetryPolicy::new(4).base_delay(Duration::from_millis(250)))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one
+    pub max_attempts: u32,
+
+    /// Delay before the first retry, doubled on every following retry
+    pub base_delay: Duration,
+
+    /// Upper bound for a single delay, including waits requested through `Retry-After`
+    pub max_delay: Duration,
+
+    /// Randomize each delay between half and the full computed value
+    pub jitter: bool,
+
+    /// HTTP status codes that should be retried. Only 429 and 503 are retried unless
+    /// `retry_undetermined` is set, so the default 500, 502 and 504 apply only then.
+    pub retry_on_status: Vec<u16>,
+
+    /// Retry when the connection can't be established
+    /// (and, with `retry_undetermined`, when it resets or times out)
+    pub retry_on_connection_errors: bool,
+
+    /// Also retry failures where the API may already have generated (and billed) the audio,
+    /// such as timeouts after the request was sent or 500/502/504 responses.
+    /// Disabled by default so retries never double-bill characters.
+    pub retry_undetermined: bool,
+
+    /// Called before every retry with the upcoming wait
+    pub on_retry: Option<RetryCallback>,
+}
+
+/// Callback told about every retry, see [`RetryPolicy::on_retry`]
+pub type RetryCallback = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// A retry that is about to happen
+#[derive(Debug, Clone)]
+pub struct RetryEvent {
+    /// The endpoint being retried, e.g. `text-to-speech`
+    pub endpoint: &'static str,
+
+    /// The attempt that failed (1-based)
+    pub attempt: u32,
+
+    /// How long the client waits before the next attempt
+    pub delay: Duration,
+
+    /// HTTP status of the failed attempt, `None` for connection errors
+    pub status: Option<u16>,
+
+    /// Wait requested by the server through `Retry-After`, before capping
+    pub retry_after: Option<Duration>,
+}
+
+impl RetryEvent {
+    /// Whether the attempt was rate limited (HTTP 429)
+    pub fn is_throttled(&self) -> bool {
+        self.status == Some(429)
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("retry_on_status", &self.retry_on_status)
+            .field(
+                "retry_on_connection_errors",
+                &self.retry_on_connection_errors,
+            )
+            .field("retry_undetermined", &self.retry_undetermined)
+            .field("on_retry", &self.on_retry.is_some())
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on_status: vec![429, 500, 502, 503, 504],
+            retry_on_connection_errors: true,
+            retry_undetermined: false,
+            on_retry: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with the default schedule and the given number of attempts
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Set the delay before the first retry
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound for a single delay
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enable or disable delay jitter
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Set the HTTP status codes that should be retried; statuses other than 429 and 503
+    /// need [`retry_undetermined`](Self::retry_undetermined)
+    pub fn retry_on_status<I: IntoIterator<Item = u16>>(mut self, statuses: I) -> Self {
+        self.retry_on_status = statuses.into_iter().collect();
+        self
+    }
+
+    /// Enable or disable retrying connection failures
+    pub fn retry_on_connection_errors(mut self, enabled: bool) -> Self {
+        self.retry_on_connection_errors = enabled;
+        self
+    }
+
+    /// Allow retrying failures that may already have been billed
+    pub fn retry_undetermined(mut self, enabled: bool) -> Self {
+        self.retry_undetermined = enabled;
+        self
+    }
+
+    /// Call `callback` before every retry, e.g. to show "throttled, retrying in 12s"
+    ///
+    /// ```rust
+    /// use elevenlabs_tts::RetryPolicy;
+    ///
+    /// let policy = RetryPolicy::new(4).on_retry(|event| {
+    ///     if event.is_throttled() {
+    ///         println!("Throttled, retrying in {}s", event.delay.as_secs());
+    ///     }
+    /// });
+    /// ```
+    pub fn on_retry<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RetryEvent) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(callback));
+        self
+    }
+
+    /// Delay to wait after the given failed attempt (1-based)
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+
+    pub(crate) fn should_retry_status(&self, status: u16) -> bool {
+        self.retry_on_status.contains(&status)
+            && (self.retry_undetermined || error::status_is_safe_to_retry(status))
+    }
+
+    pub(crate) fn should_retry_error(&self, error: &reqwest::Error) -> bool {
+        self.retry_on_connection_errors
+            && if self.retry_undetermined {
+                error::request_error_is_transient(error)
+            } else {
+                error::request_error_never_sent(error)
+            }
+    }
+
+    /// Whether a request that failed with this error is worth sending again as a whole
+    pub(crate) fn should_retry(&self, error: &ElevenLabsTTSError) -> bool {
+        match error {
+            ElevenLabsTTSError::RequestError(error) => self.should_retry_error(error),
+            ElevenLabsTTSError::ApiError { status, .. } => self.should_retry_status(*status),
+            ElevenLabsTTSError::RateLimitError { .. } => self.should_retry_status(429),
+            ElevenLabsTTSError::CircuitOpenError { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Delay before the next attempt: exactly the server's `Retry-After` when it sent one
+    /// (capped by `max_delay`), the exponential schedule otherwise
+    pub(crate) fn retry_delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        match retry_after {
+            Some(wait) => wait.min(self.max_delay),
+            None => self.delay_for(attempt),
+        }
+    }
+
+    /// Delay before retrying after `error`, honouring the server's `Retry-After`
+    pub(crate) fn delay_after(&self, attempt: u32, error: &ElevenLabsTTSError) -> Duration {
+        self.retry_delay(attempt, retry_after(error))
+    }
+
+    /// Tell the `on_retry` callback about an upcoming retry
+    pub(crate) fn notify(&self, event: RetryEvent) {
+        if let Some(callback) = &self.on_retry {
+            callback(&event);
+        }
+    }
+}
+
+/// The wait an error asks for before trying again
+pub(crate) fn retry_after(error: &ElevenLabsTTSError) -> Option<Duration> {
+    match error {
+        ElevenLabsTTSError::RateLimitError {
+            retry_after: Some(seconds),
+            ..
+        }
+        | ElevenLabsTTSError::CircuitOpenError {
+            retry_after: seconds,
+        } => Some(Duration::from_secs(*seconds)),
+        _ => None,
+    }
+}
+
+/// Cheap pseudo-random value in [0, 1), good enough to spread retries apart
+fn random_fraction() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let mut x = nanos as u64 ^ 0x9E37_79B9_7F4A_7C15;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    (x % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exponential_delay_is_capped() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(300))
+            .jitter(false);
+
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_undetermined_statuses_need_opt_in() {
+        let policy = RetryPolicy::default();
+        assert!(policy.should_retry_status(429));
+        assert!(policy.should_retry_status(503));
+        assert!(!policy.should_retry_status(500));
+        assert!(!policy.should_retry_status(504));
+
+        let policy = policy.retry_undetermined(true);
+        assert!(policy.should_retry_status(500));
+        assert!(!policy.should_retry_status(400));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::new(3).base_delay(Duration::from_millis(100));
+        let delay = policy.delay_for(1);
+        assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_rate_limit_retry_honours_retry_after() {
+        let policy = RetryPolicy::new(3)
+            .base_delay(Duration::from_millis(100))
+            .jitter(false);
+        let error = ElevenLabsTTSError::RateLimitError {
+            retry_after: Some(2),
+            message: "slow down".to_string(),
+            request_id: None,
+        };
+
+        assert!(policy.should_retry(&error));
+        assert_eq!(policy.delay_after(1, &error), Duration::from_secs(2));
+        // The server's wait replaces the schedule, within max_delay
+        assert_eq!(policy.delay_after(5, &error), Duration::from_secs(2));
+        let capped = policy.clone().max_delay(Duration::from_secs(1));
+        assert_eq!(capped.delay_after(1, &error), Duration::from_secs(1));
+        assert!(!policy.should_retry(&ElevenLabsTTSError::ValidationError(String::new())));
+    }
+}
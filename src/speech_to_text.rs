@@ -0,0 +1,283 @@
+//! Speech-to-text transcription
+//!
+//! Short clips can be transcribed with [`SpeechToTextBuilder::execute`], which holds the
+//! connection open until the transcript is ready. For long recordings,
+//! [`SpeechToTextBuilder::submit_webhook`] returns as soon as the upload is accepted and
+//! the transcript is delivered later to the webhooks configured on the account, where
+//! [`webhooks::WebhookVerifier`](crate::webhooks::WebhookVerifier) checks and parses it.
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::{ElevenLabsTTSClient, ElevenLabsTTSError, RequestOptions, multipart};
+
+/// Speech-to-text model used unless another is set
+pub const DEFAULT_SPEECH_TO_TEXT_MODEL: &str = "scribe_v1";
+
+/// Most speakers diarization can tell apart
+pub const MAX_SPEAKERS: u32 = 32;
+
+/// Largest `webhook_metadata` the API accepts once serialized, in bytes
+pub const MAX_WEBHOOK_METADATA_LENGTH: usize = 16 * 1024;
+
+/// A transcript returned by speech-to-text
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Transcription {
+    /// Detected (or requested) language, e.g. `eng`
+    #[serde(default)]
+    pub language_code: Option<String>,
+
+    /// Confidence of the language detection, from 0 to 1
+    #[serde(default)]
+    pub language_probability: Option<f64>,
+
+    pub text: String,
+
+    /// Words, spacing and audio events, in order
+    #[serde(default)]
+    pub words: Vec<TranscriptionWord>,
+}
+
+/// A word, space or audio event (e.g. `(laughter)`) in a [`Transcription`]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TranscriptionWord {
+    pub text: String,
+
+    /// `word`, `spacing` or `audio_event`
+    #[serde(rename = "type", default)]
+    pub kind: Option<String>,
+
+    /// Start time in seconds
+    #[serde(default)]
+    pub start: Option<f64>,
+
+    /// End time in seconds
+    #[serde(default)]
+    pub end: Option<f64>,
+
+    /// Speaker label when diarization is enabled, e.g. `speaker_0`
+    #[serde(default)]
+    pub speaker_id: Option<String>,
+}
+
+/// Reply to a job submitted with [`SpeechToTextBuilder::submit_webhook`]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SpeechToTextJob {
+    /// Matches [`SpeechToTextCompleted::request_id`](crate::webhooks::SpeechToTextCompleted)
+    /// in the webhook delivering the transcript
+    pub request_id: String,
+
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Builder for speech-to-text requests
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::webhooks::{WebhookPayload, WebhookVerifier};
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// // Submit a long recording and return right away
+/// let job = client
+///     .speech_to_text(std::fs::read("meeting.mp3")?)
+///     .file_name("meeting.mp3")
+///     .diarize(true)
+///     .webhook_metadata(serde_json::json!({ "meeting_id": 42 }))
+///     .submit_webhook()
+///     .await?;
+/// println!("Transcribing as {}", job.request_id);
+///
+/// // Later, in the webhook handler
+/// # let (body, signature): (Vec<u8>, String) = (Vec::new(), String::new());
+/// let event = WebhookVerifier::new("wsec_...").construct_event(&body, &signature)?;
+/// if let WebhookPayload::SpeechToTextTranscription(completed) = event.payload()? {
+///     println!("{}: {}", completed.request_id, completed.transcription.text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct SpeechToTextBuilder {
+    client: ElevenLabsTTSClient,
+    audio: Vec<u8>,
+    file_name: Option<String>,
+    model_id: Option<String>,
+    language_code: Option<String>,
+    diarize: Option<bool>,
+    num_speakers: Option<u32>,
+    tag_audio_events: Option<bool>,
+    webhook_id: Option<String>,
+    webhook_metadata: Option<serde_json::Value>,
+    options: RequestOptions,
+}
+
+impl SpeechToTextBuilder {
+    pub(crate) fn new(client: ElevenLabsTTSClient, audio: Vec<u8>) -> Self {
+        Self {
+            client,
+            audio,
+            file_name: None,
+            model_id: None,
+            language_code: None,
+            diarize: None,
+            num_speakers: None,
+            tag_audio_events: None,
+            webhook_id: None,
+            webhook_metadata: None,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Name of the uploaded file, e.g. `interview.mp3`. Defaults to `audio`.
+    pub fn file_name<S: Into<String>>(mut self, file_name: S) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Set the model to use. Defaults to: scribe_v1
+    pub fn model_id<S: Into<String>>(mut self, model_id: S) -> Self {
+        self.model_id = Some(model_id.into());
+        self
+    }
+
+    /// Language of the recording (ISO 639-1 or 639-3), instead of detecting it
+    pub fn language_code<S: Into<String>>(mut self, language_code: S) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+
+    /// Label which speaker said each word
+    pub fn diarize(mut self, diarize: bool) -> Self {
+        self.diarize = Some(diarize);
+        self
+    }
+
+    /// Most speakers to expect when diarizing (1 - 32)
+    pub fn num_speakers(mut self, num_speakers: u32) -> Self {
+        self.num_speakers = Some(num_speakers);
+        self
+    }
+
+    /// Transcribe sounds such as `(laughter)` or `(footsteps)`
+    pub fn tag_audio_events(mut self, tag_audio_events: bool) -> Self {
+        self.tag_audio_events = Some(tag_audio_events);
+        self
+    }
+
+    /// Deliver the result of [`submit_webhook`](Self::submit_webhook) to this webhook only,
+    /// instead of every speech-to-text webhook on the account
+    pub fn webhook_id<S: Into<String>>(mut self, webhook_id: S) -> Self {
+        self.webhook_id = Some(webhook_id.into());
+        self
+    }
+
+    /// A JSON object echoed back in the webhook, e.g. to find the record the recording
+    /// belongs to. Only sent with [`submit_webhook`](Self::submit_webhook).
+    pub fn webhook_metadata(mut self, webhook_metadata: serde_json::Value) -> Self {
+        self.webhook_metadata = Some(webhook_metadata);
+        self
+    }
+
+    /// Set a timeout for each HTTP attempt, overriding the client-level timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.options.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to this request (e.g. a correlation ID), overriding client defaults
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.options.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Upload the recording and wait for its transcript
+    pub async fn execute(self) -> Result<Transcription, ElevenLabsTTSError> {
+        if self.webhook_id.is_some() || self.webhook_metadata.is_some() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Webhook options need submit_webhook instead of execute".to_string(),
+            ));
+        }
+        let form = self.form()?;
+        self.send(form).await
+    }
+
+    /// Upload the recording and return once it is accepted. The transcript arrives later
+    /// as a `speech_to_text_transcription` webhook carrying the returned request ID.
+    pub async fn submit_webhook(self) -> Result<SpeechToTextJob, ElevenLabsTTSError> {
+        let mut form = self.form()?.text("webhook", "true");
+        if let Some(webhook_id) = &self.webhook_id {
+            form = form.text("webhook_id", webhook_id.as_str());
+        }
+        if let Some(metadata) = &self.webhook_metadata {
+            if !metadata.is_object() {
+                return Err(ElevenLabsTTSError::ValidationError(
+                    "Webhook metadata must be a JSON object".to_string(),
+                ));
+            }
+            let metadata = metadata.to_string();
+            if metadata.len() > MAX_WEBHOOK_METADATA_LENGTH {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Webhook metadata cannot exceed {} bytes, got {}",
+                    MAX_WEBHOOK_METADATA_LENGTH,
+                    metadata.len()
+                )));
+            }
+            form = form.text("webhook_metadata", metadata);
+        }
+        self.send(form).await
+    }
+
+    /// Internal method to validate the options and build the fields shared by both modes
+    fn form(&self) -> Result<multipart::Form, ElevenLabsTTSError> {
+        if self.audio.is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Speech-to-text audio cannot be empty".to_string(),
+            ));
+        }
+        if let Some(num_speakers) = self.num_speakers {
+            if !(1..=MAX_SPEAKERS).contains(&num_speakers) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Number of speakers must be between 1 and {}, got {}",
+                    MAX_SPEAKERS, num_speakers
+                )));
+            }
+        }
+
+        let mut form = multipart::Form::new()
+            .text(
+                "model_id",
+                self.model_id
+                    .as_deref()
+                    .unwrap_or(DEFAULT_SPEECH_TO_TEXT_MODEL),
+            )
+            .file(
+                "file",
+                self.file_name.as_deref().unwrap_or("audio"),
+                "application/octet-stream",
+                self.audio.clone(),
+            );
+        if let Some(language_code) = &self.language_code {
+            form = form.text("language_code", language_code.as_str());
+        }
+        if let Some(diarize) = self.diarize {
+            form = form.text("diarize", diarize.to_string());
+        }
+        if let Some(num_speakers) = self.num_speakers {
+            form = form.text("num_speakers", num_speakers.to_string());
+        }
+        if let Some(tag_audio_events) = self.tag_audio_events {
+            form = form.text("tag_audio_events", tag_audio_events.to_string());
+        }
+        Ok(form)
+    }
+
+    async fn send<T: serde::de::DeserializeOwned>(
+        &self,
+        form: multipart::Form,
+    ) -> Result<T, ElevenLabsTTSError> {
+        let url = format!("{}/speech-to-text", self.client.inner.base_url);
+        self.client
+            .post_multipart("speech-to-text", &url, form, &self.options)
+            .await
+    }
+}
@@ -0,0 +1,241 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, ready};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures_util::{Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWrite, AsyncWriteExt, ReadBuf, SeekFrom};
+use tokio::sync::{OwnedSemaphorePermit, mpsc};
+
+use crate::{ElevenLabsTTSError, MetricsObserver, OutputFormat, audio};
+
+/// A stream of audio chunks as they arrive from the API
+pub type AudioStream = Pin<Box<dyn Stream<Item = Result<Bytes, ElevenLabsTTSError>> + Send>>;
+
+/// Streamed audio as a [`tokio::io::AsyncRead`], for code that expects a reader
+/// (`tokio::io::copy`, a child process' stdin...)
+///
+/// A failure midway surfaces as an `io::Error` wrapping the [`ElevenLabsTTSError`],
+/// which can be recovered with `error.get_ref()` and `downcast_ref`.
+pub struct AudioReader {
+    stream: AudioStream,
+    chunk: Bytes,
+}
+
+impl AudioReader {
+    pub fn new(stream: AudioStream) -> Self {
+        Self {
+            stream,
+            chunk: Bytes::new(),
+        }
+    }
+}
+
+impl AsyncRead for AudioReader {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        loop {
+            if !self.chunk.is_empty() {
+                let len = self.chunk.len().min(buf.remaining());
+                buf.put_slice(&self.chunk.split_to(len));
+                return Poll::Ready(Ok(()));
+            }
+            match ready!(self.stream.as_mut().poll_next(cx)) {
+                Some(Ok(chunk)) => self.chunk = chunk,
+                Some(Err(error)) => return Poll::Ready(Err(std::io::Error::other(error))),
+                None => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+/// Callback told how many bytes of audio have arrived and how long the request has taken
+#[derive(Clone)]
+pub(crate) struct Progress(Arc<dyn Fn(u64, Duration) + Send + Sync>);
+
+impl Progress {
+    pub(crate) fn new<F>(callback: F) -> Self
+    where
+        F: Fn(u64, Duration) + Send + Sync + 'static,
+    {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn report(&self, bytes_received: u64, started: Instant) {
+        (self.0)(bytes_received, started.elapsed())
+    }
+}
+
+impl std::fmt::Debug for Progress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Progress")
+    }
+}
+
+/// Read a whole response body, reporting progress after every chunk
+pub(crate) async fn read_with_progress(
+    mut response: reqwest::Response,
+    progress: &Progress,
+    started: Instant,
+) -> Result<Bytes, ElevenLabsTTSError> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        progress.report(body.len() as u64, started);
+    }
+    Ok(body.into())
+}
+
+/// Report progress as the stream's chunks are consumed
+pub(crate) fn with_progress(
+    stream: AudioStream,
+    progress: Progress,
+    started: Instant,
+) -> AudioStream {
+    let mut received = 0;
+    Box::pin(stream.inspect(move |chunk| {
+        if let Ok(chunk) = chunk {
+            received += chunk.len() as u64;
+            progress.report(received, started);
+        }
+    }))
+}
+
+/// Turn a successful response into an audio chunk stream, holding the
+/// concurrency permit (if any) until the stream is dropped
+pub(crate) fn from_response(
+    response: reqwest::Response,
+    permit: Option<OwnedSemaphorePermit>,
+    metrics: Option<(&'static str, Arc<dyn MetricsObserver>)>,
+) -> AudioStream {
+    Box::pin(response.bytes_stream().map(move |chunk| {
+        let _permit = &permit;
+        if let (Ok(bytes), Some((endpoint, metrics))) = (&chunk, &metrics) {
+            metrics.bytes_received(endpoint, bytes.len());
+        }
+        chunk.map_err(ElevenLabsTTSError::from)
+    }))
+}
+
+/// Write every chunk of the stream to the writer as it arrives, returning the number of bytes written
+pub(crate) async fn write_to<W>(
+    mut stream: AudioStream,
+    writer: &mut W,
+) -> Result<u64, ElevenLabsTTSError>
+where
+    W: AsyncWrite + Unpin + ?Sized,
+{
+    let mut written = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+
+/// Forward every chunk into a channel as it arrives, waiting while the channel is full.
+/// Fails with `BrokenPipe` if the receiver is dropped before the stream ends.
+pub(crate) async fn send_to(
+    mut stream: AudioStream,
+    sender: &mpsc::Sender<Bytes>,
+) -> Result<u64, ElevenLabsTTSError> {
+    let mut sent = 0;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        let len = chunk.len() as u64;
+        if sender.send(chunk).await.is_err() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::BrokenPipe,
+                "audio channel receiver was dropped",
+            )
+            .into());
+        }
+        sent += len;
+    }
+    Ok(sent)
+}
+
+/// Stream into a newly created file, removing the partial file if the stream fails
+pub(crate) async fn write_to_file(
+    stream: AudioStream,
+    path: &Path,
+) -> Result<u64, ElevenLabsTTSError> {
+    let mut file = tokio::fs::File::create(path).await?;
+    let result = write_to(stream, &mut file).await;
+    if result.is_err() {
+        drop(file);
+        let _ = tokio::fs::remove_file(path).await;
+    }
+    result
+}
+
+/// Append the format's extension to `base` unless it already ends with it
+pub(crate) fn path_with_extension(base: &Path, format: OutputFormat) -> PathBuf {
+    let extension = format.file_extension();
+    if base
+        .extension()
+        .is_some_and(|existing| existing.eq_ignore_ascii_case(extension))
+    {
+        return base.to_path_buf();
+    }
+    let mut path = OsString::from(base.as_os_str());
+    path.push(".");
+    path.push(extension);
+    PathBuf::from(path)
+}
+
+/// Stream into `base` plus the format's extension, creating parent directories.
+/// PCM is written as a 16-bit mono WAV file whose sizes are filled in once the stream ends.
+pub(crate) async fn write_to_path(
+    stream: AudioStream,
+    base: &Path,
+    format: OutputFormat,
+) -> Result<PathBuf, ElevenLabsTTSError> {
+    let path = path_with_extension(base, format);
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    if format.codec() != "pcm" {
+        write_to_file(stream, &path).await?;
+        return Ok(path);
+    }
+
+    let mut file = tokio::fs::File::create(&path).await?;
+    let result = write_wav(stream, &mut file, format.sample_rate()).await;
+    if let Err(err) = result {
+        drop(file);
+        let _ = tokio::fs::remove_file(&path).await;
+        return Err(err);
+    }
+    Ok(path)
+}
+
+async fn write_wav(
+    stream: AudioStream,
+    file: &mut tokio::fs::File,
+    sample_rate: u32,
+) -> Result<(), ElevenLabsTTSError> {
+    file.write_all(&audio::wav_header(sample_rate, 1, 0)?)
+        .await?;
+    let data_len = write_to(stream, file).await?;
+    let data_len = u32::try_from(data_len).map_err(|_| {
+        ElevenLabsTTSError::ValidationError("PCM audio too large for a WAV file".to_string())
+    })?;
+    file.seek(SeekFrom::Start(0)).await?;
+    file.write_all(&audio::wav_header(sample_rate, 1, data_len)?)
+        .await?;
+    file.flush().await?;
+    Ok(())
+}
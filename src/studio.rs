@@ -0,0 +1,659 @@
+//! Studio (formerly Projects): long-form audio assembled from chapters
+//!
+//! A Studio project holds a document split into chapters, each narrated with the
+//! project's voices. Converting a project or chapter renders its audio and records it as
+//! a snapshot, which stays downloadable after later edits and conversions.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::ModelId;
+use crate::{AudioStream, ElevenLabsTTSClient, ElevenLabsTTSError, RequestOptions, ids, multipart};
+
+/// A Studio project
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StudioProject {
+    pub project_id: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub state: ProjectState,
+
+    #[serde(default)]
+    pub default_title_voice_id: Option<String>,
+
+    #[serde(default)]
+    pub default_paragraph_voice_id: Option<String>,
+
+    #[serde(default)]
+    pub default_model_id: Option<ModelId>,
+
+    /// Title written into the metadata of downloaded audio
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Author written into the metadata of downloaded audio
+    #[serde(default)]
+    pub author: Option<String>,
+
+    /// Whether a converted version exists to download
+    #[serde(default)]
+    pub can_be_downloaded: bool,
+
+    /// When the project was created (Unix timestamp, seconds)
+    #[serde(default)]
+    pub create_date_unix: Option<i64>,
+
+    /// When the project was last converted (Unix timestamp, seconds)
+    #[serde(default)]
+    pub last_conversion_date_unix: Option<i64>,
+}
+
+/// Processing state of a Studio project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectState {
+    /// The project is being created from its source document or URL
+    Creating,
+    /// Idle, ready to edit or convert
+    Default,
+    Converting,
+    /// Waiting for a conversion slot
+    InQueue,
+    /// A state this version of the crate does not know about
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// A chapter of a Studio project
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StudioChapter {
+    pub chapter_id: String,
+    pub name: String,
+
+    #[serde(default)]
+    pub state: ChapterState,
+
+    /// Progress of the running conversion, from 0 to 1
+    #[serde(default)]
+    pub conversion_progress: Option<f64>,
+
+    /// Whether a converted version exists to download
+    #[serde(default)]
+    pub can_be_downloaded: bool,
+
+    /// When the chapter was last converted (Unix timestamp, seconds)
+    #[serde(default)]
+    pub last_conversion_date_unix: Option<i64>,
+}
+
+/// Processing state of a Studio chapter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChapterState {
+    /// Idle, ready to edit or convert
+    Default,
+    Converting,
+    /// A state this version of the crate does not know about
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// A converted version of a whole project
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ProjectSnapshot {
+    pub project_snapshot_id: String,
+    pub project_id: String,
+
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// When the snapshot was made (Unix timestamp, seconds)
+    #[serde(default)]
+    pub created_at_unix: Option<i64>,
+}
+
+/// A converted version of one chapter
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChapterSnapshot {
+    pub chapter_snapshot_id: String,
+    pub project_id: String,
+    pub chapter_id: String,
+
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// When the snapshot was made (Unix timestamp, seconds)
+    #[serde(default)]
+    pub created_at_unix: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct ProjectResponse {
+    project: StudioProject,
+}
+
+#[derive(Deserialize)]
+struct ProjectsResponse {
+    projects: Vec<StudioProject>,
+}
+
+#[derive(Deserialize)]
+struct ChapterResponse {
+    chapter: StudioChapter,
+}
+
+#[derive(Deserialize)]
+struct ChaptersResponse {
+    chapters: Vec<StudioChapter>,
+}
+
+#[derive(Deserialize)]
+struct ProjectSnapshotsResponse {
+    snapshots: Vec<ProjectSnapshot>,
+}
+
+#[derive(Deserialize)]
+struct ChapterSnapshotsResponse {
+    snapshots: Vec<ChapterSnapshot>,
+}
+
+/// Request body for adding a chapter
+#[derive(Serialize)]
+struct AddChapterRequest<'a> {
+    name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from_url: Option<&'a str>,
+}
+
+/// Request body for downloading a snapshot's audio
+#[derive(Serialize)]
+struct SnapshotAudioRequest {
+    convert_to_mpeg: bool,
+}
+
+/// Studio endpoints
+///
+/// ```rust,no_run
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let studio = client.studio();
+/// let project = studio
+///     .create_project("The Rust Book")
+///     .voice("21m00Tcm4TlvDq8ikWAM")
+///     .model("eleven_multilingual_v2")
+///     .from_url("https://doc.rust-lang.org/book/ch01-00-getting-started.html")
+///     .execute()
+///     .await?;
+/// studio
+///     .add_chapter_from_url(&project.project_id, "Chapter 2", "https://doc.rust-lang.org/book/ch02-00-guessing-game-tutorial.html")
+///     .await?;
+/// studio.convert_project(&project.project_id).await?;
+///
+/// // Once the conversion has finished
+/// let snapshots = studio.project_snapshots(&project.project_id).await?;
+/// let audio = studio
+///     .download_project_snapshot(&project.project_id, &snapshots[0].project_snapshot_id)
+///     .await?;
+/// std::fs::write("rust-book.mp3", audio)?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Studio {
+    client: ElevenLabsTTSClient,
+}
+
+impl Studio {
+    pub(crate) fn new(client: ElevenLabsTTSClient) -> Self {
+        Self { client }
+    }
+
+    /// Start creating a project
+    pub fn create_project<S: Into<String>>(&self, name: S) -> CreateProjectBuilder {
+        CreateProjectBuilder::new(self.client.clone(), name.into())
+    }
+
+    /// List the account's projects
+    pub async fn projects(&self) -> Result<Vec<StudioProject>, ElevenLabsTTSError> {
+        let url = self.url("");
+        let response: ProjectsResponse = self.client.get_json("studio-projects", &url, &[]).await?;
+        Ok(response.projects)
+    }
+
+    /// Get a project, e.g. to check whether it has finished converting
+    pub async fn project(&self, project_id: &str) -> Result<StudioProject, ElevenLabsTTSError> {
+        let url = self.url(&format!("/{}", ids::check_path_id("Project", project_id)?));
+        self.client.get_json("studio-project", &url, &[]).await
+    }
+
+    /// List a project's chapters
+    pub async fn chapters(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<StudioChapter>, ElevenLabsTTSError> {
+        let url = self.url(&format!(
+            "/{}/chapters",
+            ids::check_path_id("Project", project_id)?
+        ));
+        let response: ChaptersResponse = self.client.get_json("studio-chapters", &url, &[]).await?;
+        Ok(response.chapters)
+    }
+
+    /// Add an empty chapter to a project
+    pub async fn add_chapter(
+        &self,
+        project_id: &str,
+        name: &str,
+    ) -> Result<StudioChapter, ElevenLabsTTSError> {
+        self.post_chapter(project_id, name, None).await
+    }
+
+    /// Add a chapter with the text of a web page
+    pub async fn add_chapter_from_url(
+        &self,
+        project_id: &str,
+        name: &str,
+        from_url: &str,
+    ) -> Result<StudioChapter, ElevenLabsTTSError> {
+        self.post_chapter(project_id, name, Some(from_url)).await
+    }
+
+    /// Start converting every chapter of a project. Conversion runs in the background;
+    /// poll [`project`](Self::project) until its state is back to `Default`.
+    pub async fn convert_project(&self, project_id: &str) -> Result<(), ElevenLabsTTSError> {
+        let url = self.url(&format!(
+            "/{}/convert",
+            ids::check_path_id("Project", project_id)?
+        ));
+        self.post_empty("studio-project-convert", &url).await
+    }
+
+    /// Start converting one chapter of a project
+    pub async fn convert_chapter(
+        &self,
+        project_id: &str,
+        chapter_id: &str,
+    ) -> Result<(), ElevenLabsTTSError> {
+        let url = self.url(&format!(
+            "/{}/chapters/{}/convert",
+            ids::check_path_id("Project", project_id)?,
+            ids::check_path_id("Chapter", chapter_id)?
+        ));
+        self.post_empty("studio-chapter-convert", &url).await
+    }
+
+    /// List the converted versions of a project
+    pub async fn project_snapshots(
+        &self,
+        project_id: &str,
+    ) -> Result<Vec<ProjectSnapshot>, ElevenLabsTTSError> {
+        let url = self.url(&format!(
+            "/{}/snapshots",
+            ids::check_path_id("Project", project_id)?
+        ));
+        let response: ProjectSnapshotsResponse = self
+            .client
+            .get_json("studio-project-snapshots", &url, &[])
+            .await?;
+        Ok(response.snapshots)
+    }
+
+    /// Download the audio of a project snapshot as one MP3
+    pub async fn download_project_snapshot(
+        &self,
+        project_id: &str,
+        project_snapshot_id: &str,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let url = self.url(&format!(
+            "/{}/snapshots/{}/stream",
+            ids::check_path_id("Project", project_id)?,
+            ids::check_path_id("Snapshot", project_snapshot_id)?
+        ));
+        self.download("studio-project-snapshot", &url).await
+    }
+
+    /// Download the audio of a chapter snapshot as an MP3
+    pub async fn download_chapter_snapshot(
+        &self,
+        project_id: &str,
+        chapter_id: &str,
+        chapter_snapshot_id: &str,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let url = self.chapter_snapshot_url(project_id, chapter_id, chapter_snapshot_id)?;
+        self.download("studio-chapter-snapshot", &url).await
+    }
+
+    /// List the converted versions of a chapter
+    pub async fn chapter_snapshots(
+        &self,
+        project_id: &str,
+        chapter_id: &str,
+    ) -> Result<Vec<ChapterSnapshot>, ElevenLabsTTSError> {
+        let url = self.url(&format!(
+            "/{}/chapters/{}/snapshots",
+            ids::check_path_id("Project", project_id)?,
+            ids::check_path_id("Chapter", chapter_id)?
+        ));
+        let response: ChapterSnapshotsResponse = self
+            .client
+            .get_json("studio-chapter-snapshots", &url, &[])
+            .await?;
+        Ok(response.snapshots)
+    }
+
+    /// The most recent converted version of a chapter, if it was ever converted
+    pub async fn latest_chapter_snapshot(
+        &self,
+        project_id: &str,
+        chapter_id: &str,
+    ) -> Result<Option<ChapterSnapshot>, ElevenLabsTTSError> {
+        let snapshots = self.chapter_snapshots(project_id, chapter_id).await?;
+        Ok(snapshots
+            .into_iter()
+            .max_by_key(|snapshot| snapshot.created_at_unix))
+    }
+
+    /// Stream the audio of a chapter snapshot as an MP3, without buffering it all
+    ///
+    /// ```rust,no_run
+    /// use futures_util::StreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// // Pull the latest render of every chapter
+    /// let studio = client.studio();
+    /// for chapter in studio.chapters("proj_123").await? {
+    ///     let Some(snapshot) = studio.latest_chapter_snapshot("proj_123", &chapter.chapter_id).await? else {
+    ///         continue;
+    ///     };
+    ///     let mut audio = studio
+    ///         .stream_chapter_snapshot("proj_123", &chapter.chapter_id, &snapshot.chapter_snapshot_id)
+    ///         .await?;
+    ///     let mut file = tokio::fs::File::create(format!("{}.mp3", chapter.name)).await?;
+    ///     while let Some(chunk) = audio.next().await {
+    ///         file.write_all(&chunk?).await?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_chapter_snapshot(
+        &self,
+        project_id: &str,
+        chapter_id: &str,
+        chapter_snapshot_id: &str,
+    ) -> Result<AudioStream, ElevenLabsTTSError> {
+        let url = self.chapter_snapshot_url(project_id, chapter_id, chapter_snapshot_id)?;
+        let request = SnapshotAudioRequest {
+            convert_to_mpeg: true,
+        };
+        self.client
+            .post_for_stream(
+                "studio-chapter-snapshot-stream",
+                &url,
+                &[],
+                &request,
+                &RequestOptions::default(),
+            )
+            .await
+    }
+
+    fn chapter_snapshot_url(
+        &self,
+        project_id: &str,
+        chapter_id: &str,
+        chapter_snapshot_id: &str,
+    ) -> Result<String, ElevenLabsTTSError> {
+        Ok(self.url(&format!(
+            "/{}/chapters/{}/snapshots/{}/stream",
+            ids::check_path_id("Project", project_id)?,
+            ids::check_path_id("Chapter", chapter_id)?,
+            ids::check_path_id("Snapshot", chapter_snapshot_id)?
+        )))
+    }
+
+    /// URL of a path under the projects endpoint
+    fn url(&self, path: &str) -> String {
+        format!("{}/studio/projects{}", self.client.inner.base_url, path)
+    }
+
+    async fn post_chapter(
+        &self,
+        project_id: &str,
+        name: &str,
+        from_url: Option<&str>,
+    ) -> Result<StudioChapter, ElevenLabsTTSError> {
+        if name.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Chapter name cannot be empty".to_string(),
+            ));
+        }
+        let url = self.url(&format!(
+            "/{}/chapters",
+            ids::check_path_id("Project", project_id)?
+        ));
+        let request = AddChapterRequest { name, from_url };
+        let response: ChapterResponse = self
+            .client
+            .post_for_json(
+                "studio-chapter-create",
+                &url,
+                &[],
+                &request,
+                &RequestOptions::default(),
+            )
+            .await?;
+        Ok(response.chapter)
+    }
+
+    async fn post_empty(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+    ) -> Result<(), ElevenLabsTTSError> {
+        let _: serde_json::Value = self
+            .client
+            .post_for_json(
+                endpoint,
+                url,
+                &[],
+                &serde_json::Map::new(),
+                &RequestOptions::default(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn download(
+        &self,
+        endpoint: &'static str,
+        url: &str,
+    ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let request = SnapshotAudioRequest {
+            convert_to_mpeg: true,
+        };
+        Ok(self
+            .client
+            .post_for_audio(
+                endpoint,
+                url,
+                &[],
+                &request,
+                &[],
+                &RequestOptions::default(),
+            )
+            .await?
+            .audio)
+    }
+}
+
+/// Builder for a new Studio project, created empty, from a document or from a web page
+pub struct CreateProjectBuilder {
+    client: ElevenLabsTTSClient,
+    name: String,
+    title_voice_id: Option<String>,
+    paragraph_voice_id: Option<String>,
+    model_id: Option<String>,
+    from_url: Option<String>,
+    from_document: Option<(String, Vec<u8>)>,
+    title: Option<String>,
+    author: Option<String>,
+    quality_preset: Option<String>,
+    auto_convert: Option<bool>,
+    options: RequestOptions,
+}
+
+impl CreateProjectBuilder {
+    fn new(client: ElevenLabsTTSClient, name: String) -> Self {
+        Self {
+            client,
+            name,
+            title_voice_id: None,
+            paragraph_voice_id: None,
+            model_id: None,
+            from_url: None,
+            from_document: None,
+            title: None,
+            author: None,
+            quality_preset: None,
+            auto_convert: None,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Narrate titles and paragraphs with the same voice
+    pub fn voice<S: Into<String>>(self, voice_id: S) -> Self {
+        let voice_id = voice_id.into();
+        self.title_voice_id(voice_id.clone())
+            .paragraph_voice_id(voice_id)
+    }
+
+    /// Voice for titles and headings (required, or set with [`voice`](Self::voice))
+    pub fn title_voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.title_voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Voice for paragraphs (required, or set with [`voice`](Self::voice))
+    pub fn paragraph_voice_id<S: Into<String>>(mut self, voice_id: S) -> Self {
+        self.paragraph_voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Set the model, as a [`ModelId`] or a model ID string. Defaults to: eleven_multilingual_v2
+    pub fn model<M: Into<ModelId>>(mut self, model_id: M) -> Self {
+        self.model_id = Some(model_id.into().to_string());
+        self
+    }
+
+    /// Fill the project with the text of a web page
+    pub fn from_url<S: Into<String>>(mut self, url: S) -> Self {
+        self.from_url = Some(url.into());
+        self
+    }
+
+    /// Fill the project from a document (`.epub`, `.pdf`, `.txt`, `.html` or `.docx`);
+    /// the file name's extension tells the API how to read it
+    pub fn from_document<S: Into<String>>(mut self, file_name: S, document: Vec<u8>) -> Self {
+        self.from_document = Some((file_name.into(), document));
+        self
+    }
+
+    /// Title written into the metadata of downloaded audio
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Author written into the metadata of downloaded audio
+    pub fn author<S: Into<String>>(mut self, author: S) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Output quality: `standard`, `high`, `ultra` or `ultra_lossless`
+    pub fn quality_preset<S: Into<String>>(mut self, quality_preset: S) -> Self {
+        self.quality_preset = Some(quality_preset.into());
+        self
+    }
+
+    /// Convert the project as soon as it is created
+    pub fn auto_convert(mut self, auto_convert: bool) -> Self {
+        self.auto_convert = Some(auto_convert);
+        self
+    }
+
+    /// Add a header to this request (e.g. a correlation ID), overriding client defaults
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.options.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Create the project
+    pub async fn execute(self) -> Result<StudioProject, ElevenLabsTTSError> {
+        let form = self.form()?;
+        let url = format!("{}/studio/projects", self.client.inner.base_url);
+        let response: ProjectResponse = self
+            .client
+            .post_multipart("studio-project-create", &url, form, &self.options)
+            .await?;
+        Ok(response.project)
+    }
+
+    fn form(&self) -> Result<multipart::Form, ElevenLabsTTSError> {
+        if self.name.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Project name cannot be empty".to_string(),
+            ));
+        }
+        let (Some(title_voice_id), Some(paragraph_voice_id)) =
+            (&self.title_voice_id, &self.paragraph_voice_id)
+        else {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Studio projects need a title and a paragraph voice".to_string(),
+            ));
+        };
+        ids::check_not_voice_name(title_voice_id)?;
+        ids::check_not_voice_name(paragraph_voice_id)?;
+        if self.from_url.is_some() && self.from_document.is_some() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Create a project from a URL or a document, not both".to_string(),
+            ));
+        }
+
+        let mut form = multipart::Form::new()
+            .text("name", self.name.as_str())
+            .text("default_title_voice_id", title_voice_id.as_str())
+            .text("default_paragraph_voice_id", paragraph_voice_id.as_str())
+            .text(
+                "default_model_id",
+                self.model_id
+                    .clone()
+                    .unwrap_or_else(|| ModelId::ElevenMultilingualV2.to_string()),
+            );
+        if let Some(from_url) = &self.from_url {
+            form = form.text("from_url", from_url.as_str());
+        }
+        if let Some((file_name, document)) = &self.from_document {
+            form = form.file(
+                "from_document",
+                file_name,
+                "application/octet-stream",
+                document.clone(),
+            );
+        }
+        if let Some(title) = &self.title {
+            form = form.text("title", title.as_str());
+        }
+        if let Some(author) = &self.author {
+            form = form.text("author", author.as_str());
+        }
+        if let Some(quality_preset) = &self.quality_preset {
+            form = form.text("quality_preset", quality_preset.as_str());
+        }
+        if let Some(auto_convert) = self.auto_convert {
+            form = form.text("auto_convert", auto_convert.to_string());
+        }
+        Ok(form)
+    }
+}
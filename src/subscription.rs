@@ -0,0 +1,61 @@
+//! The account's subscription and the quota left in the billing period
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::ElevenLabsTTSError;
+
+/// How long the subscription fetched by `execute_checked` is reused. Characters billed by
+/// checked requests are deducted from the cached copy meanwhile.
+pub const SUBSCRIPTION_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// The account's subscription, as returned by `GET /v1/user/subscription`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Subscription {
+    /// Plan name, e.g. `free`, `creator`, `pro`
+    pub tier: String,
+
+    /// Characters (credits) used in the current billing period
+    pub character_count: u64,
+
+    /// Characters (credits) available in the current billing period
+    pub character_limit: u64,
+
+    /// Whether usage beyond the limit is billed instead of refused
+    #[serde(default)]
+    pub can_extend_character_limit: bool,
+
+    /// When the character count resets, as a unix timestamp
+    #[serde(default)]
+    pub next_character_count_reset_unix: Option<i64>,
+
+    /// e.g. `active`, `trialing`, `past_due`
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+impl Subscription {
+    /// Characters (credits) left in the current billing period
+    pub fn remaining_characters(&self) -> u64 {
+        self.character_limit.saturating_sub(self.character_count)
+    }
+
+    /// Fail with [`ElevenLabsTTSError::QuotaExceededError`] if `characters` more don't fit
+    /// in the billing period. Subscriptions that bill usage beyond the limit always pass.
+    pub fn check_quota(&self, characters: u64) -> Result<(), ElevenLabsTTSError> {
+        if self.can_extend_character_limit || characters <= self.remaining_characters() {
+            return Ok(());
+        }
+        Err(ElevenLabsTTSError::QuotaExceededError {
+            used: Some(self.character_count),
+            limit: Some(self.character_limit),
+            message: format!(
+                "Request needs {} characters but only {} remain in the billing period",
+                characters,
+                self.remaining_characters()
+            ),
+            request_id: None,
+        })
+    }
+}
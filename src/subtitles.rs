@@ -0,0 +1,166 @@
+//! SRT and WebVTT captions from character alignment
+//!
+//! ```rust
+//! use elevenlabs_tts::Alignment;
+//! use elevenlabs_tts::subtitles::{self, SubtitleOptions};
+//!
+//! let text = "Hi there";
+//! let alignment = Alignment {
+//!     characters: text.chars().map(String::from).collect(),
+//!     character_start_times_seconds: (0..8).map(|i| i as f64 * 0.1).collect(),
+//!     character_end_times_seconds: (1..9).map(|i| i as f64 * 0.1).collect(),
+//! };
+//! let srt = subtitles::srt(&alignment, &SubtitleOptions::default());
+//! assert_eq!(srt, "1\n00:00:00,000 --> 00:00:00,800\nHi there\n\n");
+//! ```
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::{Alignment, WordTimestamp};
+
+/// How words are grouped into caption cues
+#[derive(Debug, Clone)]
+pub struct SubtitleOptions {
+    /// Maximum characters per caption line
+    pub max_line_length: usize,
+
+    /// Maximum lines per cue
+    pub max_lines: usize,
+
+    /// Maximum time a single cue stays on screen
+    pub max_duration: Duration,
+
+    /// Start a new cue after sentence-ending punctuation
+    pub break_on_sentences: bool,
+}
+
+impl Default for SubtitleOptions {
+    fn default() -> Self {
+        Self {
+            max_line_length: 42,
+            max_lines: 2,
+            max_duration: Duration::from_secs(6),
+            break_on_sentences: true,
+        }
+    }
+}
+
+impl SubtitleOptions {
+    /// Set the maximum characters per caption line
+    pub fn max_line_length(mut self, max_line_length: usize) -> Self {
+        self.max_line_length = max_line_length.max(1);
+        self
+    }
+
+    /// Set the maximum lines per cue
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    /// Set the maximum time a single cue stays on screen
+    pub fn max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = max_duration;
+        self
+    }
+
+    /// Enable or disable starting a new cue after each sentence
+    pub fn break_on_sentences(mut self, break_on_sentences: bool) -> Self {
+        self.break_on_sentences = break_on_sentences;
+        self
+    }
+}
+
+/// A caption shown between `start` and `end` (in seconds)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cue {
+    pub start: f64,
+    pub end: f64,
+    pub lines: Vec<String>,
+}
+
+/// Group the aligned characters into words and the words into cues
+pub fn cues(alignment: &Alignment, options: &SubtitleOptions) -> Vec<Cue> {
+    let mut cues = Vec::new();
+    let mut current: Option<Cue> = None;
+
+    for WordTimestamp { word, start, end } in alignment.display_words() {
+        if let Some(cue) = current.as_mut() {
+            let last_line = cue.lines.last().map_or(0, |line| line.chars().count());
+            let fits_line = last_line + 1 + word.chars().count() <= options.max_line_length;
+            let fits_cue = fits_line || cue.lines.len() < options.max_lines;
+            let fits_time = end - cue.start <= options.max_duration.as_secs_f64();
+            let sentence_ended = options.break_on_sentences
+                && cue
+                    .lines
+                    .last()
+                    .is_some_and(|line| line.ends_with(['.', '!', '?', '…']));
+
+            if fits_cue && fits_time && !sentence_ended {
+                match cue.lines.last_mut() {
+                    Some(line) if fits_line => {
+                        line.push(' ');
+                        line.push_str(&word);
+                    }
+                    _ => cue.lines.push(word),
+                }
+                cue.end = end;
+                continue;
+            }
+            cues.extend(current.take());
+        }
+        current = Some(Cue {
+            start,
+            end,
+            lines: vec![word],
+        });
+    }
+
+    cues.extend(current);
+    cues
+}
+
+/// Render the alignment as SubRip (`.srt`) captions
+pub fn srt(alignment: &Alignment, options: &SubtitleOptions) -> String {
+    let mut output = String::new();
+    for (index, cue) in cues(alignment, options).iter().enumerate() {
+        let _ = write!(
+            output,
+            "{}\n{} --> {}\n{}\n\n",
+            index + 1,
+            timestamp(cue.start, ','),
+            timestamp(cue.end, ','),
+            cue.lines.join("\n")
+        );
+    }
+    output
+}
+
+/// Render the alignment as WebVTT (`.vtt`) captions
+pub fn vtt(alignment: &Alignment, options: &SubtitleOptions) -> String {
+    let mut output = String::from("WEBVTT\n\n");
+    for cue in cues(alignment, options) {
+        let _ = write!(
+            output,
+            "{} --> {}\n{}\n\n",
+            timestamp(cue.start, '.'),
+            timestamp(cue.end, '.'),
+            cue.lines.join("\n")
+        );
+    }
+    output
+}
+
+/// `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT)
+fn timestamp(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        separator,
+        millis % 1000
+    )
+}
@@ -0,0 +1,152 @@
+//! ID3v2 metadata for generated MP3s (`tags` feature)
+//!
+//! Players and podcast apps show the title, artist and album from the file's ID3 tag and
+//! order audiobook chapters by track number. [`Id3Tags`] writes that tag into the MP3
+//! bytes returned by the API, so the files don't need a separate tagging pass.
+
+use std::io::Cursor;
+use std::path::Path;
+
+use id3::{Tag, TagLike, Version};
+
+use crate::{ElevenLabsTTSError, StaticVoice, audio};
+
+/// ID3v2 fields to write into an MP3. Fields left unset are not written; fields already
+/// in the MP3's tag are kept unless set here.
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::{tags::Id3Tags, voices::all_voices::RACHEL};
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let audio = client.text_to_speech("Welcome to episode one.").voice(&RACHEL).execute().await?;
+/// let tagged = Id3Tags::new()
+///     .title("Episode 1: Getting Started")
+///     .voice(&RACHEL)
+///     .album("The Rust Podcast")
+///     .track(1)
+///     .apply(&audio)?;
+/// std::fs::write("episode-1.mp3", tagged)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Id3Tags {
+    pub title: Option<String>,
+
+    /// Artist, usually the narrator's voice
+    pub artist: Option<String>,
+
+    /// Album, e.g. the book or podcast the file is a chapter or episode of
+    pub album: Option<String>,
+
+    /// Position of the file within the album, starting at 1
+    pub track: Option<u32>,
+
+    /// Number of tracks in the album
+    pub total_tracks: Option<u32>,
+}
+
+impl Id3Tags {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn artist<S: Into<String>>(mut self, artist: S) -> Self {
+        self.artist = Some(artist.into());
+        self
+    }
+
+    /// Use the voice's name as the artist
+    pub fn voice(self, voice: &StaticVoice) -> Self {
+        self.artist(voice.name)
+    }
+
+    pub fn album<S: Into<String>>(mut self, album: S) -> Self {
+        self.album = Some(album.into());
+        self
+    }
+
+    pub fn track(mut self, track: u32) -> Self {
+        self.track = Some(track);
+        self
+    }
+
+    pub fn total_tracks(mut self, total_tracks: u32) -> Self {
+        self.total_tracks = Some(total_tracks);
+        self
+    }
+
+    /// Return the MP3 with an ID3v2.4 tag holding these fields in front of the audio,
+    /// replacing any ID3v2 tag it already starts with
+    pub fn apply(&self, mp3: &[u8]) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        let (mut tag, audio) = match audio::id3v2_length(mp3) {
+            Some(length) => (
+                Tag::read_from2(Cursor::new(mp3)).map_err(tag_error)?,
+                mp3.get(length..).unwrap_or_default(),
+            ),
+            None => (Tag::new(), mp3),
+        };
+        if !audio::is_mp3(audio) {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "ID3 tags can only be written to MP3 audio".to_string(),
+            ));
+        }
+
+        if let Some(title) = &self.title {
+            tag.set_title(title.as_str());
+        }
+        if let Some(artist) = &self.artist {
+            tag.set_artist(artist.as_str());
+        }
+        if let Some(album) = &self.album {
+            tag.set_album(album.as_str());
+        }
+        if let Some(track) = self.track {
+            tag.set_track(track);
+        }
+        if let Some(total_tracks) = self.total_tracks {
+            tag.set_total_tracks(total_tracks);
+        }
+
+        let mut tagged = Vec::with_capacity(mp3.len() + 256);
+        tag.write_to(&mut tagged, Version::Id3v24)
+            .map_err(tag_error)?;
+        tagged.extend_from_slice(audio);
+        Ok(tagged)
+    }
+
+    /// Tag the MP3 file at `path` in place
+    pub async fn apply_to_path<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTSError> {
+        let path = path.as_ref();
+        let mp3 = tokio::fs::read(path).await?;
+        tokio::fs::write(path, self.apply(&mp3)?).await?;
+        Ok(())
+    }
+
+    /// Read the ID3v2 tag at the start of an MP3, if it has one
+    pub fn read(mp3: &[u8]) -> Result<Option<Self>, ElevenLabsTTSError> {
+        if audio::id3v2_length(mp3).is_none() {
+            return Ok(None);
+        }
+        let tag = Tag::read_from2(Cursor::new(mp3)).map_err(tag_error)?;
+        Ok(Some(Self {
+            title: tag.title().map(str::to_string),
+            artist: tag.artist().map(str::to_string),
+            album: tag.album().map(str::to_string),
+            track: tag.track(),
+            total_tracks: tag.total_tracks(),
+        }))
+    }
+}
+
+fn tag_error(error: id3::Error) -> ElevenLabsTTSError {
+    match error.kind {
+        id3::ErrorKind::Io(error) => ElevenLabsTTSError::IoError(error),
+        _ => ElevenLabsTTSError::ValidationError(format!("Invalid ID3 tag: {}", error)),
+    }
+}
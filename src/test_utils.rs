@@ -0,0 +1,330 @@
+//! VCR-style record/replay of API traffic, for exercising the client offline
+//!
+//! In record mode a [`Cassette`] is a local proxy: the client talks to it, it forwards every
+//! request to the real API and writes the request/response pairs to a fixture file. In replay
+//! mode it answers from that file, so tests run through the full builder, serialization and
+//! response handling path without network access or an API key.
+//!
+//! ```rust,no_run
+//! use elevenlabs_tts::test_utils::Cassette;
+//!
+//! # async fn run() -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+//! // Once, with a real key: ELEVENLABS_RECORD=1 cargo test --features test-utils
+//! let cassette = if std::env::var("ELEVENLABS_RECORD").is_ok() {
+//!     Cassette::record("tests/fixtures/hello.json", "https://api.elevenlabs.io/v1").await?
+//! } else {
+//!     Cassette::replay("tests/fixtures/hello.json").await?
+//! };
+//! let client = cassette.client(std::env::var("ELEVENLABS_API_KEY").unwrap_or_default());
+//! let audio = client.text_to_speech("Hello").execute().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::{ElevenLabsTTSClient, ElevenLabsTTSError};
+
+/// A request captured in a fixture file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureRequest {
+    pub method: String,
+
+    /// Path and query relative to the base URL, e.g. `/text-to-speech/<voice>?output_format=...`
+    pub path: String,
+
+    /// JSON body (or the raw text when it isn't JSON)
+    #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+    pub body: serde_json::Value,
+}
+
+/// A response captured in a fixture file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixtureResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+
+    /// Base64-encoded body (audio is binary)
+    pub body: String,
+}
+
+/// A recorded request/response pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: FixtureRequest,
+    pub response: FixtureResponse,
+}
+
+struct Recording {
+    path: PathBuf,
+    upstream: String,
+    http: reqwest::Client,
+}
+
+struct State {
+    interactions: Vec<Interaction>,
+    used: Vec<bool>,
+    recording: Option<Recording>,
+}
+
+/// Local server recording API traffic to a fixture file, or replaying it
+pub struct Cassette {
+    base_url: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl Cassette {
+    /// Forward requests to `upstream` (e.g. `https://api.elevenlabs.io/v1`) and record them to `path`,
+    /// replacing any previous recording. Request headers, and so API keys, are never written.
+    pub async fn record<P: AsRef<Path>, S: Into<String>>(
+        path: P,
+        upstream: S,
+    ) -> Result<Self, ElevenLabsTTSError> {
+        let recording = Recording {
+            path: path.as_ref().to_path_buf(),
+            upstream: upstream.into().trim_end_matches('/').to_string(),
+            http: reqwest::Client::new(),
+        };
+        Self::start(Vec::new(), Some(recording)).await
+    }
+
+    /// Answer requests from the interactions recorded in `path`
+    pub async fn replay<P: AsRef<Path>>(path: P) -> Result<Self, ElevenLabsTTSError> {
+        let fixture = tokio::fs::read(path).await?;
+        let interactions: Vec<Interaction> = serde_json::from_slice(&fixture)?;
+        Self::start(interactions, None).await
+    }
+
+    async fn start(
+        interactions: Vec<Interaction>,
+        recording: Option<Recording>,
+    ) -> Result<Self, ElevenLabsTTSError> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let state = Arc::new(Mutex::new(State {
+            used: vec![false; interactions.len()],
+            interactions,
+            recording,
+        }));
+
+        let server_state = state.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                tokio::spawn(serve(socket, server_state.clone()));
+            }
+        });
+
+        Ok(Self { base_url, state })
+    }
+
+    /// Base URL to point a client at
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// A client talking to this cassette. The key only matters in record mode.
+    pub fn client<S: Into<String>>(&self, api_key: S) -> ElevenLabsTTSClient {
+        ElevenLabsTTSClient::with_base_url(api_key.into(), self.base_url.clone())
+    }
+
+    /// The interactions recorded so far (or loaded for replay)
+    pub fn interactions(&self) -> Vec<Interaction> {
+        lock(&self.state).interactions.clone()
+    }
+}
+
+fn lock(state: &Mutex<State>) -> std::sync::MutexGuard<'_, State> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+async fn serve(mut socket: TcpStream, state: Arc<Mutex<State>>) {
+    let Some((request, headers, raw_body)) = read_request(&mut socket).await else {
+        return;
+    };
+
+    let recording = lock(&state)
+        .recording
+        .as_ref()
+        .map(|recording| (recording.upstream.clone(), recording.http.clone()));
+    let response = match recording {
+        Some((upstream, http)) => {
+            let response = forward(&http, &upstream, &request, &headers, raw_body).await;
+            let mut state = lock(&state);
+            state.interactions.push(Interaction {
+                request,
+                response: response.clone(),
+            });
+            state.used.push(true);
+            if let Some(recording) = &state.recording {
+                if let Ok(json) = serde_json::to_vec_pretty(&state.interactions) {
+                    // Save after every interaction so an aborted test still leaves a fixture
+                    if let Some(parent) = recording.path.parent() {
+                        let _ = std::fs::create_dir_all(parent);
+                    }
+                    let _ = std::fs::write(&recording.path, json);
+                }
+            }
+            response
+        }
+        None => replay(&mut lock(&state), &request),
+    };
+
+    let body = STANDARD.decode(&response.body).unwrap_or_default();
+    let mut head = format!(
+        "HTTP/1.1 {} Cassette\r\ncontent-length: {}\r\nconnection: close\r\n",
+        response.status,
+        body.len()
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str("\r\n");
+
+    let _ = socket.write_all(head.as_bytes()).await;
+    let _ = socket.write_all(&body).await;
+    let _ = socket.shutdown().await;
+}
+
+/// The first unused matching interaction, or the last matching one when all were used
+fn replay(state: &mut State, request: &FixtureRequest) -> FixtureResponse {
+    let matching: Vec<usize> = (0..state.interactions.len())
+        .filter(|&index| &state.interactions[index].request == request)
+        .collect();
+    let index = matching
+        .iter()
+        .copied()
+        .find(|&index| !state.used[index])
+        .or(matching.last().copied());
+
+    match index {
+        Some(index) => {
+            state.used[index] = true;
+            state.interactions[index].response.clone()
+        }
+        None => {
+            let detail = serde_json::json!({
+                "detail": {
+                    "status": "no_recorded_interaction",
+                    "message": format!("No recorded interaction for {} {}", request.method, request.path),
+                }
+            });
+            FixtureResponse {
+                status: 404,
+                headers: vec![("content-type".to_string(), "application/json".to_string())],
+                body: STANDARD.encode(detail.to_string()),
+            }
+        }
+    }
+}
+
+async fn forward(
+    http: &reqwest::Client,
+    upstream: &str,
+    request: &FixtureRequest,
+    headers: &[(String, String)],
+    body: Vec<u8>,
+) -> FixtureResponse {
+    let method = reqwest::Method::from_bytes(request.method.as_bytes()).unwrap_or_default();
+    let mut outbound = http
+        .request(method, format!("{}{}", upstream, request.path))
+        .body(body);
+    for (name, value) in headers {
+        if !matches!(name.as_str(), "host" | "content-length" | "connection") {
+            outbound = outbound.header(name, value);
+        }
+    }
+
+    let failed = |message: String| FixtureResponse {
+        status: 502,
+        headers: Vec::new(),
+        body: STANDARD.encode(message),
+    };
+    let response = match outbound.send().await {
+        Ok(response) => response,
+        Err(err) => return failed(err.to_string()),
+    };
+    let status = response.status().as_u16();
+    let response_headers = response
+        .headers()
+        .iter()
+        .filter(|(name, _)| {
+            !matches!(
+                name.as_str(),
+                "content-length" | "connection" | "transfer-encoding" | "set-cookie"
+            )
+        })
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+    match response.bytes().await {
+        Ok(bytes) => FixtureResponse {
+            status,
+            headers: response_headers,
+            body: STANDARD.encode(bytes),
+        },
+        Err(err) => failed(err.to_string()),
+    }
+}
+
+async fn read_request(
+    socket: &mut TcpStream,
+) -> Option<(FixtureRequest, Vec<(String, String)>, Vec<u8>)> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 8192];
+
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(position) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .and_then(|(_, value)| value.parse::<usize>().ok())
+        .unwrap_or(0);
+    let mut body = buffer[header_end..].to_vec();
+    while body.len() < content_length {
+        let read = socket.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    let json_body = if body.is_empty() {
+        serde_json::Value::Null
+    } else {
+        serde_json::from_slice(&body)
+            .unwrap_or_else(|_| serde_json::Value::String(String::from_utf8_lossy(&body).into()))
+    };
+    let request = FixtureRequest {
+        method,
+        path,
+        body: json_body,
+    };
+    Some((request, headers, body))
+}
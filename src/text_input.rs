@@ -0,0 +1,140 @@
+//! Reading the text to speak from files and readers
+//!
+//! Scripts often come from files saved by other tools, so the encoding is detected
+//! rather than assumed: a byte order mark selects UTF-8 or UTF-16, BOM-less UTF-16 is
+//! recognised by its zero bytes, and text that is not valid UTF-8 is read as
+//! Windows-1252. See [`ElevenLabsTTSClient::text_to_speech_from_path`](crate::ElevenLabsTTSClient::text_to_speech_from_path).
+
+use std::path::Path;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::ElevenLabsTTSError;
+
+/// Largest input read by [`read_text`] and the client's `text_to_speech_from_*` helpers
+pub const MAX_TEXT_INPUT_BYTES: u64 = 10 * 1024 * 1024;
+
+// Windows-1252 characters for the bytes 0x80 - 0x9F (unassigned bytes map to themselves)
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž', '\u{8F}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}', 'ž', 'Ÿ',
+];
+
+/// Read a whole reader as text, failing if it is longer than `max_bytes`
+pub async fn read_text<R>(reader: R, max_bytes: u64) -> Result<String, ElevenLabsTTSError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut bytes = Vec::new();
+    reader
+        .take(max_bytes.saturating_add(1))
+        .read_to_end(&mut bytes)
+        .await?;
+    if bytes.len() as u64 > max_bytes {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "Text input is larger than {} bytes",
+            max_bytes
+        )));
+    }
+    decode_text(&bytes)
+}
+
+/// Read a whole file as text, failing if it is longer than `max_bytes`
+pub async fn read_text_file<P: AsRef<Path>>(
+    path: P,
+    max_bytes: u64,
+) -> Result<String, ElevenLabsTTSError> {
+    let file = tokio::fs::File::open(path.as_ref()).await?;
+    read_text(file, max_bytes).await
+}
+
+/// Decode text in UTF-8, UTF-16 (LE or BE) or Windows-1252, dropping any byte order mark
+///
+/// ```rust
+/// use elevenlabs_tts::text_input::decode_text;
+///
+/// assert_eq!(decode_text(b"\xEF\xBB\xBFHello").unwrap(), "Hello");
+/// assert_eq!(decode_text(b"\xFF\xFEH\x00i\x00").unwrap(), "Hi");
+/// assert_eq!(decode_text(b"caf\xE9").unwrap(), "café");
+/// ```
+pub fn decode_text(bytes: &[u8]) -> Result<String, ElevenLabsTTSError> {
+    if let Some(rest) = bytes.strip_prefix(b"\xEF\xBB\xBF") {
+        return String::from_utf8(rest.to_vec()).map_err(|_| invalid("UTF-8"));
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFF\xFE") {
+        return decode_utf16(rest, u16::from_le_bytes);
+    }
+    if let Some(rest) = bytes.strip_prefix(b"\xFE\xFF") {
+        return decode_utf16(rest, u16::from_be_bytes);
+    }
+
+    // Text without a BOM that is mostly zero bytes at odd (or even) offsets is UTF-16
+    if bytes.len() >= 2 && bytes.len().is_multiple_of(2) {
+        let zeros_at = |offset: usize| {
+            bytes
+                .iter()
+                .skip(offset)
+                .step_by(2)
+                .filter(|byte| **byte == 0)
+                .count()
+        };
+        let half = bytes.len() / 4;
+        if zeros_at(1) > half && zeros_at(0) == 0 {
+            return decode_utf16(bytes, u16::from_le_bytes);
+        }
+        if zeros_at(0) > half && zeros_at(1) == 0 {
+            return decode_utf16(bytes, u16::from_be_bytes);
+        }
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Ok(text.to_string()),
+        Err(_) => Ok(bytes.iter().map(|byte| windows_1252(*byte)).collect()),
+    }
+}
+
+fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> Result<String, ElevenLabsTTSError> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(invalid("UTF-16"));
+    }
+    let units = bytes.chunks_exact(2).map(|pair| unit([pair[0], pair[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| invalid("UTF-16"))
+}
+
+fn windows_1252(byte: u8) -> char {
+    match byte {
+        0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
+
+fn invalid(encoding: &str) -> ElevenLabsTTSError {
+    ElevenLabsTTSError::ValidationError(format!("Text input is not valid {}", encoding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_text_detects_encodings() {
+        assert_eq!(decode_text("héllo".as_bytes()).unwrap(), "héllo");
+        assert_eq!(decode_text(b"\xFE\xFF\x00H\x00i").unwrap(), "Hi");
+        assert_eq!(decode_text(b"H\x00i\x00!\x00").unwrap(), "Hi!");
+        assert_eq!(decode_text(b"\x00H\x00i").unwrap(), "Hi");
+        assert_eq!(decode_text(b"\x93quoted\x94").unwrap(), "“quoted”");
+        assert!(decode_text(b"\xFF\xFE\x00\xD8").is_err());
+        assert!(decode_text(b"\xEF\xBB\xBF\xFF").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_text_enforces_size_cap() {
+        assert_eq!(read_text(&b"Hello"[..], 5).await.unwrap(), "Hello");
+        assert!(matches!(
+            read_text(&b"Hello!"[..], 5).await,
+            Err(ElevenLabsTTSError::ValidationError(_))
+        ));
+    }
+}
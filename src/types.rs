@@ -1,4 +1,10 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ElevenLabsTTSError;
 
 /// Request body for text-to-speech API calls
 #[derive(Debug, Clone, Serialize)]
@@ -12,10 +18,10 @@ pub struct TTSRequest {
     // Output format of the generated audio. Formatted as codec_sample_rate_bitrate. So an mp3 with 22.05kHz sample rate at 32kbs is represented as mp3_22050_32.
     // MP3 with 192kbps bitrate requires you to be subscribed to Creator tier or above. PCM with 44.1kHz sample rate requires you to be subscribed to Pro tier or above.
     // Note that the μ-law format (sometimes written mu-law, often approximated as u-law) is commonly used for Twilio audio inputs.
-    // Possible values are: mp3_22050_32 | mp3_44100_32 | mp3_44100_64 | mp3_44100_96 | mp3_44100_128 | mp3_44100_192 | pcm_8000 | pcm_16000 | pcm_22050 | pcm_24000 | pcm_44100 | pcm_48000 | ulaw_8000 | alaw_8000 | opus_48000_32 | opus_48000_64 | opus_48000_96
     // Default to: mp3_44100_128
-    // This goes in the URL path, not in the body.
-    pub output_format: Option<String>,
+    // This goes in the query string, not in the body.
+    #[serde(skip_serializing)]
+    pub output_format: OutputFormat,
 
     // Identifier of the model that will be used, you can query them using GET https://api.elevenlabs.io/v1/models.
     // The model needs to have support for text to speech, you can check this using the can_do_text_to_speech property.
@@ -52,7 +58,7 @@ pub struct TTSRequest {
     // This parameter controls text normalization with three modes: ‘auto’, ‘on’, and ‘off’. When set to ‘auto’, the system will automatically decide whether to apply text normalization (e.g., spelling out numbers). With ‘on’,
     // text normalization will always be applied, while with ‘off’, it will be skipped. For ‘eleven_turbo_v2_5’ and ‘eleven_flash_v2_5’ models, text normalization can only be enabled with Enterprise plans.
     // Defaults to: auto
-    pub apply_text_normalization: Option<String>,
+    pub apply_text_normalization: Option<TextNormalization>,
 
     // This parameter controls language text normalization. This helps with proper pronunciation of text in some supported languages.
     // WARNING: This parameter can heavily increase the latency of the request. Currently only supported for Japanese.
@@ -60,19 +66,557 @@ pub struct TTSRequest {
     pub apply_language_text_normalization: Option<bool>,
 
     // Voice settings overriding stored settings for the given voice. They are applied only on the given request.
-    pub voice_settings: VoiceSettings,
+    // When omitted, the settings stored for the voice apply.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voice_settings: Option<VoiceSettings>,
+
+    // When false, zero retention mode is used: the request is not stored in history and no logs are kept.
+    // Zero retention mode is only available to Enterprise customers.
+    // Defaults to: true
+    // This goes in the query string, not in the body.
+    #[serde(skip_serializing)]
+    pub enable_logging: Option<bool>,
+}
+
+impl TTSRequest {
+    /// Query parameters sent alongside the JSON body
+    pub(crate) fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![("output_format", self.output_format.to_string())];
+        if let Some(enable_logging) = self.enable_logging {
+            query.push(("enable_logging", enable_logging.to_string()));
+        }
+        query
+    }
+}
+
+/// Audio output format, formatted as `codec_sample_rate[_bitrate]`
+///
+/// MP3 at 192kbps requires the Creator tier or above, PCM at 44.1kHz the Pro tier or above.
+/// μ-law (`ulaw_8000`) is the format used by Twilio audio streams.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutputFormat {
+    Mp3_22050_32,
+    Mp3_24000_48,
+    Mp3_44100_32,
+    Mp3_44100_64,
+    Mp3_44100_96,
+    #[default]
+    Mp3_44100_128,
+    Mp3_44100_192,
+    Pcm_8000,
+    Pcm_16000,
+    Pcm_22050,
+    Pcm_24000,
+    Pcm_32000,
+    Pcm_44100,
+    Pcm_48000,
+    Ulaw_8000,
+    Alaw_8000,
+    Opus_48000_32,
+    Opus_48000_64,
+    Opus_48000_96,
+    Opus_48000_128,
+    Opus_48000_192,
+}
+
+impl OutputFormat {
+    /// Every known output format
+    pub const ALL: &'static [OutputFormat] = &[
+        OutputFormat::Mp3_22050_32,
+        OutputFormat::Mp3_24000_48,
+        OutputFormat::Mp3_44100_32,
+        OutputFormat::Mp3_44100_64,
+        OutputFormat::Mp3_44100_96,
+        OutputFormat::Mp3_44100_128,
+        OutputFormat::Mp3_44100_192,
+        OutputFormat::Pcm_8000,
+        OutputFormat::Pcm_16000,
+        OutputFormat::Pcm_22050,
+        OutputFormat::Pcm_24000,
+        OutputFormat::Pcm_32000,
+        OutputFormat::Pcm_44100,
+        OutputFormat::Pcm_48000,
+        OutputFormat::Ulaw_8000,
+        OutputFormat::Alaw_8000,
+        OutputFormat::Opus_48000_32,
+        OutputFormat::Opus_48000_64,
+        OutputFormat::Opus_48000_96,
+        OutputFormat::Opus_48000_128,
+        OutputFormat::Opus_48000_192,
+    ];
+
+    /// The value sent to the API, e.g. `mp3_44100_128`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Mp3_22050_32 => "mp3_22050_32",
+            OutputFormat::Mp3_24000_48 => "mp3_24000_48",
+            OutputFormat::Mp3_44100_32 => "mp3_44100_32",
+            OutputFormat::Mp3_44100_64 => "mp3_44100_64",
+            OutputFormat::Mp3_44100_96 => "mp3_44100_96",
+            OutputFormat::Mp3_44100_128 => "mp3_44100_128",
+            OutputFormat::Mp3_44100_192 => "mp3_44100_192",
+            OutputFormat::Pcm_8000 => "pcm_8000",
+            OutputFormat::Pcm_16000 => "pcm_16000",
+            OutputFormat::Pcm_22050 => "pcm_22050",
+            OutputFormat::Pcm_24000 => "pcm_24000",
+            OutputFormat::Pcm_32000 => "pcm_32000",
+            OutputFormat::Pcm_44100 => "pcm_44100",
+            OutputFormat::Pcm_48000 => "pcm_48000",
+            OutputFormat::Ulaw_8000 => "ulaw_8000",
+            OutputFormat::Alaw_8000 => "alaw_8000",
+            OutputFormat::Opus_48000_32 => "opus_48000_32",
+            OutputFormat::Opus_48000_64 => "opus_48000_64",
+            OutputFormat::Opus_48000_96 => "opus_48000_96",
+            OutputFormat::Opus_48000_128 => "opus_48000_128",
+            OutputFormat::Opus_48000_192 => "opus_48000_192",
+        }
+    }
+}
+
+impl OutputFormat {
+    /// Codec name: `mp3`, `pcm`, `ulaw`, `alaw` or `opus`
+    pub fn codec(&self) -> &'static str {
+        self.as_str().split('_').next().unwrap_or_default()
+    }
+
+    /// Sample rate in Hz
+    pub fn sample_rate(&self) -> u32 {
+        self.as_str()
+            .split('_')
+            .nth(1)
+            .and_then(|rate| rate.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Bitrate in kbps, for compressed formats (mp3 and opus)
+    pub fn bitrate_kbps(&self) -> Option<u32> {
+        self.as_str()
+            .split('_')
+            .nth(2)
+            .and_then(|rate| rate.parse().ok())
+    }
+
+    /// Number of audio channels (all ElevenLabs output is mono)
+    pub fn channels(&self) -> u16 {
+        1
+    }
+
+    /// Suggested file extension for the bytes as returned, without the dot. Raw PCM is
+    /// signed 16-bit little-endian. Files saved by `execute_to_path` use
+    /// [`file_extension`](Self::file_extension) instead.
+    pub fn extension(&self) -> &'static str {
+        self.codec()
+    }
+
+    /// File extension used when saving this format to disk, e.g. by `execute_to_path`:
+    /// raw PCM is wrapped in a WAV header (`wav`) and Opus arrives in an Ogg container
+    /// (`ogg`); other formats keep their [`extension`](Self::extension)
+    pub fn file_extension(&self) -> &'static str {
+        match self.codec() {
+            "pcm" => "wav",
+            "opus" => "ogg",
+            codec => codec,
+        }
+    }
+
+    /// MIME type to serve the audio with: `audio/mpeg`, `audio/ogg` (Opus), `audio/pcm`,
+    /// `audio/basic` (μ-law) or `audio/x-alaw-basic`
+    pub fn mime_type(&self) -> &'static str {
+        match self.codec() {
+            "mp3" => "audio/mpeg",
+            "opus" => "audio/ogg",
+            "ulaw" => "audio/basic",
+            "alaw" => "audio/x-alaw-basic",
+            _ => "audio/pcm",
+        }
+    }
+
+    /// Whether the audio is raw samples without a container (PCM, μ-law, A-law)
+    pub fn is_raw(&self) -> bool {
+        matches!(self.codec(), "pcm" | "ulaw" | "alaw")
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = ElevenLabsTTSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        OutputFormat::ALL
+            .iter()
+            .find(|format| format.as_str().eq_ignore_ascii_case(s.trim()))
+            .copied()
+            .ok_or_else(|| {
+                ElevenLabsTTSError::ValidationError(format!("Unknown output format: {}", s))
+            })
+    }
+}
+
+impl Serialize for OutputFormat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for OutputFormat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Text normalization mode (e.g. spelling out numbers)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextNormalization {
+    /// Let the model decide whether to normalize
+    #[default]
+    Auto,
+    /// Always normalize
+    On,
+    /// Never normalize
+    Off,
+}
+
+impl TextNormalization {
+    /// The value sent to the API: `auto`, `on` or `off`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextNormalization::Auto => "auto",
+            TextNormalization::On => "on",
+            TextNormalization::Off => "off",
+        }
+    }
+}
+
+impl fmt::Display for TextNormalization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TextNormalization {
+    type Err = ElevenLabsTTSError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "auto" => Ok(TextNormalization::Auto),
+            "on" => Ok(TextNormalization::On),
+            "off" => Ok(TextNormalization::Off),
+            _ => Err(ElevenLabsTTSError::ValidationError(format!(
+                "Text normalization must be one of auto, on or off, got {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// An HTTP request as it would be sent, for debugging and snapshot tests
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequestParts {
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+
+    /// Full URL, without the query string
+    pub url: String,
+
+    /// Query parameters, in the order they are sent
+    pub query: Vec<(String, String)>,
+
+    /// Headers, with the `xi-api-key` value replaced by `[REDACTED]`
+    pub headers: Vec<(String, String)>,
+
+    /// The JSON body
+    pub body: serde_json::Value,
+}
+
+impl HttpRequestParts {
+    /// Value of the given header, if present (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Generated audio that knows its format
+#[derive(Debug, Clone)]
+pub struct AudioOutput {
+    bytes: Vec<u8>,
+    format: OutputFormat,
+}
+
+impl AudioOutput {
+    pub fn new(bytes: Vec<u8>, format: OutputFormat) -> Self {
+        Self { bytes, format }
+    }
+
+    /// The format the audio was generated in
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Sample rate in Hz
+    pub fn sample_rate(&self) -> u32 {
+        self.format.sample_rate()
+    }
+
+    /// Number of audio channels
+    pub fn channels(&self) -> u16 {
+        self.format.channels()
+    }
+
+    /// Playback duration, see [`audio::duration`](crate::audio::duration)
+    pub fn duration(&self) -> Result<std::time::Duration, ElevenLabsTTSError> {
+        crate::audio::duration(&self.bytes, self.format)
+    }
+
+    /// Suggested file extension, e.g. `mp3` or `pcm`, see [`OutputFormat::extension`]
+    pub fn extension(&self) -> &'static str {
+        self.format.extension()
+    }
+
+    /// The raw audio bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Take the raw audio bytes
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Write the audio to a file as is (raw formats are written without a header)
+    pub async fn save<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), ElevenLabsTTSError> {
+        tokio::fs::write(path, &self.bytes).await?;
+        Ok(())
+    }
+
+    /// Play the audio on the default output device, blocking until it has finished
+    #[cfg(feature = "playback")]
+    pub fn play(&self) -> Result<(), ElevenLabsTTSError> {
+        crate::playback::play(&self.bytes, self.format)
+    }
+}
+
+/// Generated audio together with the metadata returned in the response headers
+#[derive(Debug, Clone)]
+pub struct AudioResponse {
+    /// Raw audio data
+    pub audio: Vec<u8>,
+
+    /// The `request-id` of this generation, usable in `previous_request_ids`/`next_request_ids`
+    pub request_id: Option<String>,
+
+    /// The history item created for this generation (absent when logging is disabled)
+    pub history_item_id: Option<String>,
+
+    /// Number of characters billed for this generation (zero when replayed from the cache)
+    pub character_cost: Option<u64>,
+
+    /// Content type of the audio, e.g. `audio/mpeg`
+    pub content_type: Option<String>,
+}
+
+impl AudioResponse {
+    pub(crate) fn from_parts(audio: Vec<u8>, headers: &HeaderMap) -> Self {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Self {
+            audio,
+            request_id: header("request-id"),
+            history_item_id: header("history-item-id"),
+            character_cost: crate::metrics::character_cost(headers),
+            content_type: header("content-type"),
+        }
+    }
+}
+
+/// Timing of each character in the generated audio
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Alignment {
+    /// The characters of the text, one per entry
+    pub characters: Vec<String>,
+
+    /// Start time of each character, in seconds
+    pub character_start_times_seconds: Vec<f64>,
+
+    /// End time of each character, in seconds
+    pub character_end_times_seconds: Vec<f64>,
+}
+
+impl Alignment {
+    /// Iterate over `(character, start, end)` triples
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64, f64)> {
+        self.characters
+            .iter()
+            .zip(&self.character_start_times_seconds)
+            .zip(&self.character_end_times_seconds)
+            .map(|((character, start), end)| (character.as_str(), *start, *end))
+    }
+
+    /// Word-level timing: each word's text without surrounding punctuation, from the start
+    /// of its first letter to the end of its last. Words are split on whitespace and dashes;
+    /// inner apostrophes, hyphens and decimal points are kept (`don't`, `e-mail`, `3.5`).
+    ///
+    /// ```rust
+    /// use elevenlabs_tts::Alignment;
+    ///
+    /// let text = "Hi, you!";
+    /// let alignment = Alignment {
+    ///     characters: text.chars().map(String::from).collect(),
+    ///     character_start_times_seconds: (0..8).map(f64::from).collect(),
+    ///     character_end_times_seconds: (1..9).map(f64::from).collect(),
+    /// };
+    /// let words = alignment.words();
+    /// assert_eq!(words[0].word, "Hi");
+    /// assert_eq!((words[0].start, words[0].end), (0.0, 2.0));
+    /// assert_eq!(words[1].word, "you");
+    /// assert_eq!((words[1].start, words[1].end), (4.0, 7.0));
+    /// ```
+    pub fn words(&self) -> Vec<WordTimestamp> {
+        self.tokens()
+            .into_iter()
+            .filter_map(|token| {
+                let first = token.chars.iter().position(|(c, ..)| is_word_char(c))?;
+                let last = token.chars.iter().rposition(|(c, ..)| is_word_char(c))?;
+                Some(WordTimestamp {
+                    word: token.chars[first..=last].iter().map(|(c, ..)| *c).collect(),
+                    start: token.chars[first].1,
+                    end: token.chars[last].2,
+                })
+            })
+            .collect()
+    }
+
+    /// Words with their punctuation attached, as displayed in captions
+    pub(crate) fn display_words(&self) -> Vec<WordTimestamp> {
+        self.tokens()
+            .into_iter()
+            .map(|token| WordTimestamp {
+                word: token.chars.iter().map(|(c, ..)| *c).collect(),
+                start: token.chars[0].1,
+                end: token.chars[token.chars.len() - 1].2,
+            })
+            .collect()
+    }
+
+    /// Runs of characters between whitespace and dashes
+    fn tokens(&self) -> Vec<Token<'_>> {
+        let mut tokens = Vec::new();
+        let mut current = Token::default();
+        for (character, start, end) in self.iter() {
+            if character.trim().is_empty() || matches!(character, "—" | "–") {
+                if !current.chars.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            current.chars.push((character, start, end));
+        }
+        if !current.chars.is_empty() {
+            tokens.push(current);
+        }
+        tokens
+    }
+}
+
+#[derive(Default)]
+struct Token<'a> {
+    chars: Vec<(&'a str, f64, f64)>,
+}
+
+fn is_word_char(character: &&str) -> bool {
+    character.chars().any(char::is_alphanumeric)
+}
+
+/// A word and when it is spoken, in seconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordTimestamp {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Generated audio with character-level timing, from `execute_with_timestamps()`
+#[derive(Debug, Clone)]
+pub struct TimestampedAudio {
+    /// Raw audio data
+    pub audio: Vec<u8>,
+
+    /// Timing of the characters of the original text
+    pub alignment: Option<Alignment>,
+
+    /// Timing of the characters after text normalization (e.g. numbers spelled out)
+    pub normalized_alignment: Option<Alignment>,
+
+    /// The `request-id` of this generation
+    pub request_id: Option<String>,
+
+    /// Number of characters billed for this generation
+    pub character_cost: Option<u64>,
+}
+
+/// Response body of the with-timestamps endpoints
+#[derive(Deserialize)]
+pub(crate) struct TimestampsResponse {
+    #[serde(deserialize_with = "deserialize_base64")]
+    pub audio_base64: Vec<u8>,
+    pub alignment: Option<Alignment>,
+    pub normalized_alignment: Option<Alignment>,
+}
+
+impl TimestampsResponse {
+    pub(crate) fn into_audio(self, headers: &HeaderMap) -> TimestampedAudio {
+        let response = AudioResponse::from_parts(self.audio_base64, headers);
+        TimestampedAudio {
+            audio: response.audio,
+            alignment: self.alignment,
+            normalized_alignment: self.normalized_alignment,
+            request_id: response.request_id,
+            character_cost: response.character_cost,
+        }
+    }
+}
+
+pub(crate) fn deserialize_base64<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<u8>, D::Error> {
+    use base64::Engine;
+
+    let encoded = String::deserialize(deserializer)?;
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
 }
 
 /// Voice settings for fine-tuning speech output
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Fields left as `None` are not sent, so the voice's stored value applies to them.
+/// [`VoiceSettings::empty`] and the `only_*` constructors override single fields.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VoiceSettings {
     /// Stability of the voice, Must be one of: 0.0, 0.5 and 1.0
     /// 0.0 : Creative, 0.5 : Natural, 1.0 : Robust
     /// Higher values make the voice more stable but less expressive
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub stability: Option<f32>,
 
     /// Similarity boost (0.0 - 1.0)
     /// Higher values make the voice more similar to the original
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub similarity_boost: Option<f32>,
 
     /// Style exaggeration (0.0 - 1.0)
@@ -104,7 +648,135 @@ impl Default for VoiceSettings {
     }
 }
 
+/// Documented stability presets for eleven_v3 voices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stability {
+    /// 0.0: most expressive and varied, may drift from the voice
+    Creative,
+    /// 0.5: balanced, closest to the original recording
+    Natural,
+    /// 1.0: most consistent, less responsive to audio tags
+    Robust,
+}
+
+impl Stability {
+    /// The stability value sent to the API
+    pub fn value(&self) -> f32 {
+        match self {
+            Stability::Creative => 0.0,
+            Stability::Natural => 0.5,
+            Stability::Robust => 1.0,
+        }
+    }
+}
+
+impl From<Stability> for f32 {
+    fn from(stability: Stability) -> Self {
+        stability.value()
+    }
+}
+
 impl VoiceSettings {
+    /// Check every value is within the range the API accepts
+    /// (0.0 - 1.0, and 0.7 - 1.2 for speed)
+    pub fn validate(&self) -> Result<(), ElevenLabsTTSError> {
+        let checks = [
+            ("stability", self.stability, 0.0, 1.0),
+            ("similarity_boost", self.similarity_boost, 0.0, 1.0),
+            ("style", self.style, 0.0, 1.0),
+            ("speed", self.speed, 0.7, 1.2),
+        ];
+        for (name, value, min, max) in checks {
+            if let Some(value) = value {
+                if !(min..=max).contains(&value) {
+                    return Err(ElevenLabsTTSError::ValidationError(format!(
+                        "{} must be between {} and {}, got {}",
+                        name, min, max, value
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Default settings with the `Creative` stability preset (0.0)
+    pub fn creative() -> Self {
+        Self::default().stability_preset(Stability::Creative)
+    }
+
+    /// Default settings with the `Natural` stability preset (0.5)
+    pub fn natural() -> Self {
+        Self::default().stability_preset(Stability::Natural)
+    }
+
+    /// Default settings with the `Robust` stability preset (1.0)
+    pub fn robust() -> Self {
+        Self::default().stability_preset(Stability::Robust)
+    }
+
+    /// Long-form reading: steady delivery with a little expression, at normal pace
+    pub fn narration() -> Self {
+        Self::new(Some(0.6), Some(0.8), Some(0.15), Some(true), Some(1.0))
+    }
+
+    /// Lively and upbeat: low stability and strong style, slightly faster
+    pub fn energetic() -> Self {
+        Self::new(Some(0.3), Some(0.75), Some(0.6), Some(true), Some(1.1))
+    }
+
+    /// Soothing and even: high stability, no style exaggeration, slightly slower
+    pub fn calm() -> Self {
+        Self::new(Some(0.8), Some(0.75), Some(0.0), Some(true), Some(0.9))
+    }
+
+    /// Newsreader: very consistent and neutral, at a brisk but clear pace
+    pub fn news() -> Self {
+        Self::new(Some(0.85), Some(0.85), Some(0.05), Some(true), Some(1.05))
+    }
+
+    /// Settings that override nothing: every field keeps the voice's stored value
+    /// until it is set
+    pub fn empty() -> Self {
+        Self {
+            stability: None,
+            similarity_boost: None,
+            style: None,
+            use_speaker_boost: None,
+            speed: None,
+        }
+    }
+
+    /// Override only the speed, keeping the voice's other stored settings
+    pub fn only_speed(speed: f32) -> Self {
+        Self::empty().speed(speed)
+    }
+
+    /// Override only the stability, keeping the voice's other stored settings
+    pub fn only_stability(stability: f32) -> Self {
+        Self::empty().stability(stability)
+    }
+
+    /// Override only the similarity boost, keeping the voice's other stored settings
+    pub fn only_similarity_boost(similarity_boost: f32) -> Self {
+        Self::empty().similarity_boost(similarity_boost)
+    }
+
+    /// Override only the style exaggeration, keeping the voice's other stored settings
+    pub fn only_style(style: f32) -> Self {
+        Self::empty().style(style)
+    }
+
+    /// Override only speaker boost, keeping the voice's other stored settings
+    pub fn only_speaker_boost(enabled: bool) -> Self {
+        Self::empty().speaker_boost(enabled)
+    }
+
+    /// Set stability from a preset
+    pub fn stability_preset(mut self, stability: Stability) -> Self {
+        self.stability = Some(stability.value());
+        self
+    }
+
     /// Create new voice settings with custom stability and similarity
     pub fn new(
         stability: Option<f32>,
@@ -164,19 +836,41 @@ pub struct StaticVoice {
     pub voice_id: &'static str,
     pub name: &'static str,
     pub gender: &'static str,
+    /// Accent of the voice, e.g. `american`, `british` (empty when unknown)
+    pub accent: &'static str,
+    /// Native language of the voice (ISO 639-1)
+    pub language: &'static str,
+    /// What the voice is best suited for, e.g. `narration`, `news` (empty when unknown)
+    pub use_case: &'static str,
 }
 
 impl StaticVoice {
     pub const fn new(voice_id: &'static str, name: &'static str, gender: &'static str) -> Self {
         Self {
-            voice_id: voice_id,
-            name: name,
-            gender: gender,
+            voice_id,
+            name,
+            gender,
+            accent: "",
+            language: "en",
+            use_case: "",
         }
     }
 
+    /// Set the accent, language and use case of a voice created with `new`
+    pub const fn with_labels(
+        mut self,
+        accent: &'static str,
+        language: &'static str,
+        use_case: &'static str,
+    ) -> Self {
+        self.accent = accent;
+        self.language = language;
+        self.use_case = use_case;
+        self
+    }
+
     /// Get the voice ID for API calls
     pub fn id(&self) -> &str {
-        &self.voice_id
+        self.voice_id
     }
 }
@@ -0,0 +1,199 @@
+//! Local accounting of the characters sent, with optional daily and monthly budgets
+//!
+//! The API only reports quota for the whole subscription. A [`UsageTracker`] counts what
+//! this process sends, per voice, model and (UTC) day, and can refuse requests once a
+//! budget is spent — a guard against loops that keep generating speech unattended.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{Datelike, NaiveDate, Utc};
+
+use crate::{ElevenLabsTTSError, cost};
+
+// Characters per (day, voice ID, model ID)
+type Counts = HashMap<(NaiveDate, String, String), u64>;
+
+/// Characters sent with one voice and model on one day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageEntry {
+    /// UTC day the requests were sent
+    pub date: NaiveDate,
+    pub voice_id: String,
+    pub model_id: String,
+    pub characters: u64,
+}
+
+/// Counts the characters of every request sent by the clients it is attached to
+///
+/// Cloning is cheap and clones share the same counts, so keep a clone to read the usage
+/// after passing the tracker to [`ClientBuilder::usage_tracker`](crate::ClientBuilder::usage_tracker).
+/// Requests that would take the day's or month's total over a budget fail with
+/// [`ElevenLabsTTSError::QuotaExceededError`] without being sent.
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::{ElevenLabsTTSClient, usage::UsageTracker};
+///
+/// # async fn example() -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let usage = UsageTracker::new().daily_budget(50_000).monthly_budget(500_000);
+/// let client = ElevenLabsTTSClient::builder()
+///     .api_key("your-api-key")
+///     .usage_tracker(usage.clone())
+///     .build()?;
+///
+/// client.text_to_speech("Hello!").execute().await?;
+/// println!("{} characters sent today", usage.characters_today());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UsageTracker {
+    counts: Arc<Mutex<Counts>>,
+    daily_budget: Option<u64>,
+    monthly_budget: Option<u64>,
+}
+
+impl UsageTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refuse requests once this many characters were sent during the current UTC day
+    pub fn daily_budget(mut self, characters: u64) -> Self {
+        self.daily_budget = Some(characters);
+        self
+    }
+
+    /// Refuse requests once this many characters were sent during the current UTC month
+    pub fn monthly_budget(mut self, characters: u64) -> Self {
+        self.monthly_budget = Some(characters);
+        self
+    }
+
+    fn counts(&self) -> MutexGuard<'_, Counts> {
+        self.counts.lock().unwrap()
+    }
+
+    /// Add characters sent outside the tracked clients (e.g. over a WebSocket session)
+    pub fn record(&self, voice_id: &str, model_id: &str, characters: u64) {
+        let mut counts = self.counts();
+        Self::add(&mut counts, today(), voice_id, model_id, characters);
+    }
+
+    /// Check that a request of `characters` fits in the remaining budgets, without recording it
+    pub fn check(&self, characters: u64) -> Result<(), ElevenLabsTTSError> {
+        let counts = self.counts();
+        self.check_budgets(&counts, today(), characters)
+    }
+
+    /// Check the budgets and record the request's `(voice_id, model_id, text)` parts in
+    /// one step, so concurrent requests can't overshoot a budget together
+    pub(crate) fn charge(&self, parts: &[(&str, &str, &str)]) -> Result<(), ElevenLabsTTSError> {
+        let today = today();
+        let mut counts = self.counts();
+        let characters = parts
+            .iter()
+            .map(|(_, _, text)| cost::estimate_characters(text))
+            .sum();
+        self.check_budgets(&counts, today, characters)?;
+        for (voice_id, model_id, text) in parts {
+            let characters = cost::estimate_characters(text);
+            Self::add(&mut counts, today, voice_id, model_id, characters);
+        }
+        Ok(())
+    }
+
+    fn add(counts: &mut Counts, date: NaiveDate, voice_id: &str, model_id: &str, characters: u64) {
+        *counts
+            .entry((date, voice_id.to_string(), model_id.to_string()))
+            .or_default() += characters;
+    }
+
+    fn check_budgets(
+        &self,
+        counts: &Counts,
+        today: NaiveDate,
+        characters: u64,
+    ) -> Result<(), ElevenLabsTTSError> {
+        let budgets = [
+            (
+                "Daily",
+                self.daily_budget,
+                sum(counts, |date| date == today),
+            ),
+            (
+                "Monthly",
+                self.monthly_budget,
+                sum(counts, |date| same_month(date, today)),
+            ),
+        ];
+        for (period, budget, used) in budgets {
+            let Some(budget) = budget else {
+                continue;
+            };
+            if used.saturating_add(characters) > budget {
+                return Err(ElevenLabsTTSError::QuotaExceededError {
+                    used: Some(used),
+                    limit: Some(budget),
+                    message: format!(
+                        "{} character budget doesn't leave room for a request of {} characters",
+                        period, characters
+                    ),
+                    request_id: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Characters sent during the current UTC day
+    pub fn characters_today(&self) -> u64 {
+        let today = today();
+        sum(&self.counts(), |date| date == today)
+    }
+
+    /// Characters sent during the current UTC month
+    pub fn characters_this_month(&self) -> u64 {
+        let today = today();
+        sum(&self.counts(), |date| same_month(date, today))
+    }
+
+    /// Every count, ordered by day, voice and model
+    pub fn entries(&self) -> Vec<UsageEntry> {
+        let counts = self.counts();
+        let mut entries: Vec<UsageEntry> = counts
+            .iter()
+            .map(|((date, voice_id, model_id), characters)| UsageEntry {
+                date: *date,
+                voice_id: voice_id.clone(),
+                model_id: model_id.clone(),
+                characters: *characters,
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            (a.date, &a.voice_id, &a.model_id).cmp(&(b.date, &b.voice_id, &b.model_id))
+        });
+        entries
+    }
+
+    /// Forget every count
+    pub fn reset(&self) {
+        self.counts().clear();
+    }
+}
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+fn same_month(date: NaiveDate, other: NaiveDate) -> bool {
+    date.year() == other.year() && date.month() == other.month()
+}
+
+fn sum<F: Fn(NaiveDate) -> bool>(counts: &Counts, include: F) -> u64 {
+    counts
+        .iter()
+        .filter(|((date, _, _), _)| include(*date))
+        .map(|(_, characters)| characters)
+        .sum()
+}
@@ -0,0 +1,286 @@
+//! Designing voices from text descriptions
+//!
+//! [`VoiceRemixBuilder`] asks for variations of an existing voice ("make her sound older,
+//! with a slight rasp") and returns short previews. Listen to them, remix again with a
+//! refined description, and save the preview you like with
+//! [`ElevenLabsTTSClient::create_voice_from_preview`].
+
+use serde::{Deserialize, Serialize};
+
+use crate::ids;
+use crate::types::deserialize_base64;
+use crate::{ElevenLabsTTSClient, ElevenLabsTTSError, OutputFormat, RequestOptions};
+
+/// Shortest description of the changes the remix endpoint accepts, in characters
+pub const MIN_REMIX_DESCRIPTION_LENGTH: usize = 5;
+
+/// Longest description of the changes the remix endpoint accepts, in characters
+pub const MAX_REMIX_DESCRIPTION_LENGTH: usize = 1000;
+
+/// Shortest preview text the voice design endpoints accept, in characters
+pub const MIN_PREVIEW_TEXT_LENGTH: usize = 100;
+
+/// Longest preview text the voice design endpoints accept, in characters
+pub const MAX_PREVIEW_TEXT_LENGTH: usize = 1000;
+
+/// Request body for the voice remix endpoint
+#[derive(Debug, Clone, Serialize)]
+pub struct VoiceRemixRequest {
+    // Description of the changes to make to the voice, e.g. "deeper, with a British accent".
+    pub voice_description: String,
+
+    // Text the previews speak, between 100 and 1000 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    // If true, a text suited to the description is generated for the previews.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_generate_text: Option<bool>,
+
+    // Volume of the previews, from -1 (quietest) to 1 (loudest). Default: 0.5
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loudness: Option<f32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u32>,
+
+    // How closely the previews follow the description; higher values can sound less natural.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guidance_scale: Option<f32>,
+
+    // How much the description outweighs the original voice, from 0 to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_strength: Option<f32>,
+
+    // Continue an earlier remix session instead of starting from the original voice.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remixing_session_id: Option<String>,
+
+    // The iteration of the session to continue from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remixing_session_iteration_id: Option<String>,
+
+    // Output format of the previews, sent as a query parameter.
+    #[serde(skip_serializing)]
+    pub output_format: Option<OutputFormat>,
+}
+
+/// A generated preview of a designed or remixed voice
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct VoicePreview {
+    /// The preview audio, decoded from the response
+    #[serde(rename = "audio_base_64", deserialize_with = "deserialize_base64")]
+    pub audio: Vec<u8>,
+
+    /// Pass to [`ElevenLabsTTSClient::create_voice_from_preview`] to keep this voice
+    pub generated_voice_id: String,
+
+    /// MIME type of the audio, e.g. `audio/mpeg`
+    #[serde(default)]
+    pub media_type: Option<String>,
+
+    #[serde(default)]
+    pub duration_secs: Option<f64>,
+
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+/// Previews returned by a voice remix
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct VoicePreviews {
+    pub previews: Vec<VoicePreview>,
+
+    /// The text the previews speak
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+/// Builder for a voice remix: variations of an existing voice following a description
+///
+/// ```rust,no_run
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let remixed = client
+///     .remix_voice("21m00Tcm4TlvDq8ikWAM", "Older and raspier, speaking slowly")
+///     .auto_generate_text(true)
+///     .execute()
+///     .await?;
+/// for (i, preview) in remixed.previews.iter().enumerate() {
+///     std::fs::write(format!("preview-{}.mp3", i), &preview.audio)?;
+/// }
+///
+/// let voice = client
+///     .create_voice_from_preview(
+///         "Rachel, older",
+///         "Older and raspier, speaking slowly",
+///         &remixed.previews[0].generated_voice_id,
+///     )
+///     .await?;
+/// println!("Saved as {}", voice.voice_id);
+/// # Ok(())
+/// # }
+/// ```
+pub struct VoiceRemixBuilder {
+    client: ElevenLabsTTSClient,
+    voice_id: String,
+    request: VoiceRemixRequest,
+    options: RequestOptions,
+}
+
+impl VoiceRemixBuilder {
+    pub(crate) fn new(client: ElevenLabsTTSClient, voice_id: String, description: String) -> Self {
+        Self {
+            client,
+            voice_id,
+            request: VoiceRemixRequest {
+                voice_description: description,
+                text: None,
+                auto_generate_text: None,
+                loudness: None,
+                seed: None,
+                guidance_scale: None,
+                prompt_strength: None,
+                remixing_session_id: None,
+                remixing_session_iteration_id: None,
+                output_format: None,
+            },
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Text the previews speak (100 - 1000 characters)
+    pub fn text<S: Into<String>>(mut self, text: S) -> Self {
+        self.request.text = Some(text.into());
+        self
+    }
+
+    /// Generate a text suited to the description instead of providing one
+    pub fn auto_generate_text(mut self, auto_generate_text: bool) -> Self {
+        self.request.auto_generate_text = Some(auto_generate_text);
+        self
+    }
+
+    /// Volume of the previews (-1.0 - 1.0)
+    pub fn loudness(mut self, loudness: f32) -> Self {
+        self.request.loudness = Some(loudness);
+        self
+    }
+
+    /// Seed for reproducible previews
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.request.seed = Some(seed);
+        self
+    }
+
+    /// How closely the previews follow the description
+    pub fn guidance_scale(mut self, guidance_scale: f32) -> Self {
+        self.request.guidance_scale = Some(guidance_scale);
+        self
+    }
+
+    /// How much the description outweighs the original voice (0.0 - 1.0)
+    pub fn prompt_strength(mut self, prompt_strength: f32) -> Self {
+        self.request.prompt_strength = Some(prompt_strength);
+        self
+    }
+
+    /// Continue from an iteration of an earlier remix session
+    pub fn session<S: Into<String>, I: Into<String>>(
+        mut self,
+        session_id: S,
+        iteration_id: I,
+    ) -> Self {
+        self.request.remixing_session_id = Some(session_id.into());
+        self.request.remixing_session_iteration_id = Some(iteration_id.into());
+        self
+    }
+
+    /// Set the output format of the previews
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.request.output_format = Some(output_format);
+        self
+    }
+
+    /// Add a header to this request (e.g. a correlation ID), overriding client defaults
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, name: K, value: V) -> Self {
+        self.options.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Build the request body without sending it
+    pub fn build_request(&self) -> Result<VoiceRemixRequest, ElevenLabsTTSError> {
+        ids::check_not_voice_name(&self.voice_id)?;
+        let request = &self.request;
+        let length = request.voice_description.trim().chars().count();
+        if !(MIN_REMIX_DESCRIPTION_LENGTH..=MAX_REMIX_DESCRIPTION_LENGTH).contains(&length) {
+            return Err(ElevenLabsTTSError::ValidationError(format!(
+                "Voice description must be between {} and {} characters, got {}",
+                MIN_REMIX_DESCRIPTION_LENGTH, MAX_REMIX_DESCRIPTION_LENGTH, length
+            )));
+        }
+        match (&request.text, request.auto_generate_text) {
+            (Some(_), Some(true)) => {
+                return Err(ElevenLabsTTSError::ValidationError(
+                    "Set either a preview text or auto_generate_text, not both".to_string(),
+                ));
+            }
+            (Some(text), _) => {
+                let length = text.chars().count();
+                if !(MIN_PREVIEW_TEXT_LENGTH..=MAX_PREVIEW_TEXT_LENGTH).contains(&length) {
+                    return Err(ElevenLabsTTSError::ValidationError(format!(
+                        "Preview text must be between {} and {} characters, got {}",
+                        MIN_PREVIEW_TEXT_LENGTH, MAX_PREVIEW_TEXT_LENGTH, length
+                    )));
+                }
+            }
+            (None, Some(true)) => {}
+            (None, _) => {
+                return Err(ElevenLabsTTSError::ValidationError(
+                    "Voice remix needs a preview text or auto_generate_text(true)".to_string(),
+                ));
+            }
+        }
+        if let Some(loudness) = request.loudness {
+            if !(-1.0..=1.0).contains(&loudness) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Loudness must be between -1.0 and 1.0, got {}",
+                    loudness
+                )));
+            }
+        }
+        if let Some(prompt_strength) = request.prompt_strength {
+            if !(0.0..=1.0).contains(&prompt_strength) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Prompt strength must be between 0.0 and 1.0, got {}",
+                    prompt_strength
+                )));
+            }
+        }
+        Ok(request.clone())
+    }
+
+    /// Generate the previews
+    pub async fn execute(self) -> Result<VoicePreviews, ElevenLabsTTSError> {
+        let request = self.build_request()?;
+        let url = format!(
+            "{}/text-to-voice/{}/remix",
+            self.client.inner.base_url, self.voice_id
+        );
+        let query: Vec<(&str, String)> = request
+            .output_format
+            .map(|format| ("output_format", format.to_string()))
+            .into_iter()
+            .collect();
+        self.client
+            .post_for_json("text-to-voice-remix", &url, &query, &request, &self.options)
+            .await
+    }
+}
+
+/// Request body for saving a generated voice
+#[derive(Serialize)]
+pub(crate) struct CreateVoiceRequest<'a> {
+    pub(crate) voice_name: &'a str,
+    pub(crate) voice_description: &'a str,
+    pub(crate) generated_voice_id: &'a str,
+}
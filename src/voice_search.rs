@@ -0,0 +1,353 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use futures_util::stream::BoxStream;
+use serde::Deserialize;
+
+use crate::{AudioResponse, ElevenLabsTTSClient, ElevenLabsTTSError, paginate};
+
+/// Largest page the voices search endpoint returns
+pub const MAX_VOICE_PAGE_SIZE: u32 = 100;
+
+/// A voice available to the account (pre-made, cloned, generated or from the library)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Voice {
+    pub voice_id: String,
+    pub name: String,
+
+    /// `premade`, `cloned`, `generated` or `professional`
+    #[serde(default)]
+    pub category: Option<String>,
+
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Free-form labels such as `accent`, `gender`, `age` or `use_case`
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+
+    /// URL of a short sample of the voice
+    #[serde(default)]
+    pub preview_url: Option<String>,
+
+    #[serde(default)]
+    pub created_at_unix: Option<i64>,
+}
+
+/// One page of voice search results
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct VoicesPage {
+    pub voices: Vec<Voice>,
+
+    /// Whether more voices follow this page
+    #[serde(default)]
+    pub has_more: bool,
+
+    /// Number of voices matching the search, across all pages
+    #[serde(default)]
+    pub total_count: Option<u64>,
+
+    /// Pass to [`VoiceSearch::page_token`] to get the next page
+    #[serde(default)]
+    pub next_page_token: Option<String>,
+}
+
+/// How a voice was created
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoiceCategory {
+    Premade,
+    Cloned,
+    Generated,
+    Professional,
+}
+
+impl VoiceCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoiceCategory::Premade => "premade",
+            VoiceCategory::Cloned => "cloned",
+            VoiceCategory::Generated => "generated",
+            VoiceCategory::Professional => "professional",
+        }
+    }
+}
+
+impl fmt::Display for VoiceCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Which voices to search, relative to the account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoiceType {
+    /// Voices created by the account
+    Personal,
+    /// Voices added from the community library
+    Community,
+    /// The default pre-made voices
+    Default,
+    /// Voices shared within the workspace
+    Workspace,
+    /// Every voice except the default ones
+    NonDefault,
+}
+
+impl VoiceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoiceType::Personal => "personal",
+            VoiceType::Community => "community",
+            VoiceType::Default => "default",
+            VoiceType::Workspace => "workspace",
+            VoiceType::NonDefault => "non-default",
+        }
+    }
+}
+
+impl fmt::Display for VoiceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Order of voice search results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoiceSort {
+    /// Newest first, unless sorted ascending
+    CreatedAt,
+    /// Alphabetical, unless sorted descending
+    Name,
+}
+
+impl VoiceSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoiceSort::CreatedAt => "created_at_unix",
+            VoiceSort::Name => "name",
+        }
+    }
+}
+
+/// Builder for a search of the account's voices (`GET /v2/voices`)
+///
+/// ```rust,no_run
+/// use elevenlabs_tts::voice_search::{VoiceCategory, VoiceSort};
+///
+/// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+/// let page = client
+///     .search_voices()
+///     .search("narrator")
+///     .category(VoiceCategory::Cloned)
+///     .sort(VoiceSort::Name)
+///     .page_size(50)
+///     .execute()
+///     .await?;
+/// for voice in &page.voices {
+///     println!("{} ({})", voice.name, voice.voice_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct VoiceSearch {
+    client: ElevenLabsTTSClient,
+    search: Option<String>,
+    category: Option<VoiceCategory>,
+    voice_type: Option<VoiceType>,
+    sort: Option<VoiceSort>,
+    descending: Option<bool>,
+    page_size: Option<u32>,
+    page_token: Option<String>,
+    include_total_count: Option<bool>,
+}
+
+impl VoiceSearch {
+    pub(crate) fn new(client: ElevenLabsTTSClient) -> Self {
+        Self {
+            client,
+            search: None,
+            category: None,
+            voice_type: None,
+            sort: None,
+            descending: None,
+            page_size: None,
+            page_token: None,
+            include_total_count: None,
+        }
+    }
+
+    /// Match voices by name, description, labels or category
+    pub fn search<S: Into<String>>(mut self, search: S) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    /// Only voices of this category
+    pub fn category(mut self, category: VoiceCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Only voices of this type
+    pub fn voice_type(mut self, voice_type: VoiceType) -> Self {
+        self.voice_type = Some(voice_type);
+        self
+    }
+
+    /// Order the results
+    pub fn sort(mut self, sort: VoiceSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Sort descending (`true`) or ascending (`false`)
+    pub fn descending(mut self, descending: bool) -> Self {
+        self.descending = Some(descending);
+        self
+    }
+
+    /// Voices per page (1 - 100, the API defaults to 10)
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Continue from a previous page's [`VoicesPage::next_page_token`]
+    pub fn page_token<S: Into<String>>(mut self, page_token: S) -> Self {
+        self.page_token = Some(page_token.into());
+        self
+    }
+
+    /// Ask for [`VoicesPage::total_count`] (slower on large inventories)
+    pub fn include_total_count(mut self, include: bool) -> Self {
+        self.include_total_count = Some(include);
+        self
+    }
+
+    /// The query parameters of the search
+    pub fn query(&self) -> Result<Vec<(&'static str, String)>, ElevenLabsTTSError> {
+        if let Some(page_size) = self.page_size {
+            if !(1..=MAX_VOICE_PAGE_SIZE).contains(&page_size) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Page size must be between 1 and {}, got {}",
+                    MAX_VOICE_PAGE_SIZE, page_size
+                )));
+            }
+        }
+
+        let mut query = Vec::new();
+        if let Some(search) = &self.search {
+            query.push(("search", search.clone()));
+        }
+        if let Some(category) = self.category {
+            query.push(("category", category.to_string()));
+        }
+        if let Some(voice_type) = self.voice_type {
+            query.push(("voice_type", voice_type.to_string()));
+        }
+        if let Some(sort) = self.sort {
+            query.push(("sort", sort.as_str().to_string()));
+        }
+        if let Some(descending) = self.descending {
+            let direction = if descending { "desc" } else { "asc" };
+            query.push(("sort_direction", direction.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        if let Some(page_token) = &self.page_token {
+            query.push(("next_page_token", page_token.clone()));
+        }
+        if let Some(include) = self.include_total_count {
+            query.push(("include_total_count", include.to_string()));
+        }
+        Ok(query)
+    }
+
+    /// Fetch one page of matching voices
+    pub async fn execute(&self) -> Result<VoicesPage, ElevenLabsTTSError> {
+        let query = self.query()?;
+        let url = self.client.v2_url("/voices");
+        self.client.get_json("voices-search", &url, &query).await
+    }
+
+    /// Every matching voice, fetching the following pages as the stream is consumed.
+    /// The stream ends after the first error.
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// let mut voices = client.search_voices().page_size(100).list_all();
+    /// while let Some(voice) = voices.try_next().await? {
+    ///     println!("{}", voice.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(self) -> BoxStream<'static, Result<Voice, ElevenLabsTTSError>> {
+        paginate(self, |search| async move {
+            let page = search.execute().await?;
+            let next = page
+                .next_page_token
+                .filter(|_| page.has_more)
+                .map(|token| search.page_token(token));
+            Ok((page.voices, next))
+        })
+    }
+}
+
+/// Voice endpoints: looking up voices and downloading their previews
+pub struct Voices {
+    client: ElevenLabsTTSClient,
+}
+
+impl Voices {
+    pub(crate) fn new(client: ElevenLabsTTSClient) -> Self {
+        Self { client }
+    }
+
+    /// Start a search of the account's voices, like
+    /// [`ElevenLabsTTSClient::search_voices`]
+    pub fn search(&self) -> VoiceSearch {
+        VoiceSearch::new(self.client.clone())
+    }
+
+    /// Get a voice by ID
+    pub async fn get<S: AsRef<str>>(&self, voice_id: S) -> Result<Voice, ElevenLabsTTSError> {
+        let voice_id = voice_id.as_ref();
+        crate::ids::check_not_voice_name(voice_id)?;
+        let url = format!("{}/voices/{}", self.client.inner.base_url, voice_id);
+        self.client.get_json("voices-get", &url, &[]).await
+    }
+
+    /// Download the preview clip of a voice, resolving its preview URL first
+    ///
+    /// ```rust,no_run
+    /// # async fn example(client: elevenlabs_tts::ElevenLabsTTSClient) -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+    /// let preview = client.voices().preview("21m00Tcm4TlvDq8ikWAM").await?;
+    /// std::fs::write("rachel-preview.mp3", &preview.audio)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview<S: AsRef<str>>(
+        &self,
+        voice_id: S,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let voice = self.get(voice_id).await?;
+        self.download_preview(&voice).await
+    }
+
+    /// Download the preview clip of a voice already fetched, e.g. from a search, without
+    /// looking it up again. The clip is hosted outside the API, so the API key isn't sent.
+    pub async fn download_preview(
+        &self,
+        voice: &Voice,
+    ) -> Result<AudioResponse, ElevenLabsTTSError> {
+        let url = voice.preview_url.as_deref().ok_or_else(|| {
+            ElevenLabsTTSError::ValidationError(format!("Voice {} has no preview", voice.voice_id))
+        })?;
+        self.client.download("voices-preview", url).await
+    }
+}
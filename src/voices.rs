@@ -1,5 +1,132 @@
 use crate::types::StaticVoice;
 
+/// Every pre-made voice in [`all_voices`]
+static ALL_VOICES: [&StaticVoice; 55] = [
+    &all_voices::WILL,
+    &all_voices::THOMAS,
+    &all_voices::CHARLIE,
+    &all_voices::GEORGE,
+    &all_voices::CALLUM,
+    &all_voices::LIAM,
+    &all_voices::CHARLOTTE,
+    &all_voices::ALICE,
+    &all_voices::MATILDA,
+    &all_voices::RACHEL,
+    &all_voices::DOMI,
+    &all_voices::BELLA,
+    &all_voices::ANTONI,
+    &all_voices::ELLI,
+    &all_voices::JOSH,
+    &all_voices::ARNOLD,
+    &all_voices::ADAM,
+    &all_voices::SAM,
+    &all_voices::SERENA,
+    &all_voices::ROGER,
+    &all_voices::RIVER,
+    &all_voices::PAUL,
+    &all_voices::PATRICK,
+    &all_voices::NICOLE,
+    &all_voices::MIMI,
+    &all_voices::MICHAEL,
+    &all_voices::MARK,
+    &all_voices::LILY,
+    &all_voices::LAURA,
+    &all_voices::JOSEPH,
+    &all_voices::JESSIE,
+    &all_voices::JESSICA,
+    &all_voices::JEREMY,
+    &all_voices::JAMES,
+    &all_voices::IVANA,
+    &all_voices::HARRY,
+    &all_voices::GRACE,
+    &all_voices::GLINDA,
+    &all_voices::GIOVANNI,
+    &all_voices::GIGI,
+    &all_voices::FREYA,
+    &all_voices::FIN,
+    &all_voices::ETHAN,
+    &all_voices::ERIC,
+    &all_voices::EMILY,
+    &all_voices::DREW,
+    &all_voices::DOROTHY,
+    &all_voices::DAVE,
+    &all_voices::DANIEL,
+    &all_voices::CLYDE,
+    &all_voices::CHRIS,
+    &all_voices::CASSIDY,
+    &all_voices::BRIAN,
+    &all_voices::BILL,
+    &all_voices::ARIA,
+];
+
+/// All pre-made voices, in catalog order
+pub fn all() -> &'static [&'static StaticVoice] {
+    &ALL_VOICES
+}
+
+/// Pre-made voices of the given gender (`male`, `female` or `neutral`)
+pub fn by_gender(gender: &str) -> impl Iterator<Item = &'static StaticVoice> + '_ {
+    ALL_VOICES
+        .iter()
+        .copied()
+        .filter(move |voice| voice.gender.eq_ignore_ascii_case(gender))
+}
+
+/// Pre-made voices with the given accent, e.g. `american`, `british`, `australian`
+pub fn by_accent(accent: &str) -> impl Iterator<Item = &'static StaticVoice> + '_ {
+    ALL_VOICES
+        .iter()
+        .copied()
+        .filter(move |voice| voice.accent.eq_ignore_ascii_case(accent))
+}
+
+/// Pre-made voices whose native language is the given ISO 639-1 code
+pub fn by_language(language: &str) -> impl Iterator<Item = &'static StaticVoice> + '_ {
+    ALL_VOICES
+        .iter()
+        .copied()
+        .filter(move |voice| voice.language.eq_ignore_ascii_case(language))
+}
+
+/// Pre-made voices suited to the given use case, e.g. `narration`, `news`, `conversational`
+pub fn by_use_case(use_case: &str) -> impl Iterator<Item = &'static StaticVoice> + '_ {
+    ALL_VOICES
+        .iter()
+        .copied()
+        .filter(move |voice| voice.use_case.eq_ignore_ascii_case(use_case))
+}
+
+/// Distinct use cases in the catalog, in first-seen order, for grouping voices in a UI
+pub fn use_cases() -> Vec<&'static str> {
+    let mut use_cases: Vec<&'static str> = Vec::new();
+    for voice in ALL_VOICES {
+        if !use_cases.contains(&voice.use_case) {
+            use_cases.push(voice.use_case);
+        }
+    }
+    use_cases
+}
+
+/// Distinct accents in the catalog, in first-seen order
+pub fn accents() -> Vec<&'static str> {
+    let mut accents: Vec<&'static str> = Vec::new();
+    for voice in ALL_VOICES {
+        if !accents.contains(&voice.accent) {
+            accents.push(voice.accent);
+        }
+    }
+    accents
+}
+
+/// Find a pre-made voice by name (case-insensitive), e.g. to resolve a user-facing voice name to its ID
+pub fn find_by_name(name: &str) -> Option<&'static StaticVoice> {
+    let name = name.trim();
+    ALL_VOICES
+        .iter()
+        .copied()
+        .find(|voice| voice.name.eq_ignore_ascii_case(name))
+}
+
 /// Elevanlabs common voice IDs as constants
 pub mod all_voices {
     use super::StaticVoice;
@@ -9,333 +136,499 @@ pub mod all_voices {
         voice_id: "bIHbv24MWmeRgasZH58o",
         name: "Will",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "social_media",
     };
 
     pub static THOMAS: StaticVoice = StaticVoice {
         voice_id: "GBv7mTt0atIp3Br8iCZE",
         name: "Thomas",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "meditation",
     };
 
     pub static CHARLIE: StaticVoice = StaticVoice {
         voice_id: "IKne3meq5aSn9XLyUdCD",
         name: "Charlie",
         gender: "male",
+        accent: "australian",
+        language: "en",
+        use_case: "conversational",
     };
 
     pub static GEORGE: StaticVoice = StaticVoice {
         voice_id: "JBFqnCBsd6RMkjVDRZzb",
         name: "George",
         gender: "male",
+        accent: "british",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static CALLUM: StaticVoice = StaticVoice {
         voice_id: "N2lVS1w4EtoT3dr4eOWO",
         name: "Callum",
         gender: "male",
+        accent: "transatlantic",
+        language: "en",
+        use_case: "characters",
     };
 
     pub static LIAM: StaticVoice = StaticVoice {
         voice_id: "TX3LPaxmHKxFdv7VOQHJ",
         name: "Liam",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static CHARLOTTE: StaticVoice = StaticVoice {
         voice_id: "XB0fDUnXU5powFXDhCwa",
         name: "Charlotte",
         gender: "female",
+        accent: "swedish",
+        language: "en",
+        use_case: "characters",
     };
 
     pub static ALICE: StaticVoice = StaticVoice {
         voice_id: "Xb7hH8MSUJpSbSDYk0k2",
         name: "Alice",
         gender: "female",
+        accent: "british",
+        language: "en",
+        use_case: "news",
     };
 
     pub static MATILDA: StaticVoice = StaticVoice {
         voice_id: "XrExE9yKIg1WjnnlVkGX",
         name: "Matilda",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static RACHEL: StaticVoice = StaticVoice {
         voice_id: "21m00Tcm4TlvDq8ikWAM",
         name: "Rachel",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static DOMI: StaticVoice = StaticVoice {
         voice_id: "AZnzlk1XvdvUeBnXmlld",
         name: "Domi",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static BELLA: StaticVoice = StaticVoice {
         voice_id: "EXAVITQu4vr4xnSDxMaL",
         name: "Bella",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static ANTONI: StaticVoice = StaticVoice {
         voice_id: "ErXwobaYiN019PkySvjV",
         name: "Antoni",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static ELLI: StaticVoice = StaticVoice {
         voice_id: "MF3mGyEYCl7XYWbV9V6O",
         name: "Elli",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static JOSH: StaticVoice = StaticVoice {
         voice_id: "TxGEqnHWrfWFTfGW9XjX",
         name: "Josh",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static ARNOLD: StaticVoice = StaticVoice {
         voice_id: "VR6AewLTigWG4xSOukaG",
         name: "Arnold",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static ADAM: StaticVoice = StaticVoice {
         voice_id: "pNInz6obpgDQGcFmaJgB",
         name: "Adam",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static SAM: StaticVoice = StaticVoice {
         voice_id: "yoZ06aMxZJJ28mfd3POQ",
         name: "Sam",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static SERENA: StaticVoice = StaticVoice {
         voice_id: "pMsXgVXv3BLzUgSXRplE",
         name: "Serena",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "interactive",
     };
 
     pub static ROGER: StaticVoice = StaticVoice {
         voice_id: "CwhRBWXzGAHq8TQ4Fs17",
         name: "Roger",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "social_media",
     };
 
     pub static RIVER: StaticVoice = StaticVoice {
         voice_id: "SAz9YHcvj6GT2YYXdXww",
         name: "River",
         gender: "neutral",
+        accent: "american",
+        language: "en",
+        use_case: "social_media",
     };
 
     pub static PAUL: StaticVoice = StaticVoice {
         voice_id: "5Q0t7uMcjvnagumLfvZi",
         name: "Paul",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "news",
     };
 
     pub static PATRICK: StaticVoice = StaticVoice {
         voice_id: "ODq5zmih8GrVes37Dizd",
         name: "Patrick",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "characters",
     };
 
     pub static NICOLE: StaticVoice = StaticVoice {
         voice_id: "piTKgcLEGmPE4e6mEKli",
         name: "Nicole",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "audiobook",
     };
 
     pub static MIMI: StaticVoice = StaticVoice {
         voice_id: "zrHiDhphv9ZnVXBqCLjz",
         name: "Mimi",
         gender: "female",
+        accent: "swedish",
+        language: "en",
+        use_case: "animation",
     };
 
     pub static MICHAEL: StaticVoice = StaticVoice {
         voice_id: "flq6f7yk4E4fJM5XTYuZ",
         name: "Michael",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "audiobook",
     };
 
     pub static MARK: StaticVoice = StaticVoice {
         voice_id: "UgBBYS2sOqTuMpoF3BR0",
         name: "Mark",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "conversational",
     };
 
     pub static LILY: StaticVoice = StaticVoice {
         voice_id: "pFZP5JQG7iQjIQuC4Bku",
         name: "Lily",
         gender: "female",
+        accent: "british",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static LAURA: StaticVoice = StaticVoice {
         voice_id: "FGY2WhTYpPnrIDTdsKH5",
         name: "Laura",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "social_media",
     };
 
     pub static JOSEPH: StaticVoice = StaticVoice {
         voice_id: "Zlb1dXrM653N07WRdFW3",
         name: "Joseph",
         gender: "male",
+        accent: "british",
+        language: "en",
+        use_case: "news",
     };
 
     pub static JESSIE: StaticVoice = StaticVoice {
         voice_id: "t0jbNlBVZ17f02VDIeMI",
         name: "Jessie",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "characters",
     };
 
     pub static JESSICA: StaticVoice = StaticVoice {
         voice_id: "cgSgspJ2msm6clMCkdW9",
         name: "Jessica",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "conversational",
     };
 
     pub static JEREMY: StaticVoice = StaticVoice {
         voice_id: "bVMeCyTHy58xNoL34h3p",
         name: "Jeremy",
         gender: "male",
+        accent: "irish",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static JAMES: StaticVoice = StaticVoice {
         voice_id: "ZQe5CZNOzWyzPSCn5a3c",
         name: "James",
         gender: "male",
+        accent: "australian",
+        language: "en",
+        use_case: "news",
     };
 
     pub static IVANA: StaticVoice = StaticVoice {
         voice_id: "4NejU5DwQjevnR6mh3mb",
         name: "Ivanna",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "conversational",
     };
 
     pub static HARRY: StaticVoice = StaticVoice {
         voice_id: "SOYHLrjzK2X1ezoPC6cr",
         name: "Harry",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "characters",
     };
 
     pub static GRACE: StaticVoice = StaticVoice {
         voice_id: "oWAxZDx7w5VEj9dCyTzz",
         name: "Grace",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "audiobook",
     };
 
     pub static GLINDA: StaticVoice = StaticVoice {
         voice_id: "z9fAnlkpzviPz146aGWa",
         name: "Glinda",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "video_games",
     };
 
     pub static GIOVANNI: StaticVoice = StaticVoice {
         voice_id: "zcAOhNBS3c14rBihAFp1",
         name: "Giovanni",
         gender: "male",
+        accent: "italian",
+        language: "en",
+        use_case: "audiobook",
     };
 
     pub static GIGI: StaticVoice = StaticVoice {
         voice_id: "jBpfuIE2acCO8z3wKNLl",
         name: "Gigi",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "animation",
     };
 
     pub static FREYA: StaticVoice = StaticVoice {
         voice_id: "jsCqWAovK2LkecY7zXl4",
         name: "Freya",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "characters",
     };
 
     pub static FIN: StaticVoice = StaticVoice {
         voice_id: "D38z5RcWu1voky8WS1ja",
         name: "Fin",
         gender: "male",
+        accent: "irish",
+        language: "en",
+        use_case: "video_games",
     };
 
     pub static ETHAN: StaticVoice = StaticVoice {
         voice_id: "g5CIjZEefAph4nQFvHAz",
         name: "Ethan",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "asmr",
     };
 
     pub static ERIC: StaticVoice = StaticVoice {
         voice_id: "cjVigY5qzO86Huf0OWal",
         name: "Eric",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "conversational",
     };
 
     pub static EMILY: StaticVoice = StaticVoice {
         voice_id: "LcfcDJNUP1GQjkzn1xUU",
         name: "Emily",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "meditation",
     };
 
     pub static DREW: StaticVoice = StaticVoice {
         voice_id: "29vD33N1CtxCmqQRPOHJ",
         name: "Drew",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "news",
     };
 
     pub static DOROTHY: StaticVoice = StaticVoice {
         voice_id: "ThT5KcBeYPX3keUQqHPh",
         name: "Dorothy",
         gender: "female",
+        accent: "british",
+        language: "en",
+        use_case: "children_stories",
     };
 
     pub static DAVE: StaticVoice = StaticVoice {
         voice_id: "CYw3kZ02Hs0563khs1Fj",
         name: "Dave",
         gender: "male",
+        accent: "british",
+        language: "en",
+        use_case: "video_games",
     };
 
     pub static DANIEL: StaticVoice = StaticVoice {
         voice_id: "onwK4e9ZLuTAKqWW03F9",
         name: "Daniel",
         gender: "male",
+        accent: "british",
+        language: "en",
+        use_case: "news",
     };
 
     pub static CLYDE: StaticVoice = StaticVoice {
         voice_id: "2EiwWnXFnvU5JabPnv8n",
         name: "Clyde",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "video_games",
     };
 
     pub static CHRIS: StaticVoice = StaticVoice {
         voice_id: "iP95p4xoKVk53GoZ742B",
         name: "Chris",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "conversational",
     };
 
     pub static CASSIDY: StaticVoice = StaticVoice {
         voice_id: "56AoDkrOh6qfVPDXZ7Pt",
         name: "Cassidy",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "podcast",
     };
 
     pub static BRIAN: StaticVoice = StaticVoice {
         voice_id: "nPczCjzI2devNBz1zQrb",
         name: "Brian",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static BILL: StaticVoice = StaticVoice {
         voice_id: "pqHfZKP75CvOlQylNhV4",
         name: "Bill",
         gender: "male",
+        accent: "american",
+        language: "en",
+        use_case: "narration",
     };
 
     pub static ARIA: StaticVoice = StaticVoice {
         voice_id: "9BWtsMINqrJLrRacOk9x",
         name: "Aria",
         gender: "female",
+        accent: "american",
+        language: "en",
+        use_case: "social_media",
     };
 
-    /// Get all available pre-built voices as a vector
+    /// Get all available pre-built voices as a vector. This is the original set of 18
+    /// voices; [`voices::all`](super::all) has the full catalog.
     pub fn all() -> Vec<&'static StaticVoice> {
         vec![
             &WILL, &THOMAS, &CHARLIE, &GEORGE, &CALLUM, &LIAM, &CHARLOTTE, &ALICE, &MATILDA,
@@ -355,8 +648,6 @@ pub mod all_voices {
 
     /// Find a voice by name (case-insensitive)
     pub fn find_by_name(name: &str) -> Option<&'static StaticVoice> {
-        all()
-            .into_iter()
-            .find(|v| v.name.to_lowercase() == name.to_lowercase())
+        super::find_by_name(name)
     }
 }
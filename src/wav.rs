@@ -0,0 +1,104 @@
+//! WAV files from the raw output formats, written with hound (`wav` feature)
+//!
+//! PCM output is signed 16-bit little-endian, and μ-law and A-law are 8-bit companded;
+//! none of them has a header, so most tools can't open them as they are. [`write_wav`]
+//! picks the sample rate and channel count from the [`OutputFormat`] and expands μ-law
+//! and A-law to 16-bit PCM, which every WAV reader supports.
+
+use std::io::{Cursor, Seek, Write};
+use std::path::Path;
+
+use hound::{SampleFormat, WavSpec, WavWriter};
+
+use crate::{AudioOutput, ElevenLabsTTSError, OutputFormat, audio};
+
+/// The WAV layout for audio generated in `format`: its sample rate and channels, as
+/// 16-bit integer samples. Fails for formats that aren't raw samples (MP3, Opus).
+pub fn spec(format: OutputFormat) -> Result<WavSpec, ElevenLabsTTSError> {
+    if !format.is_raw() {
+        return Err(ElevenLabsTTSError::ValidationError(format!(
+            "WAV files need a pcm_*, ulaw_* or alaw_* output format, got {}",
+            format
+        )));
+    }
+    Ok(WavSpec {
+        channels: format.channels(),
+        sample_rate: format.sample_rate(),
+        bits_per_sample: 16,
+        sample_format: SampleFormat::Int,
+    })
+}
+
+/// Write audio generated in a raw `format` as a WAV file
+pub fn write_wav<W: Write + Seek>(
+    writer: W,
+    audio: &[u8],
+    format: OutputFormat,
+) -> Result<(), ElevenLabsTTSError> {
+    let spec = spec(format)?;
+    let expanded;
+    let pcm = match format.codec() {
+        "ulaw" => {
+            expanded = audio::ulaw_to_pcm(audio);
+            &expanded[..]
+        }
+        "alaw" => {
+            expanded = audio::alaw_to_pcm(audio);
+            &expanded[..]
+        }
+        _ => audio,
+    };
+    if !pcm.len().is_multiple_of(2) {
+        return Err(ElevenLabsTTSError::ValidationError(
+            "PCM audio has an odd number of bytes, not 16-bit samples".to_string(),
+        ));
+    }
+
+    let count = u32::try_from(pcm.len() / 2).map_err(|_| {
+        ElevenLabsTTSError::ValidationError("PCM audio too large for a WAV file".to_string())
+    })?;
+
+    let mut wav = WavWriter::new(writer, spec).map_err(wav_error)?;
+    let mut samples = wav.get_i16_writer(count);
+    for sample in pcm.chunks_exact(2) {
+        samples.write_sample(i16::from_le_bytes([sample[0], sample[1]]));
+    }
+    samples.flush().map_err(wav_error)?;
+    wav.finalize().map_err(wav_error)
+}
+
+/// WAV file bytes for audio generated in a raw `format`
+///
+/// ```rust
+/// use elevenlabs_tts::{OutputFormat, wav};
+///
+/// let wav = wav::to_wav(&[0xFF, 0x7F], OutputFormat::Ulaw_8000).unwrap();
+/// assert_eq!(&wav[..4], b"RIFF");
+/// // Each μ-law byte becomes a 16-bit sample
+/// assert_eq!(wav.len(), 44 + 4);
+/// ```
+pub fn to_wav(audio: &[u8], format: OutputFormat) -> Result<Vec<u8>, ElevenLabsTTSError> {
+    let mut wav = Cursor::new(Vec::new());
+    write_wav(&mut wav, audio, format)?;
+    Ok(wav.into_inner())
+}
+
+impl AudioOutput {
+    /// The audio as WAV file bytes (`wav` feature); fails for MP3 and Opus
+    pub fn to_wav(&self) -> Result<Vec<u8>, ElevenLabsTTSError> {
+        to_wav(self.as_bytes(), self.format())
+    }
+
+    /// Save the audio as a WAV file (`wav` feature); fails for MP3 and Opus
+    pub async fn save_wav<P: AsRef<Path>>(&self, path: P) -> Result<(), ElevenLabsTTSError> {
+        tokio::fs::write(path, self.to_wav()?).await?;
+        Ok(())
+    }
+}
+
+fn wav_error(error: hound::Error) -> ElevenLabsTTSError {
+    match error {
+        hound::Error::IoError(error) => ElevenLabsTTSError::IoError(error),
+        error => ElevenLabsTTSError::ValidationError(format!("Could not write WAV: {}", error)),
+    }
+}
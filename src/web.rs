@@ -0,0 +1,164 @@
+//! Relaying streamed speech from web handlers (`axum` and `actix-web` features)
+//!
+//! A [`SpeechResponse`] forwards the audio to the HTTP client chunk by chunk as it is
+//! generated, with the format's `Content-Type` and chunked transfer, so a backend can
+//! expose text-to-speech without buffering whole files. Errors convert into responses
+//! too: invalid input becomes `400`, rate limiting `429` and API failures `502`.
+
+use crate::{AudioStream, ElevenLabsTTSError, OutputFormat};
+
+/// Generated audio streamed to an HTTP client as it arrives. Created by
+/// [`TextToSpeechBuilder::execute_response`](crate::TextToSpeechBuilder::execute_response).
+///
+/// An error midway through the generation aborts the response, so the client sees a
+/// truncated body rather than a complete one.
+pub struct SpeechResponse {
+    stream: AudioStream,
+    format: OutputFormat,
+}
+
+impl SpeechResponse {
+    pub fn new(stream: AudioStream, format: OutputFormat) -> Self {
+        Self { stream, format }
+    }
+
+    /// `Content-Type` the audio is served with
+    pub fn content_type(&self) -> &'static str {
+        self.format.mime_type()
+    }
+
+    /// The audio chunks, for frameworks without a built-in integration
+    pub fn into_stream(self) -> AudioStream {
+        self.stream
+    }
+}
+
+/// HTTP status to answer a relayed request with when generating the speech failed
+pub fn error_status(error: &ElevenLabsTTSError) -> u16 {
+    match error {
+        ElevenLabsTTSError::ValidationError(_) => 400,
+        // The text or voice was rejected, so the caller's request was at fault
+        ElevenLabsTTSError::ApiError { status, .. } if matches!(status, 400 | 422) => *status,
+        ElevenLabsTTSError::RateLimitError { .. } => 429,
+        ElevenLabsTTSError::CircuitOpenError { .. } => 503,
+        _ => 502,
+    }
+}
+
+/// With axum, return the response or the error from a handler:
+///
+/// ```rust,no_run
+/// use axum::extract::State;
+/// use elevenlabs_tts::{ElevenLabsTTSClient, ElevenLabsTTSError, web::SpeechResponse};
+///
+/// // POST /speak with the text as the body
+/// async fn speak(
+///     State(client): State<ElevenLabsTTSClient>,
+///     text: String,
+/// ) -> Result<SpeechResponse, ElevenLabsTTSError> {
+///     client.text_to_speech(text).execute_response().await
+/// }
+/// ```
+#[cfg(feature = "axum")]
+mod axum_response {
+    use axum::body::Body;
+    use axum::http::{HeaderValue, StatusCode, header};
+    use axum::response::{IntoResponse, Response};
+
+    use super::{SpeechResponse, error_status};
+    use crate::ElevenLabsTTSError;
+
+    impl IntoResponse for SpeechResponse {
+        fn into_response(self) -> Response {
+            let content_type = HeaderValue::from_static(self.content_type());
+            let mut response = Response::new(Body::from_stream(self.stream));
+            response
+                .headers_mut()
+                .insert(header::CONTENT_TYPE, content_type);
+            response
+        }
+    }
+
+    impl IntoResponse for ElevenLabsTTSError {
+        fn into_response(self) -> Response {
+            let status =
+                StatusCode::from_u16(error_status(&self)).unwrap_or(StatusCode::BAD_GATEWAY);
+            let retry_after = match &self {
+                ElevenLabsTTSError::RateLimitError {
+                    retry_after: Some(seconds),
+                    ..
+                }
+                | ElevenLabsTTSError::CircuitOpenError {
+                    retry_after: seconds,
+                } => Some(*seconds),
+                _ => None,
+            };
+            let mut response = (status, self.to_string()).into_response();
+            if let Some(seconds) = retry_after {
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+            }
+            response
+        }
+    }
+}
+
+/// With actix-web, the handler looks the same:
+///
+/// ```rust,no_run
+/// use actix_web::web::Data;
+/// use elevenlabs_tts::{ElevenLabsTTSClient, ElevenLabsTTSError, web::SpeechResponse};
+///
+/// async fn speak(
+///     client: Data<ElevenLabsTTSClient>,
+///     text: String,
+/// ) -> Result<SpeechResponse, ElevenLabsTTSError> {
+///     client.text_to_speech(text).execute_response().await
+/// }
+///
+/// let app = actix_web::App::new().route("/speak", actix_web::web::post().to(speak));
+/// ```
+#[cfg(feature = "actix-web")]
+mod actix_response {
+    use actix_web::body::BoxBody;
+    use actix_web::http::StatusCode;
+    use actix_web::http::header;
+    use actix_web::{HttpRequest, HttpResponse, Responder, ResponseError};
+
+    use super::{SpeechResponse, error_status};
+    use crate::ElevenLabsTTSError;
+
+    impl Responder for SpeechResponse {
+        type Body = BoxBody;
+
+        fn respond_to(self, _request: &HttpRequest) -> HttpResponse {
+            HttpResponse::Ok()
+                .content_type(self.content_type())
+                .streaming(self.stream)
+        }
+    }
+
+    impl ResponseError for ElevenLabsTTSError {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::from_u16(error_status(self)).unwrap_or(StatusCode::BAD_GATEWAY)
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            let mut response = HttpResponse::build(ResponseError::status_code(self));
+            match self {
+                ElevenLabsTTSError::RateLimitError {
+                    retry_after: Some(seconds),
+                    ..
+                }
+                | ElevenLabsTTSError::CircuitOpenError {
+                    retry_after: seconds,
+                } => {
+                    response.insert_header((header::RETRY_AFTER, *seconds));
+                }
+                _ => {}
+            }
+            response.body(self.to_string())
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! Verifying and parsing ElevenLabs webhooks
+//!
+//! Each webhook request carries an `ElevenLabs-Signature` header of the form
+//! `t=<unix timestamp>,v0=<hex HMAC-SHA256>`, signed with the webhook's shared secret over
+//! `<timestamp>.<body>`. [`WebhookVerifier`] checks the signature in constant time and
+//! rejects stale timestamps, so a captured request can't be replayed later.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use sha2::Sha256;
+
+use crate::ElevenLabsTTSError;
+use crate::speech_to_text::Transcription;
+
+/// Header carrying the signature
+pub const SIGNATURE_HEADER: &str = "ElevenLabs-Signature";
+
+/// How old a signature may be by default
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(30 * 60);
+
+/// Verifies webhook signatures with the secret shown when the webhook was created
+///
+/// ```rust
+/// use elevenlabs_tts::webhooks::{WebhookEvent, WebhookVerifier};
+///
+/// let verifier = WebhookVerifier::new("wsec_example");
+/// let body = br#"{"type":"speech_to_text_transcription","event_timestamp":1700000000,"data":{}}"#;
+/// let signature = verifier.sign(body, 1_700_000_000);
+///
+/// let event = verifier
+///     .tolerance(std::time::Duration::MAX)
+///     .construct_event(body, &signature)
+///     .unwrap();
+/// assert_eq!(event.event_type, "speech_to_text_transcription");
+/// ```
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secret: Vec<u8>,
+    tolerance: Duration,
+}
+
+impl std::fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("secret", &"[REDACTED]")
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+impl WebhookVerifier {
+    /// Create a verifier for the webhook's `wsec_…` secret, shown when the webhook was
+    /// created, accepting signatures up to [`DEFAULT_TOLERANCE`] old
+    pub fn new<S: AsRef<[u8]>>(secret: S) -> Self {
+        Self {
+            secret: secret.as_ref().to_vec(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Set how old a signature may be (default: [`DEFAULT_TOLERANCE`])
+    pub fn tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn mac(&self, timestamp: &str, payload: &[u8]) -> Hmac<Sha256> {
+        // HMAC accepts keys of any length
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.secret).expect("any key length");
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        mac
+    }
+
+    /// Check the raw request body against the value of the [`SIGNATURE_HEADER`]
+    pub fn verify(&self, payload: &[u8], signature_header: &str) -> Result<(), ElevenLabsTTSError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.verify_at(payload, signature_header, now)
+    }
+
+    /// [`verify`](Self::verify) as if the current time was `now` (Unix seconds)
+    pub fn verify_at(
+        &self,
+        payload: &[u8],
+        signature_header: &str,
+        now: u64,
+    ) -> Result<(), ElevenLabsTTSError> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for part in signature_header.split(',') {
+            match part.trim().split_once('=') {
+                Some(("t", value)) => timestamp = Some(value),
+                Some(("v0", value)) => signatures.push(value),
+                _ => {}
+            }
+        }
+        let (Some(timestamp), false) = (timestamp, signatures.is_empty()) else {
+            return Err(ElevenLabsTTSError::WebhookError(
+                "Signature header needs a timestamp (t=) and a signature (v0=)".to_string(),
+            ));
+        };
+        let signed_at: u64 = timestamp.parse().map_err(|_| {
+            ElevenLabsTTSError::WebhookError(format!("Invalid signature timestamp {:?}", timestamp))
+        })?;
+        if Duration::from_secs(now.abs_diff(signed_at)) > self.tolerance {
+            return Err(ElevenLabsTTSError::WebhookError(
+                "Signature timestamp is outside the tolerance".to_string(),
+            ));
+        }
+
+        let matches = signatures
+            .iter()
+            .filter_map(|hex| decode_hex(hex))
+            .any(|signature| {
+                self.mac(timestamp, payload)
+                    .verify_slice(&signature)
+                    .is_ok()
+            });
+        if !matches {
+            return Err(ElevenLabsTTSError::WebhookError(
+                "Signature does not match the payload".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Verify the signature and parse the body into an event
+    pub fn construct_event(
+        &self,
+        payload: &[u8],
+        signature_header: &str,
+    ) -> Result<WebhookEvent, ElevenLabsTTSError> {
+        self.verify(payload, signature_header)?;
+        Ok(serde_json::from_slice(payload)?)
+    }
+
+    /// The signature header ElevenLabs would send for `payload` at `timestamp`, for tests
+    /// and local development
+    pub fn sign(&self, payload: &[u8], timestamp: u64) -> String {
+        let timestamp = timestamp.to_string();
+        let signature: String = self
+            .mac(&timestamp, payload)
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        format!("t={},v0={}", timestamp, signature)
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// A webhook request body
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct WebhookEvent {
+    /// e.g. `speech_to_text_transcription`, `post_call_transcription`
+    #[serde(rename = "type")]
+    pub event_type: String,
+
+    /// When the event happened (Unix timestamp, seconds)
+    #[serde(default)]
+    pub event_timestamp: Option<i64>,
+
+    /// The event's payload; see [`payload`](Self::payload) for the typed version
+    #[serde(default)]
+    pub data: serde_json::Value,
+}
+
+/// The typed payload of a [`WebhookEvent`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookPayload {
+    /// An asynchronous speech-to-text job finished
+    SpeechToTextTranscription(SpeechToTextCompleted),
+    /// An agent conversation ended and was analysed; the full conversation details
+    PostCallTranscription(serde_json::Value),
+    /// An event type this version of the crate does not know about
+    Unknown(serde_json::Value),
+}
+
+/// Payload of a `speech_to_text_transcription` event
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SpeechToTextCompleted {
+    /// ID of the speech-to-text request the result belongs to
+    pub request_id: String,
+
+    pub transcription: Transcription,
+
+    /// The metadata passed when the job was submitted
+    #[serde(default)]
+    pub webhook_metadata: Option<serde_json::Value>,
+}
+
+impl WebhookEvent {
+    /// Deserialize the payload according to the event type
+    pub fn payload(&self) -> Result<WebhookPayload, ElevenLabsTTSError> {
+        Ok(match self.event_type.as_str() {
+            "speech_to_text_transcription" => {
+                WebhookPayload::SpeechToTextTranscription(self.data_as()?)
+            }
+            "post_call_transcription" => WebhookPayload::PostCallTranscription(self.data.clone()),
+            _ => WebhookPayload::Unknown(self.data.clone()),
+        })
+    }
+
+    /// Deserialize the payload into a type of your own
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, ElevenLabsTTSError> {
+        Ok(T::deserialize(&self.data)?)
+    }
+}
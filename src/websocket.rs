@@ -0,0 +1,902 @@
+//! Input streaming text-to-speech over WebSocket (`websocket` feature)
+//!
+//! Text is sent piece by piece as it becomes available (e.g. from an LLM) and audio comes
+//! back as soon as enough text has been buffered to generate it.
+//!
+//! ```rust,no_run
+//! use elevenlabs_tts::{ElevenLabsTTSClient, voices};
+//! use elevenlabs_tts::websocket::GenerationConfig;
+//!
+//! # async fn example() -> Result<(), elevenlabs_tts::ElevenLabsTTSError> {
+//! let client = ElevenLabsTTSClient::new("your-api-key");
+//! let mut socket = client
+//!     .text_to_speech_websocket(voices::all_voices::RACHEL.voice_id)
+//!     .generation_config(GenerationConfig::default().chunk_length_schedule([80, 120, 200]))
+//!     .connect()
+//!     .await?;
+//!
+//! socket.send_text("Hello there, ").await?;
+//! socket.send_text("how are you today? ").await?;
+//! socket.close_input().await?;
+//!
+//! let mut audio = Vec::new();
+//! while let Some(chunk) = socket.recv().await {
+//!     audio.extend_from_slice(&chunk?.audio);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::pin::{Pin, pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, ready};
+use std::time::Duration;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use bytes::Bytes;
+use futures_util::future::BoxFuture;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpStream;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use crate::{
+    Alignment, AudioStream, ElevenLabsTTSClient, ElevenLabsTTSError, LanguageCode, OutputFormat,
+    RetryPolicy, TextNormalization, VoiceSettings, auth, llm, models,
+};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Smallest value accepted in `chunk_length_schedule`
+pub const MIN_CHUNK_LENGTH: u32 = 50;
+
+/// Largest value accepted in `chunk_length_schedule`
+pub const MAX_CHUNK_LENGTH: u32 = 500;
+
+/// How long the API waits for text before closing the session, unless configured otherwise
+pub const DEFAULT_INACTIVITY_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Longest inactivity timeout the API accepts
+pub const MAX_INACTIVITY_TIMEOUT: Duration = Duration::from_secs(180);
+
+/// Generation settings of a WebSocket session, sent with the first message
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct GenerationConfig {
+    /// Characters to buffer before each generation: the first entry applies to the first
+    /// chunk of audio, the second to the next one, and the last entry to every chunk after.
+    /// Lower values start audio sooner, higher values give the model more context.
+    /// Each value must be between 50 and 500; the API defaults to `[120, 160, 250, 290]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_length_schedule: Option<Vec<u32>>,
+}
+
+impl GenerationConfig {
+    /// Set the characters to buffer before each generation
+    pub fn chunk_length_schedule<I: IntoIterator<Item = u32>>(mut self, schedule: I) -> Self {
+        self.chunk_length_schedule = Some(schedule.into_iter().collect());
+        self
+    }
+
+    /// Check the schedule against the limits the API accepts
+    pub fn validate(&self) -> Result<(), ElevenLabsTTSError> {
+        if let Some(schedule) = &self.chunk_length_schedule {
+            if schedule.is_empty() {
+                return Err(ElevenLabsTTSError::ValidationError(
+                    "chunk_length_schedule cannot be empty".to_string(),
+                ));
+            }
+            if let Some(length) = schedule
+                .iter()
+                .find(|length| !(MIN_CHUNK_LENGTH..=MAX_CHUNK_LENGTH).contains(*length))
+            {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "chunk_length_schedule values must be between {} and {}, got {}",
+                    MIN_CHUNK_LENGTH, MAX_CHUNK_LENGTH, length
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.chunk_length_schedule.is_none()
+    }
+}
+
+/// Builder for a text-to-speech WebSocket session
+pub struct WebSocketBuilder {
+    client: ElevenLabsTTSClient,
+    voice_id: String,
+    model_id: Option<String>,
+    output_format: Option<OutputFormat>,
+    language_code: Option<String>,
+    voice_settings: Option<VoiceSettings>,
+    generation_config: GenerationConfig,
+    auto_mode: Option<bool>,
+    sync_alignment: Option<bool>,
+    apply_text_normalization: Option<TextNormalization>,
+    enable_logging: Option<bool>,
+    inactivity_timeout: Option<Duration>,
+    keepalive: Option<Duration>,
+    reconnect: Option<RetryPolicy>,
+    on_reconnect: Option<ReconnectCallback>,
+}
+
+impl WebSocketBuilder {
+    pub(crate) fn new(client: ElevenLabsTTSClient, voice_id: String) -> Self {
+        let defaults = &client.inner.defaults;
+        Self {
+            model_id: defaults.model_id.clone(),
+            output_format: defaults.output_format,
+            voice_settings: defaults.voice_settings.clone(),
+            client,
+            voice_id,
+            language_code: None,
+            generation_config: GenerationConfig::default(),
+            auto_mode: None,
+            sync_alignment: None,
+            apply_text_normalization: None,
+            enable_logging: None,
+            inactivity_timeout: None,
+            keepalive: None,
+            reconnect: None,
+            on_reconnect: None,
+        }
+    }
+
+    /// Set the model to use, as a [`models::ModelId`] or a model ID string
+    pub fn model<M: Into<models::ModelId>>(mut self, model_id: M) -> Self {
+        self.model_id = Some(model_id.into().to_string());
+        self
+    }
+
+    /// Set the output format of the audio chunks
+    pub fn output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = Some(output_format);
+        self
+    }
+
+    /// Set the language (ISO 639-1) to enforce, for models that support it
+    pub fn language_code<S: Into<String>>(mut self, language_code: S) -> Self {
+        self.language_code = Some(language_code.into());
+        self
+    }
+
+    /// Set the voice settings for the session
+    pub fn voice_settings(mut self, voice_settings: VoiceSettings) -> Self {
+        self.voice_settings = Some(voice_settings);
+        self
+    }
+
+    /// Set the generation settings (chunk length schedule)
+    pub fn generation_config(mut self, generation_config: GenerationConfig) -> Self {
+        self.generation_config = generation_config;
+        self
+    }
+
+    /// Set the characters to buffer before each generation, see
+    /// [`GenerationConfig::chunk_length_schedule`]
+    pub fn chunk_length_schedule<I: IntoIterator<Item = u32>>(mut self, schedule: I) -> Self {
+        self.generation_config = self.generation_config.chunk_length_schedule(schedule);
+        self
+    }
+
+    /// Let the API decide when to generate instead of following the chunk length schedule.
+    /// Reduces latency when every message ends a sentence or phrase.
+    pub fn auto_mode(mut self, auto_mode: bool) -> Self {
+        self.auto_mode = Some(auto_mode);
+        self
+    }
+
+    /// Send the character alignment with the audio chunk it belongs to
+    pub fn sync_alignment(mut self, sync_alignment: bool) -> Self {
+        self.sync_alignment = Some(sync_alignment);
+        self
+    }
+
+    /// Set the text normalization mode
+    pub fn text_normalization(mut self, text_normalization: TextNormalization) -> Self {
+        self.apply_text_normalization = Some(text_normalization);
+        self
+    }
+
+    /// Enable or disable request logging on the API side (zero retention mode when disabled)
+    pub fn enable_logging(mut self, enable_logging: bool) -> Self {
+        self.enable_logging = Some(enable_logging);
+        self
+    }
+
+    /// Set how long the API waits for text before closing the session (1 to 180 seconds,
+    /// whole seconds; 20 by default)
+    pub fn inactivity_timeout(mut self, timeout: Duration) -> Self {
+        self.inactivity_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep the session open while no text is sent by sending a single space every
+    /// `interval` of silence. Must be shorter than the inactivity timeout.
+    pub fn keepalive(mut self, interval: Duration) -> Self {
+        self.keepalive = Some(interval);
+        self
+    }
+
+    /// Reopen the session when the connection drops, following the policy's attempts and
+    /// backoff, and send again the text whose audio had not been received. Reconnection
+    /// happens while the audio is being received.
+    pub fn reconnect(mut self, policy: RetryPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
+    /// Call `callback` every time the session has been reopened
+    pub fn on_reconnect<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&ReconnectEvent) + Send + Sync + 'static,
+    {
+        self.on_reconnect = Some(Arc::new(callback));
+        self
+    }
+
+    /// Check the session settings locally
+    pub fn validate(&self) -> Result<(), ElevenLabsTTSError> {
+        if self.voice_id.trim().is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Voice ID cannot be empty".to_string(),
+            ));
+        }
+        if let Some(settings) = &self.voice_settings {
+            settings.validate()?;
+        }
+        if let Some(language_code) = &self.language_code {
+            LanguageCode::new(language_code)?;
+        }
+        if let Some(timeout) = self.inactivity_timeout {
+            if !(Duration::from_secs(1)..=MAX_INACTIVITY_TIMEOUT).contains(&timeout) {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Inactivity timeout must be between 1 and {} seconds, got {:?}",
+                    MAX_INACTIVITY_TIMEOUT.as_secs(),
+                    timeout
+                )));
+            }
+        }
+        if let Some(interval) = self.keepalive {
+            let timeout = self
+                .inactivity_timeout
+                .unwrap_or(DEFAULT_INACTIVITY_TIMEOUT);
+            if interval.is_zero() || interval >= timeout {
+                return Err(ElevenLabsTTSError::ValidationError(format!(
+                    "Keepalive interval must be shorter than the inactivity timeout ({:?}), got {:?}",
+                    timeout, interval
+                )));
+            }
+        }
+        self.generation_config.validate()
+    }
+
+    /// The `stream-input` URL with the session's query parameters
+    fn url(&self) -> Result<reqwest::Url, ElevenLabsTTSError> {
+        let base_url = &self.client.inner.base_url;
+        let invalid =
+            || ElevenLabsTTSError::ValidationError(format!("Invalid base URL: {}", base_url));
+        let mut url = reqwest::Url::parse(&format!(
+            "{}/text-to-speech/{}/stream-input",
+            base_url, self.voice_id
+        ))
+        .map_err(|_| invalid())?;
+        let scheme = if url.scheme() == "http" { "ws" } else { "wss" };
+        url.set_scheme(scheme).map_err(|_| invalid())?;
+
+        let model_id = self
+            .model_id
+            .as_deref()
+            .unwrap_or(models::elevanlabs_models::ELEVEN_MULTILINGUAL_V2); // Default to: eleven_multilingual_v2
+        let mut query = url.query_pairs_mut();
+        query.append_pair("model_id", model_id);
+        query.append_pair(
+            "output_format",
+            &self.output_format.unwrap_or_default().to_string(),
+        );
+        if let Some(language_code) = &self.language_code {
+            query.append_pair("language_code", language_code);
+        }
+        if let Some(auto_mode) = self.auto_mode {
+            query.append_pair("auto_mode", &auto_mode.to_string());
+        }
+        if let Some(sync_alignment) = self.sync_alignment {
+            query.append_pair("sync_alignment", &sync_alignment.to_string());
+        }
+        if let Some(normalization) = self.apply_text_normalization {
+            query.append_pair("apply_text_normalization", normalization.as_str());
+        }
+        if let Some(enable_logging) = self.enable_logging {
+            query.append_pair("enable_logging", &enable_logging.to_string());
+        }
+        if let Some(timeout) = self.inactivity_timeout {
+            query.append_pair("inactivity_timeout", &timeout.as_secs().to_string());
+        }
+        drop(query);
+        Ok(url)
+    }
+
+    /// Open the WebSocket and send the session settings
+    pub async fn connect(self) -> Result<TtsWebSocket, ElevenLabsTTSError> {
+        self.validate()?;
+        let session = Arc::new(Session {
+            url: self.url()?,
+            init: serde_json::to_string(&InitMessage {
+                text: " ",
+                voice_settings: self.voice_settings.as_ref(),
+                generation_config: &self.generation_config,
+            })?,
+            reconnect: self.reconnect,
+            on_reconnect: self.on_reconnect,
+            client: self.client,
+        });
+
+        let (sink, stream) = session.open().await?.split();
+        let outgoing = Arc::new(Outgoing {
+            sink: tokio::sync::Mutex::new(sink),
+            last_sent: Mutex::new(Instant::now()),
+            pending: Mutex::new(PendingText::default()),
+            reconnects: session.reconnect.is_some(),
+        });
+        // The first message opens the session and carries its settings
+        outgoing.send_raw(session.init.clone()).await?;
+
+        let keepalive = self
+            .keepalive
+            .map(|interval| tokio::spawn(keepalive(outgoing.clone(), interval)));
+        Ok(TtsWebSocket {
+            sender: WebSocketSender {
+                outgoing: outgoing.clone(),
+                keepalive,
+            },
+            receiver: WebSocketReceiver {
+                stream,
+                outgoing,
+                session,
+                reconnecting: None,
+                finished: false,
+            },
+        })
+    }
+}
+
+impl WebSocketBuilder {
+    /// Speak text as it is produced, e.g. by an LLM: tokens are buffered into sentences
+    /// ([`llm::SentenceBuffer`]) which are sent over one session as soon as they are
+    /// complete, and the audio comes back as one continuous stream.
+    /// The session is closed once `tokens` ends; dropping the stream stops sending.
+    pub async fn synthesize_stream<S>(self, tokens: S) -> Result<AudioStream, ElevenLabsTTSError>
+    where
+        S: Stream<Item = String> + Send + 'static,
+    {
+        let (mut sender, receiver) = self.connect().await?.split();
+        let sending = tokio::spawn(async move {
+            let mut sentences = pin!(llm::sentences(tokens));
+            while let Some(sentence) = sentences.next().await {
+                // A broken connection also ends the receiving side, which reports it
+                if sender.send_text(&format!("{} ", sentence)).await.is_err() {
+                    return;
+                }
+            }
+            let _ = sender.close_input().await;
+        });
+
+        let sending = AbortOnDrop(sending);
+        Ok(Box::pin(receiver.map(move |chunk| {
+            let _ = &sending;
+            chunk.map(|chunk| Bytes::from(chunk.audio))
+        })))
+    }
+}
+
+/// Stops the sending task when the audio stream is dropped
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Everything needed to open the session again
+struct Session {
+    client: ElevenLabsTTSClient,
+    url: reqwest::Url,
+    init: String,
+    reconnect: Option<RetryPolicy>,
+    on_reconnect: Option<ReconnectCallback>,
+}
+
+impl Session {
+    async fn open(&self) -> Result<Socket, ElevenLabsTTSError> {
+        let mut request = self
+            .url
+            .as_str()
+            .into_client_request()
+            .map_err(websocket_error)?;
+
+        let inner = &self.client.inner;
+        let headers = request.headers_mut();
+        for (name, value) in &inner.default_headers {
+            headers.insert(name.clone(), value.clone());
+        }
+        let api_key = inner.api_key.get_key().await?;
+        headers.insert("xi-api-key", auth::api_key_header(&api_key)?);
+
+        let (socket, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(handshake_error)?;
+        Ok(socket)
+    }
+}
+
+/// Callback told about every reconnection, see [`WebSocketBuilder::on_reconnect`]
+pub type ReconnectCallback = Arc<dyn Fn(&ReconnectEvent) + Send + Sync>;
+
+/// A session that was reopened after its connection dropped
+#[derive(Debug, Clone)]
+pub struct ReconnectEvent {
+    /// Why the previous connection ended
+    pub cause: String,
+
+    /// Connection attempts it took (1 when the first one succeeded)
+    pub attempts: u32,
+
+    /// Text sent again because its audio had not been received
+    pub replayed_text: String,
+}
+
+/// An open text-to-speech WebSocket session
+///
+/// Use [`split`](Self::split) to send text and receive audio from different tasks.
+pub struct TtsWebSocket {
+    sender: WebSocketSender,
+    receiver: WebSocketReceiver,
+}
+
+impl TtsWebSocket {
+    /// Send the next piece of text, see [`WebSocketSender::send_text`]
+    pub async fn send_text(&mut self, text: &str) -> Result<(), ElevenLabsTTSError> {
+        self.sender.send_text(text).await
+    }
+
+    /// Generate the buffered text now, see [`WebSocketSender::flush`]
+    pub async fn flush(&mut self) -> Result<(), ElevenLabsTTSError> {
+        self.sender.flush().await
+    }
+
+    /// Signal that no more text will be sent, see [`WebSocketSender::close_input`]
+    pub async fn close_input(&mut self) -> Result<(), ElevenLabsTTSError> {
+        self.sender.close_input().await
+    }
+
+    /// Wait for the next audio chunk, `None` once the session is over
+    pub async fn recv(&mut self) -> Option<Result<WebSocketAudio, ElevenLabsTTSError>> {
+        self.receiver.next().await
+    }
+
+    /// Separate the sending and receiving halves
+    pub fn split(self) -> (WebSocketSender, WebSocketReceiver) {
+        (self.sender, self.receiver)
+    }
+}
+
+/// Sending half of a [`TtsWebSocket`]
+pub struct WebSocketSender {
+    outgoing: Arc<Outgoing>,
+    keepalive: Option<JoinHandle<()>>,
+}
+
+impl WebSocketSender {
+    /// Send the next piece of text. The API expects every piece to end with a space,
+    /// and generates audio once the chunk length schedule has been reached.
+    pub async fn send_text(&mut self, text: &str) -> Result<(), ElevenLabsTTSError> {
+        if text.is_empty() {
+            return Err(ElevenLabsTTSError::ValidationError(
+                "Text cannot be empty, use close_input() to end the session".to_string(),
+            ));
+        }
+        self.outgoing.send_text(text).await
+    }
+
+    /// Generate audio for the text buffered so far without waiting for the chunk length
+    /// schedule, e.g. at the end of a conversational turn. The session stays open.
+    pub async fn flush(&mut self) -> Result<(), ElevenLabsTTSError> {
+        let message = TextMessage {
+            flush: Some(true),
+            ..TextMessage::new(" ")
+        };
+        self.outgoing.send_json(&message).await
+    }
+
+    /// Signal that no more text will be sent: the remaining text is generated and the
+    /// session ends after its audio
+    pub async fn close_input(&mut self) -> Result<(), ElevenLabsTTSError> {
+        self.stop_keepalive();
+        self.outgoing.send_text("").await
+    }
+
+    fn stop_keepalive(&mut self) {
+        if let Some(keepalive) = self.keepalive.take() {
+            keepalive.abort();
+        }
+    }
+}
+
+impl Drop for WebSocketSender {
+    fn drop(&mut self) {
+        self.stop_keepalive();
+    }
+}
+
+/// The sink shared by a sender, its keepalive task and the reconnecting receiver
+struct Outgoing {
+    sink: tokio::sync::Mutex<SplitSink<Socket, Message>>,
+    last_sent: Mutex<Instant>,
+    pending: Mutex<PendingText>,
+    // Sending fails while the connection is down; the receiver replays the text
+    reconnects: bool,
+}
+
+/// Text sent since the last audio, replayed after a reconnection
+#[derive(Default)]
+struct PendingText {
+    text: String,
+    input_closed: bool,
+}
+
+impl Outgoing {
+    async fn send_json<T: Serialize>(&self, message: &T) -> Result<(), ElevenLabsTTSError> {
+        self.send_raw(serde_json::to_string(message)?).await
+    }
+
+    async fn send_raw(&self, message: String) -> Result<(), ElevenLabsTTSError> {
+        self.sink
+            .lock()
+            .await
+            .send(Message::text(message))
+            .await
+            .map_err(websocket_error)?;
+        *self.last_sent.lock().unwrap() = Instant::now();
+        Ok(())
+    }
+
+    /// Send text (empty to close the input), remembering it until its audio arrives
+    async fn send_text(&self, text: &str) -> Result<(), ElevenLabsTTSError> {
+        let message = serde_json::to_string(&TextMessage::new(text))?;
+        // Hold the sink while recording, so a reconnection replays it exactly once
+        let mut sink = self.sink.lock().await;
+        {
+            let mut pending = self.pending.lock().unwrap();
+            pending.text.push_str(text);
+            pending.input_closed |= text.is_empty();
+        }
+        match sink.send(Message::text(message)).await {
+            Ok(()) => {
+                *self.last_sent.lock().unwrap() = Instant::now();
+                Ok(())
+            }
+            Err(_) if self.reconnects => Ok(()),
+            Err(error) => Err(websocket_error(error)),
+        }
+    }
+
+    /// Forget the first `characters` characters of pending text, now that their audio arrived
+    fn consume(&self, characters: usize) {
+        let mut pending = self.pending.lock().unwrap();
+        let end = pending
+            .text
+            .char_indices()
+            .nth(characters)
+            .map_or(pending.text.len(), |(index, _)| index);
+        pending.text.drain(..end);
+    }
+}
+
+/// Reopen the session, swap the new connection in and send the pending text again
+async fn reconnect(
+    outgoing: Arc<Outgoing>,
+    session: Arc<Session>,
+    cause: String,
+) -> Result<SplitStream<Socket>, ElevenLabsTTSError> {
+    let policy = session.reconnect.clone().unwrap_or_else(RetryPolicy::none);
+    // Senders wait for the new connection instead of writing to the dropped one
+    let mut sink = outgoing.sink.lock().await;
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        tokio::time::sleep(policy.delay_for(attempt)).await;
+        let result = async {
+            let (mut new_sink, stream) = session.open().await?.split();
+            let (text, input_closed) = {
+                let pending = outgoing.pending.lock().unwrap();
+                (pending.text.clone(), pending.input_closed)
+            };
+            let mut messages = vec![session.init.clone()];
+            if !text.is_empty() {
+                messages.push(serde_json::to_string(&TextMessage::new(&text))?);
+            }
+            if input_closed {
+                messages.push(serde_json::to_string(&TextMessage::new(""))?);
+            }
+            for message in messages {
+                new_sink
+                    .send(Message::text(message))
+                    .await
+                    .map_err(websocket_error)?;
+            }
+            Ok((new_sink, stream, text))
+        }
+        .await;
+
+        match result {
+            Ok((new_sink, stream, replayed_text)) => {
+                *sink = new_sink;
+                *outgoing.last_sent.lock().unwrap() = Instant::now();
+                if let Some(callback) = &session.on_reconnect {
+                    callback(&ReconnectEvent {
+                        cause,
+                        attempts: attempt,
+                        replayed_text,
+                    });
+                }
+                return Ok(stream);
+            }
+            Err(error) if attempt < policy.max_attempts && is_transient(&policy, &error) => {}
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+fn is_transient(policy: &RetryPolicy, error: &ElevenLabsTTSError) -> bool {
+    matches!(error, ElevenLabsTTSError::WebSocketError(_)) || policy.should_retry(error)
+}
+
+/// Send a single space (which the API ignores) whenever nothing was sent for `interval`
+async fn keepalive(outgoing: Arc<Outgoing>, interval: Duration) {
+    loop {
+        let due = *outgoing.last_sent.lock().unwrap() + interval;
+        tokio::time::sleep_until(due).await;
+        if outgoing.last_sent.lock().unwrap().elapsed() < interval {
+            continue;
+        }
+        if outgoing.send_json(&TextMessage::new(" ")).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Receiving half of a [`TtsWebSocket`], a stream of audio chunks
+pub struct WebSocketReceiver {
+    stream: SplitStream<Socket>,
+    outgoing: Arc<Outgoing>,
+    session: Arc<Session>,
+    reconnecting: Option<BoxFuture<'static, Result<SplitStream<Socket>, ElevenLabsTTSError>>>,
+    finished: bool,
+}
+
+impl WebSocketReceiver {
+    /// Wait for the next audio chunk, `None` once the session is over
+    pub async fn recv(&mut self) -> Option<Result<WebSocketAudio, ElevenLabsTTSError>> {
+        self.next().await
+    }
+
+    /// Start reconnecting if the session has a reconnect policy
+    fn start_reconnect(&mut self, cause: String) -> bool {
+        if self.session.reconnect.is_none() {
+            return false;
+        }
+        self.reconnecting = Some(Box::pin(reconnect(
+            self.outgoing.clone(),
+            self.session.clone(),
+            cause,
+        )));
+        true
+    }
+}
+
+impl Stream for WebSocketReceiver {
+    type Item = Result<WebSocketAudio, ElevenLabsTTSError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if self.finished {
+                return Poll::Ready(None);
+            }
+            if let Some(reconnecting) = &mut self.reconnecting {
+                let result = ready!(reconnecting.as_mut().poll(cx));
+                self.reconnecting = None;
+                match result {
+                    Ok(stream) => self.stream = stream,
+                    Err(error) => {
+                        self.finished = true;
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                }
+            }
+
+            let message = match ready!(self.stream.poll_next_unpin(cx)) {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(Message::Close(frame))) if !is_dropped(frame.as_ref()) => {
+                    self.finished = true;
+                    return Poll::Ready(None);
+                }
+                Some(Ok(Message::Close(_))) | None => {
+                    if self.start_reconnect("connection closed".to_string()) {
+                        continue;
+                    }
+                    self.finished = true;
+                    return Poll::Ready(None);
+                }
+                // Pings are answered by tungstenite itself
+                Some(Ok(_)) => continue,
+                Some(Err(error)) => {
+                    if self.start_reconnect(error.to_string()) {
+                        continue;
+                    }
+                    self.finished = true;
+                    return Poll::Ready(Some(Err(websocket_error(error))));
+                }
+            };
+
+            let message: ServerMessage = match serde_json::from_str(message.as_str()) {
+                Ok(message) => message,
+                Err(error) => return Poll::Ready(Some(Err(error.into()))),
+            };
+            if let Some(error) = message.error {
+                self.finished = true;
+                let detail = message.message.unwrap_or_default();
+                return Poll::Ready(Some(Err(if error == "input_timeout_exceeded" {
+                    ElevenLabsTTSError::WebSocketTimeout(detail)
+                } else {
+                    ElevenLabsTTSError::WebSocketError(format!("{}: {}", error, detail))
+                })));
+            }
+
+            let is_final = message.is_final.unwrap_or(false);
+            self.finished = is_final;
+            if let Some(alignment) = &message.alignment {
+                self.outgoing.consume(alignment.chars.len());
+            }
+            let audio = match message.audio.as_deref().filter(|audio| !audio.is_empty()) {
+                Some(audio) => match STANDARD.decode(audio) {
+                    Ok(audio) => audio,
+                    Err(error) => {
+                        return Poll::Ready(Some(Err(ElevenLabsTTSError::WebSocketError(
+                            format!("Invalid base64 audio: {}", error),
+                        ))));
+                    }
+                },
+                None => continue,
+            };
+            return Poll::Ready(Some(Ok(WebSocketAudio {
+                audio,
+                is_final,
+                alignment: message.alignment.map(Alignment::from),
+                normalized_alignment: message.normalized_alignment.map(Alignment::from),
+            })));
+        }
+    }
+}
+
+/// A chunk of audio received over the WebSocket
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebSocketAudio {
+    /// Audio in the session's output format
+    pub audio: Vec<u8>,
+
+    /// Whether this is the last chunk of the session
+    pub is_final: bool,
+
+    /// Timing of the characters spoken in this chunk, relative to the start of the chunk
+    pub alignment: Option<Alignment>,
+
+    /// Same as `alignment`, for the text as normalized by the API (numbers spelled out...)
+    pub normalized_alignment: Option<Alignment>,
+}
+
+#[derive(Serialize)]
+struct InitMessage<'a> {
+    text: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    voice_settings: Option<&'a VoiceSettings>,
+    #[serde(skip_serializing_if = "GenerationConfig::is_empty")]
+    generation_config: &'a GenerationConfig,
+}
+
+#[derive(Serialize)]
+struct TextMessage<'a> {
+    text: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flush: Option<bool>,
+}
+
+impl<'a> TextMessage<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, flush: None }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerMessage {
+    audio: Option<String>,
+    is_final: Option<bool>,
+    // Set on errors, e.g. `{"message": "...", "error": "input_timeout_exceeded", "code": 1008}`
+    message: Option<String>,
+    error: Option<String>,
+    alignment: Option<ServerAlignment>,
+    normalized_alignment: Option<ServerAlignment>,
+}
+
+/// Alignment as the WebSocket sends it, in milliseconds with durations
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerAlignment {
+    chars: Vec<String>,
+    #[serde(default)]
+    char_start_times_ms: Vec<f64>,
+    #[serde(default)]
+    char_durations_ms: Vec<f64>,
+}
+
+impl From<ServerAlignment> for Alignment {
+    fn from(alignment: ServerAlignment) -> Self {
+        let character_end_times_seconds = alignment
+            .char_start_times_ms
+            .iter()
+            .zip(&alignment.char_durations_ms)
+            .map(|(start, duration)| (start + duration) / 1000.0)
+            .collect();
+        Alignment {
+            characters: alignment.chars,
+            character_start_times_seconds: alignment
+                .char_start_times_ms
+                .iter()
+                .map(|start| start / 1000.0)
+                .collect(),
+            character_end_times_seconds,
+        }
+    }
+}
+
+/// Whether the server closed the connection because of a failure on its side,
+/// rather than to end the session
+fn is_dropped(frame: Option<&CloseFrame>) -> bool {
+    frame.is_some_and(|frame| {
+        matches!(
+            frame.code,
+            CloseCode::Away | CloseCode::Error | CloseCode::Restart | CloseCode::Again
+        )
+    })
+}
+
+fn websocket_error(error: tungstenite::Error) -> ElevenLabsTTSError {
+    ElevenLabsTTSError::WebSocketError(error.to_string())
+}
+
+/// Map a rejected handshake to the same errors as HTTP calls (401, 404, 429...)
+fn handshake_error(error: tungstenite::Error) -> ElevenLabsTTSError {
+    match error {
+        tungstenite::Error::Http(response) => {
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+            let body = response
+                .into_body()
+                .map(|body| String::from_utf8_lossy(&body).into_owned())
+                .unwrap_or_default();
+            ElevenLabsTTSError::from_response_parts(status, &headers, body)
+        }
+        error => websocket_error(error),
+    }
+}
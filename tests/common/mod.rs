@@ -0,0 +1,267 @@
+//! Minimal canned-response HTTP server for exercising the client without the real API
+
+#![allow(dead_code)]
+
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// A canned HTTP response
+#[derive(Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request received by the mock server
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Serves the given responses in order, one per connection; the last one repeats
+pub struct MockServer {
+    pub base_url: String,
+    pub requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    pub async fn start(responses: Vec<MockResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        tokio::spawn(async move {
+            let mut index = 0;
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let response = responses[index.min(responses.len() - 1)].clone();
+                index += 1;
+
+                if let Some(request) = read_request(&mut socket).await {
+                    recorded.lock().unwrap().push(request);
+                }
+
+                let mut head = format!(
+                    "HTTP/1.1 {} Mock\r\ncontent-length: {}\r\nconnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                for (name, value) in &response.headers {
+                    head.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                head.push_str("\r\n");
+
+                let _ = socket.write_all(head.as_bytes()).await;
+                let _ = socket.write_all(&response.body).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// A server that accepts connections but never answers, returning its base URL
+    pub async fn hanging() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let mut open = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                open.push(socket);
+            }
+        });
+
+        base_url
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<RecordedRequest> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        let read = socket.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+        if let Some(position) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next()?.split(' ');
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect();
+
+    let content_length = headers
+        .iter()
+        .find(|(k, _)| k == "content-length")
+        .and_then(|(_, v)| v.parse::<usize>().ok())
+        .unwrap_or(0);
+
+    let mut body = buffer[header_end..].to_vec();
+    while body.len() < content_length {
+        let read = socket.read(&mut chunk).await.ok()?;
+        if read == 0 {
+            break;
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+
+    Some(RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    })
+}
+
+/// Canned WebSocket server: every connection is handed to a script that plays the API side
+#[cfg(feature = "websocket")]
+pub mod websocket {
+    // The handshake callback's error type is tungstenite's, not ours
+    #![allow(clippy::result_large_err)]
+
+    use std::future::Future;
+    use std::sync::{Arc, Mutex};
+
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio_tungstenite::WebSocketStream;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    use super::RecordedRequest;
+
+    /// The server side of an accepted connection
+    pub struct MockSocket {
+        pub socket: WebSocketStream<TcpStream>,
+    }
+
+    impl MockSocket {
+        /// The next text message parsed as JSON, `None` once the client has gone away
+        pub async fn recv_json(&mut self) -> Option<serde_json::Value> {
+            while let Some(Ok(message)) = self.socket.next().await {
+                match message {
+                    Message::Text(text) => return serde_json::from_str(text.as_str()).ok(),
+                    Message::Close(_) => return None,
+                    _ => continue,
+                }
+            }
+            None
+        }
+
+        pub async fn send_json(&mut self, value: serde_json::Value) {
+            let _ = self.socket.send(Message::text(value.to_string())).await;
+        }
+
+        pub async fn close(mut self) {
+            let _ = self.socket.close(None).await;
+        }
+    }
+
+    pub struct MockWebSocketServer {
+        pub base_url: String,
+        pub connections: Arc<Mutex<Vec<RecordedRequest>>>,
+    }
+
+    impl MockWebSocketServer {
+        /// Run `script` for every connection, with the index of the connection
+        pub async fn start<F, Fut>(script: F) -> Self
+        where
+            F: Fn(usize, MockSocket) -> Fut + Send + Sync + 'static,
+            Fut: Future<Output = ()> + Send + 'static,
+        {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let base_url = format!("http://{}/v1", listener.local_addr().unwrap());
+            let connections = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = connections.clone();
+            tokio::spawn(async move {
+                let mut index = 0;
+                while let Ok((stream, _)) = listener.accept().await {
+                    let mut request = None;
+                    let callback = |handshake: &Request, response: Response| {
+                        request = Some(RecordedRequest {
+                            method: handshake.method().to_string(),
+                            path: handshake.uri().to_string(),
+                            headers: handshake
+                                .headers()
+                                .iter()
+                                .map(|(name, value)| {
+                                    (
+                                        name.to_string(),
+                                        value.to_str().unwrap_or_default().to_string(),
+                                    )
+                                })
+                                .collect(),
+                            body: Vec::new(),
+                        });
+                        Ok(response)
+                    };
+                    let Ok(socket) = tokio_tungstenite::accept_hdr_async(stream, callback).await
+                    else {
+                        continue;
+                    };
+                    recorded.lock().unwrap().extend(request);
+                    tokio::spawn(script(index, MockSocket { socket }));
+                    index += 1;
+                }
+            });
+
+            Self {
+                base_url,
+                connections,
+            }
+        }
+
+        pub fn connections(&self) -> Vec<RecordedRequest> {
+            self.connections.lock().unwrap().clone()
+        }
+    }
+}
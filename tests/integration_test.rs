@@ -1,4 +1,19 @@
-use elevenlabs_tts::{ElevenLabsTTSClient, ElevenLabsTTSError, VoiceSettings, models, voices};
+#![allow(clippy::len_zero)]
+
+mod common;
+
+use common::{MockResponse, MockServer};
+use elevenlabs_tts::dialogue::TextToDialogueRequest;
+use elevenlabs_tts::music::MusicRequest;
+use elevenlabs_tts::subtitles::{self, SubtitleOptions};
+use elevenlabs_tts::{
+    Alignment, ApiKeyProvider, AudioResponse, AudioStream, AudioTag, BatchJob,
+    CircuitBreakerConfig, CircuitState, DialogueInput, DiskCache, ElevenLabsTTSClient,
+    ElevenLabsTTSError, HistoryItem, HistoryItemState, LanguageCode, MemoryCache, MetricsObserver,
+    OutputFormat, PhonemeAlphabet, Priority, PronunciationRules, QueueEvent, RequestId, RetryEvent,
+    RetryPolicy, Stability, TTSRequest, TaggedText, TextNormalization, TtsApi, TtsCache, TtsQueue,
+    UsageTracker, VoiceId, VoiceSettings, async_trait, cost, models, voices,
+};
 
 #[tokio::test]
 async fn test_client_creation() {
@@ -29,6 +44,22 @@ fn test_voice_settings() {
     assert_eq!(settings.speed, Some(1.0));
 }
 
+#[test]
+fn test_partial_voice_settings_serialize_only_set_fields() {
+    let body = serde_json::to_value(VoiceSettings::only_speed(1.1)).unwrap();
+    assert_eq!(body, serde_json::json!({ "speed": 1.1f32 }));
+
+    let body = serde_json::to_value(VoiceSettings::only_stability(0.4).style(0.2)).unwrap();
+    assert_eq!(body.as_object().unwrap().len(), 2);
+    assert!(body.get("similarity_boost").is_none());
+
+    assert_eq!(
+        serde_json::to_value(VoiceSettings::empty()).unwrap(),
+        serde_json::json!({})
+    );
+    assert!(VoiceSettings::only_speaker_boost(false).validate().is_ok());
+}
+
 #[test]
 fn test_error_display() {
     let error = ElevenLabsTTSError::ValidationError("Invalid voice ID".to_string());
@@ -85,6 +116,43 @@ fn test_voice_search() {
     assert!(not_found.is_none());
 }
 
+#[test]
+fn test_voice_catalog_filters() {
+    let british: Vec<_> = voices::by_accent("British").collect();
+    assert!(!british.is_empty());
+    assert!(british.iter().all(|v| v.accent == "british"));
+
+    let female_narrators: Vec<_> = voices::by_gender("female")
+        .filter(|v| v.use_case == "narration")
+        .map(|v| v.name)
+        .collect();
+    assert!(female_narrators.contains(&"Rachel"));
+
+    assert_eq!(voices::by_language("en").count(), voices::all().len());
+    // Grouping by use case covers the whole catalog
+    let grouped: usize = voices::use_cases()
+        .into_iter()
+        .map(|use_case| voices::by_use_case(use_case).count())
+        .sum();
+    assert_eq!(grouped, voices::all().len());
+}
+
+#[test]
+fn test_voice_catalog_lookup() {
+    assert_eq!(voices::all().len(), 55);
+    assert_eq!(
+        voices::find_by_name(" aria ").map(|v| v.voice_id),
+        Some(voices::all_voices::ARIA.voice_id)
+    );
+    // Every catalog voice can be resolved back from its name
+    for voice in voices::all() {
+        assert_eq!(
+            voices::find_by_name(voice.name).unwrap().voice_id,
+            voice.voice_id
+        );
+    }
+}
+
 #[tokio::test]
 async fn test_builder_with_voice_reference() {
     let client = ElevenLabsTTSClient::new("test-key");
@@ -97,6 +165,3710 @@ async fn test_builder_with_voice_reference() {
     assert_eq!(true, true);
 }
 
+#[test]
+fn test_dialogue_builder() {
+    let client = ElevenLabsTTSClient::new("test-key");
+    let request = client
+        .dialogue([
+            (voices::all_voices::RACHEL.voice_id, "Did you hear that?"),
+            (voices::all_voices::ADAM.voice_id, "Hear what?"),
+        ])
+        .input(
+            DialogueInput::new(voices::all_voices::RACHEL.voice_id, "Nothing.")
+                .tags(["whispers", "nervous"]),
+        )
+        .build_request()
+        .unwrap();
+
+    assert_eq!(request.inputs.len(), 3);
+    assert_eq!(request.model_id, models::elevanlabs_models::ELEVEN_V3);
+    assert_eq!(request.inputs[2].text, "[whispers] [nervous] Nothing.");
+
+    let body = serde_json::to_value(&request).unwrap();
+    assert!(body.get("output_format").is_none());
+    assert_eq!(
+        body["inputs"][1]["voice_id"],
+        voices::all_voices::ADAM.voice_id
+    );
+}
+
+#[test]
+fn test_empty_dialogue_is_rejected() {
+    let client = ElevenLabsTTSClient::new("test-key");
+    let turns: Vec<DialogueInput> = Vec::new();
+    let result = client.dialogue(turns).build_request();
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn test_music_builder() {
+    let client = ElevenLabsTTSClient::new("test-key");
+    let request = client
+        .music("calm lo-fi beat with soft piano")
+        .duration(std::time::Duration::from_secs(30))
+        .instrumental(true)
+        .build_request()
+        .unwrap();
+
+    assert_eq!(request.music_length_ms, Some(30_000));
+    assert_eq!(request.model_id, models::elevanlabs_models::MUSIC_V1);
+    assert_eq!(request.force_instrumental, Some(true));
+
+    let too_short = client.music("jingle").duration_ms(500).build_request();
+    assert!(matches!(
+        too_short,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_signed_url_requires_agent_id() {
+    let client = ElevenLabsTTSClient::new("test-key");
+    let result = client.conversational_ai().signed_url("  ").await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn test_client_builder() {
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url("http://localhost:8080/v1")
+        .timeout(std::time::Duration::from_secs(30))
+        .connect_timeout(std::time::Duration::from_secs(5))
+        .pool_idle_timeout(Some(std::time::Duration::from_secs(90)))
+        .pool_max_idle_per_host(32)
+        .tcp_keepalive(std::time::Duration::from_secs(30))
+        .build();
+    assert!(client.is_ok());
+
+    let missing_key = ElevenLabsTTSClient::builder().build();
+    assert!(matches!(
+        missing_key,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn test_client_from_env() {
+    // The only test touching these variables, so it can't race with others
+    std::env::remove_var(elevenlabs_tts::API_KEY_ENV);
+    let missing = ElevenLabsTTSClient::from_env();
+    assert!(
+        matches!(missing, Err(ElevenLabsTTSError::ValidationError(msg)) if msg.contains("ELEVENLABS_API_KEY"))
+    );
+
+    std::env::set_var(elevenlabs_tts::API_KEY_ENV, "test-key");
+    assert!(ElevenLabsTTSClient::from_env().is_ok());
+}
+
+#[tokio::test]
+async fn test_retry_on_transient_status() {
+    let server = MockServer::start(vec![
+        MockResponse::new(503, "unavailable"),
+        MockResponse::new(200, "audio"),
+    ])
+    .await;
+
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .retry_policy(
+            RetryPolicy::new(3)
+                .base_delay(std::time::Duration::from_millis(1))
+                .jitter(false),
+        )
+        .build()
+        .unwrap();
+
+    let audio = client.text_to_speech("Hello").execute().await.unwrap();
+    assert_eq!(audio, b"audio");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "POST");
+    assert!(requests[1].path.starts_with("/v1/text-to-speech/"));
+    assert_eq!(requests[1].header("xi-api-key"), Some("test-key"));
+}
+
+#[tokio::test]
+async fn test_no_retry_by_default() {
+    let server = MockServer::start(vec![
+        MockResponse::new(503, "unavailable"),
+        MockResponse::new(200, "audio"),
+    ])
+    .await;
+
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let result = client.text_to_speech("Hello").execute().await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ApiError { status: 503, .. })
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_error_response_keeps_request_id() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            404,
+            r#"{"detail":{"status":"voice_not_found","message":"Voice not found"}}"#,
+        )
+        .header("request-id", "req_abc123"),
+    ])
+    .await;
+
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let error = client.text_to_speech("Hello").execute().await.unwrap_err();
+    assert_eq!(error.request_id(), Some("req_abc123"));
+    assert!(error.to_string().ends_with("(request-id: req_abc123)"));
+}
+
+#[tokio::test]
+async fn test_rate_limit_response_maps_to_rate_limit_error() {
+    let server = MockServer::start(vec![
+        MockResponse::new(429, "too many concurrent requests").header("retry-after", "7"),
+    ])
+    .await;
+
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let result = client.text_to_speech("Hello").execute().await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::RateLimitError {
+            retry_after: Some(7),
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn test_retry_waits_for_retry_after_and_reports_it() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(429, "slow down").header("retry-after", "7"),
+        MockResponse::new(200, "audio-bytes"),
+    ])
+    .await;
+
+    let events: Arc<Mutex<Vec<RetryEvent>>> = Arc::default();
+    let recorded = events.clone();
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .retry_policy(
+            RetryPolicy::new(2)
+                .max_delay(Duration::from_millis(50))
+                .on_retry(move |event| recorded.lock().unwrap().push(event.clone())),
+        )
+        .build()
+        .unwrap();
+
+    let audio = client.text_to_speech("Hello").execute().await.unwrap();
+    assert_eq!(audio, b"audio-bytes");
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].endpoint, "text-to-speech");
+    assert_eq!(events[0].attempt, 1);
+    assert!(events[0].is_throttled());
+    assert_eq!(events[0].retry_after, Some(Duration::from_secs(7)));
+    // The server's wait is capped by max_delay
+    assert_eq!(events[0].delay, Duration::from_millis(50));
+}
+
+#[cfg(feature = "tower")]
+#[tokio::test]
+async fn test_client_as_tower_service() {
+    use tower_service::Service;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "audio-bytes").header("request-id", "req-1"),
+    ])
+    .await;
+
+    let mut service = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    std::future::poll_fn(|cx| service.poll_ready(cx))
+        .await
+        .unwrap();
+    let request = service
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .build_request()
+        .unwrap();
+    let response = service.call(request).await.unwrap();
+
+    assert_eq!(response.audio, b"audio-bytes");
+    assert_eq!(response.request_id.as_deref(), Some("req-1"));
+    assert!(
+        server.requests()[0]
+            .path
+            .starts_with("/v1/text-to-speech/voice-123")
+    );
+}
+
+#[derive(Default)]
+struct RecordingObserver {
+    events: std::sync::Mutex<Vec<String>>,
+}
+
+impl MetricsObserver for RecordingObserver {
+    fn request_started(&self, endpoint: &str) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("started {}", endpoint));
+    }
+
+    fn request_finished(&self, endpoint: &str, status: Option<u16>, _: std::time::Duration) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("finished {} {:?}", endpoint, status));
+    }
+
+    fn bytes_received(&self, endpoint: &str, bytes: usize) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("bytes {} {}", endpoint, bytes));
+    }
+
+    fn characters_billed(&self, endpoint: &str, characters: u64) {
+        self.events
+            .lock()
+            .unwrap()
+            .push(format!("billed {} {}", endpoint, characters));
+    }
+}
+
+#[tokio::test]
+async fn test_metrics_observer_hooks() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "audio").header("character-cost", "5"),
+    ])
+    .await;
+    let observer = std::sync::Arc::new(RecordingObserver::default());
+
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .metrics_observer(observer.clone())
+        .build()
+        .unwrap();
+    client.text_to_speech("Hello").execute().await.unwrap();
+
+    assert_eq!(
+        *observer.events.lock().unwrap(),
+        vec![
+            "started text-to-speech",
+            "finished text-to-speech Some(200)",
+            "billed text-to-speech 5",
+            "bytes text-to-speech 5",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_execute_detailed_reads_response_headers() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "audio")
+            .header("content-type", "audio/mpeg")
+            .header("request-id", "req-123")
+            .header("history-item-id", "hist-456")
+            .header("character-cost", "5"),
+    ])
+    .await;
+
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let response = client
+        .text_to_speech("Hello")
+        .execute_detailed()
+        .await
+        .unwrap();
+
+    assert_eq!(response.audio, b"audio");
+    assert_eq!(response.request_id.as_deref(), Some("req-123"));
+    assert_eq!(response.history_item_id.as_deref(), Some("hist-456"));
+    assert_eq!(response.character_cost, Some(5));
+    assert_eq!(response.content_type.as_deref(), Some("audio/mpeg"));
+}
+
+#[tokio::test]
+async fn test_per_request_timeout() {
+    let base_url = MockServer::hanging().await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &base_url);
+
+    let result = client
+        .text_to_speech("Hello")
+        .timeout(std::time::Duration::from_millis(50))
+        .execute()
+        .await;
+    assert!(matches!(result, Err(ElevenLabsTTSError::RequestError(e)) if e.is_timeout()));
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_fails_fast() {
+    let server = MockServer::start(vec![MockResponse::new(503, "unavailable")]).await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .circuit_breaker(CircuitBreakerConfig::new(2))
+        .build()
+        .unwrap();
+
+    for _ in 0..2 {
+        let result = client.text_to_speech("Hello").execute().await;
+        assert!(matches!(
+            result,
+            Err(ElevenLabsTTSError::ApiError { status: 503, .. })
+        ));
+    }
+
+    assert_eq!(client.circuit_state(), Some(CircuitState::Open));
+    let result = client.text_to_speech("Hello").execute().await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::CircuitOpenError { .. })
+    ));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_recovers_from_cancelled_probe() {
+    let base_url = MockServer::hanging().await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&base_url)
+        .circuit_breaker(CircuitBreakerConfig::new(1).reset_timeout(std::time::Duration::ZERO))
+        .build()
+        .unwrap();
+    let timed_out = || {
+        client
+            .text_to_speech("Hello")
+            .timeout(std::time::Duration::from_millis(50))
+            .execute()
+    };
+
+    let result = timed_out().await;
+    assert!(matches!(result, Err(ElevenLabsTTSError::RequestError(e)) if e.is_timeout()));
+
+    // The probe's future is dropped mid-request, which must give its slot back
+    let cancelled = tokio::time::timeout(
+        std::time::Duration::from_millis(50),
+        client.text_to_speech("Hello").execute(),
+    )
+    .await;
+    assert!(cancelled.is_err());
+    assert_eq!(client.circuit_state(), Some(CircuitState::HalfOpen));
+
+    let result = timed_out().await;
+    assert!(matches!(result, Err(ElevenLabsTTSError::RequestError(e)) if e.is_timeout()));
+}
+
+struct RotatingKeys(std::sync::atomic::AtomicUsize);
+
+#[elevenlabs_tts::async_trait]
+impl ApiKeyProvider for RotatingKeys {
+    async fn get_key(&self) -> Result<String, ElevenLabsTTSError> {
+        let n = self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Ok(format!("key-{}", n))
+    }
+}
+
+#[tokio::test]
+async fn test_api_key_provider_is_consulted_per_request() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key_provider(RotatingKeys(std::sync::atomic::AtomicUsize::new(1)))
+        .base_url(&server.base_url)
+        .build()
+        .unwrap();
+
+    client.text_to_speech("One").execute().await.unwrap();
+    client.text_to_speech("Two").execute().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests[0].header("xi-api-key"), Some("key-1"));
+    assert_eq!(requests[1].header("xi-api-key"), Some("key-2"));
+}
+
+#[tokio::test]
+async fn test_default_and_per_request_headers() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .default_header("x-workspace", "studio")
+        .default_header("x-correlation-id", "default")
+        .build()
+        .unwrap();
+
+    client
+        .text_to_speech("Hello")
+        .header("x-correlation-id", "abc-123")
+        .execute()
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(request.header("x-workspace"), Some("studio"));
+    assert_eq!(request.header("x-correlation-id"), Some("abc-123"));
+
+    let invalid = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .default_header("bad header", "value")
+        .build();
+    assert!(matches!(
+        invalid,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_user_agent() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    client.text_to_speech("Hello").execute().await.unwrap();
+
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .app_user_agent("my-app", "1.4.0")
+        .build()
+        .unwrap();
+    client.text_to_speech("Hello").execute().await.unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].header("user-agent"),
+        Some(elevenlabs_tts::DEFAULT_USER_AGENT)
+    );
+    assert_eq!(
+        requests[1].header("user-agent").unwrap(),
+        format!("{} my-app/1.4.0", elevenlabs_tts::DEFAULT_USER_AGENT)
+    );
+}
+
+#[test]
+fn test_builder_proxy_and_root_certificate_validation() {
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .https_proxy("http://proxy.internal:3128")
+        .proxy_basic_auth("user", "secret")
+        .no_proxy("localhost,127.0.0.1")
+        .build();
+    assert!(client.is_ok());
+
+    let result = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .all_proxy("not a url")
+        .build();
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+
+    let result = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .add_root_certificate_pem("not a certificate")
+        .build();
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[cfg(not(feature = "socks"))]
+#[test]
+fn test_socks_proxy_requires_feature() {
+    let result = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .all_proxy("socks5://proxy.internal:1080")
+        .build();
+    assert!(
+        matches!(result, Err(ElevenLabsTTSError::ValidationError(msg)) if msg.contains("socks"))
+    );
+}
+
+#[cfg(feature = "blocking")]
+#[tokio::test(flavor = "multi_thread")]
+async fn test_blocking_client_shares_builder_surface() {
+    use elevenlabs_tts::blocking::ElevenLabsTTSClientBlocking;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(503, "busy"),
+        MockResponse::new(200, "audio-bytes").header("request-id", "req-1"),
+    ])
+    .await;
+    let base_url = server.base_url.clone();
+
+    let response = tokio::task::spawn_blocking(move || {
+        let client = ElevenLabsTTSClientBlocking::builder()
+            .api_key("test-key")
+            .base_url(base_url)
+            .retry_policy(RetryPolicy::new(2).base_delay(std::time::Duration::from_millis(1)))
+            .build()
+            .unwrap();
+        client
+            .text_to_speech("Hello")
+            .voice_id("voice-123")
+            .header("x-correlation-id", "abc")
+            .execute_detailed()
+    })
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(response.audio, b"audio-bytes");
+    assert_eq!(response.request_id.as_deref(), Some("req-1"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[1].path,
+        "/v1/text-to-speech/voice-123?output_format=mp3_44100_128"
+    );
+    assert_eq!(requests[1].header("xi-api-key"), Some("test-key"));
+    assert_eq!(requests[1].header("x-correlation-id"), Some("abc"));
+}
+
+#[tokio::test]
+async fn test_on_progress_reports_received_bytes() {
+    use std::sync::{Arc, Mutex};
+
+    let server = MockServer::start(vec![MockResponse::new(200, "streamed-audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let reports: Arc<Mutex<Vec<u64>>> = Arc::default();
+    let recorded = reports.clone();
+    let audio = client
+        .text_to_speech("Hello")
+        .on_progress(move |bytes, _elapsed| recorded.lock().unwrap().push(bytes))
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(audio, b"streamed-audio");
+    assert_eq!(reports.lock().unwrap().last(), Some(&14));
+
+    reports.lock().unwrap().clear();
+    let recorded = reports.clone();
+    let mut buffer = Vec::new();
+    client
+        .text_to_speech("Hello")
+        .on_progress(move |bytes, _elapsed| recorded.lock().unwrap().push(bytes))
+        .execute_to_writer(&mut buffer)
+        .await
+        .unwrap();
+    let reports = reports.lock().unwrap();
+    assert!(reports.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(reports.last(), Some(&14));
+}
+
+#[tokio::test]
+async fn test_execute_reader_reads_streamed_audio() {
+    use tokio::io::AsyncReadExt;
+
+    let server = MockServer::start(vec![MockResponse::new(200, "streamed-audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let mut reader = client
+        .text_to_speech("Hello")
+        .execute_reader()
+        .await
+        .unwrap();
+    let mut head = [0u8; 8];
+    reader.read_exact(&mut head).await.unwrap();
+    assert_eq!(&head, b"streamed");
+    let mut rest = Vec::new();
+    tokio::io::copy(&mut reader, &mut rest).await.unwrap();
+    assert_eq!(rest, b"-audio");
+
+    let stream: AudioStream = Box::pin(futures_util::stream::iter(vec![
+        Ok(bytes::Bytes::from_static(b"partial")),
+        Err(ElevenLabsTTSError::ValidationError("stream broke".into())),
+    ]));
+    let mut reader = elevenlabs_tts::AudioReader::new(stream);
+    let error = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+    let cause = error
+        .get_ref()
+        .unwrap()
+        .downcast_ref::<ElevenLabsTTSError>();
+    assert!(matches!(
+        cause,
+        Some(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_execute_stream_to_channel_forwards_chunks() {
+    let server = MockServer::start(vec![MockResponse::new(200, "streamed-audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let (sender, mut receiver) = tokio::sync::mpsc::channel::<bytes::Bytes>(1);
+    let consumer = tokio::spawn(async move {
+        let mut audio = Vec::new();
+        while let Some(chunk) = receiver.recv().await {
+            audio.extend_from_slice(&chunk);
+        }
+        audio
+    });
+    let sent = client
+        .text_to_speech("Hello")
+        .execute_stream_to_channel(sender)
+        .await
+        .unwrap();
+    assert_eq!(sent, 14);
+    // The channel closes once the stream is done
+    assert_eq!(consumer.await.unwrap(), b"streamed-audio");
+
+    let (sender, receiver) = tokio::sync::mpsc::channel::<bytes::Bytes>(1);
+    drop(receiver);
+    let result = client
+        .text_to_speech("Hello")
+        .execute_stream_to_channel(sender)
+        .await;
+    assert!(matches!(result, Err(ElevenLabsTTSError::IoError(_))));
+}
+
+#[tokio::test]
+async fn test_execute_to_writer_and_file_stream_audio() {
+    let server = MockServer::start(vec![MockResponse::new(200, "streamed-audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let mut buffer: Vec<u8> = Vec::new();
+    let written = client
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .execute_to_writer(&mut buffer)
+        .await
+        .unwrap();
+    assert_eq!(written, 14);
+    assert_eq!(buffer, b"streamed-audio");
+    assert_eq!(
+        server.requests()[0].path,
+        "/v1/text-to-speech/voice-123/stream?output_format=mp3_44100_128"
+    );
+
+    let path = std::env::temp_dir().join(format!("elevenlabs-tts-{}.mp3", std::process::id()));
+    client
+        .text_to_speech("Hello")
+        .execute_to_file(&path)
+        .await
+        .unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), b"streamed-audio");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_execute_to_file_does_not_create_file_on_api_error() {
+    let server = MockServer::start(vec![MockResponse::new(400, "bad request")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let path = std::env::temp_dir().join(format!("elevenlabs-tts-err-{}.mp3", std::process::id()));
+    let result = client.text_to_speech("Hello").execute_to_file(&path).await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ApiError { status: 400, .. })
+    ));
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn test_enable_logging_is_sent_as_query_parameter() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    client
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .execute()
+        .await
+        .unwrap();
+    client
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .enable_logging(false)
+        .execute()
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v1/text-to-speech/voice-123?output_format=mp3_44100_128"
+    );
+    assert_eq!(
+        requests[1].path,
+        "/v1/text-to-speech/voice-123?output_format=mp3_44100_128&enable_logging=false"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert!(body.get("enable_logging").is_none());
+}
+
+#[tokio::test]
+async fn test_output_format_is_sent_as_query_parameter() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    client
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .output_format("ulaw_8000".parse().unwrap())
+        .execute()
+        .await
+        .unwrap();
+
+    let request = &server.requests()[0];
+    assert_eq!(
+        request.path,
+        "/v1/text-to-speech/voice-123?output_format=ulaw_8000"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert!(body.get("output_format").is_none());
+}
+
+#[test]
+fn test_output_format_round_trips_through_strings() {
+    assert_eq!(OutputFormat::Pcm_24000.as_str(), "pcm_24000");
+    assert_eq!(
+        "opus_48000_64".parse::<OutputFormat>().unwrap(),
+        OutputFormat::Opus_48000_64
+    );
+    assert!(matches!(
+        "mp3_1".parse::<OutputFormat>(),
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+    for format in OutputFormat::ALL {
+        assert_eq!(format.as_str().parse::<OutputFormat>().unwrap(), *format);
+    }
+}
+
+#[test]
+fn test_model_id_accepts_variants_and_strings() {
+    use elevenlabs_tts::models::ModelId;
+
+    assert_eq!(ModelId::ElevenFlashV2_5.as_str(), "eleven_flash_v2_5");
+    assert_eq!(ModelId::from("eleven_v3"), ModelId::ElevenV3);
+    assert_eq!(
+        ModelId::from("eleven_v4_preview"),
+        ModelId::Custom("eleven_v4_preview".to_string())
+    );
+
+    let client = ElevenLabsTTSClient::new("test-key");
+    let request = client
+        .dialogue([("voice-1", "Hi")])
+        .model(ModelId::ElevenV3)
+        .build_request()
+        .unwrap();
+    assert_eq!(request.model_id, "eleven_v3");
+
+    let request = client
+        .music("calm piano")
+        .model(ModelId::Custom("music_v2".to_string()))
+        .build_request()
+        .unwrap();
+    assert_eq!(request.model_id, "music_v2");
+}
+
+#[tokio::test]
+async fn test_text_normalization_is_typed() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    client
+        .text_to_speech("Call 555-0100")
+        .text_normalization(TextNormalization::On)
+        .execute()
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[0].body).unwrap();
+    assert_eq!(body["apply_text_normalization"], "on");
+
+    // The string setter is still accepted, but unknown modes never reach the API
+    let result = client
+        .text_to_speech("Hello")
+        .apply_text_normalization("sometimes")
+        .execute()
+        .await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_language_code_is_validated_before_sending() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    client
+        .text_to_speech("Bonjour")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .language_code(LanguageCode::FR)
+        .execute()
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[0].body).unwrap();
+    assert_eq!(body["language_code"], "fr");
+
+    let invalid = client
+        .text_to_speech("Bonjour")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .language_code("french")
+        .execute()
+        .await;
+    assert!(matches!(
+        invalid,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+
+    // eleven_multilingual_v2 (the default model) doesn't enforce languages
+    let unsupported = client
+        .text_to_speech("Bonjour")
+        .language_code("fr")
+        .execute()
+        .await;
+    assert!(
+        matches!(unsupported, Err(ElevenLabsTTSError::ValidationError(msg)) if msg.contains("eleven_multilingual_v2"))
+    );
+
+    assert_eq!(server.requests().len(), 1);
+    assert!(!models::ModelId::ElevenMultilingualV2.supports_language_code());
+    assert!(
+        client
+            .dialogue([("voice-1", "Hola")])
+            .language_code("e5")
+            .build_request()
+            .is_err()
+    );
+}
+
+#[test]
+fn test_style_presets() {
+    let presets = [
+        VoiceSettings::narration(),
+        VoiceSettings::energetic(),
+        VoiceSettings::calm(),
+        VoiceSettings::news(),
+    ];
+    for preset in &presets {
+        preset.validate().unwrap();
+    }
+    let [narration, energetic, calm, news] = presets;
+    assert!(energetic.stability < narration.stability && energetic.style > narration.style);
+    assert!(calm.speed < narration.speed && energetic.speed > narration.speed);
+    assert!(news.stability > narration.stability);
+    // Presets are starting points that can still be tweaked
+    assert_eq!(VoiceSettings::calm().speed(0.8).speed, Some(0.8));
+}
+
+#[test]
+fn test_stability_presets() {
+    assert_eq!(VoiceSettings::creative().stability, Some(0.0));
+    assert_eq!(VoiceSettings::natural().stability, Some(0.5));
+    assert_eq!(VoiceSettings::robust().stability, Some(1.0));
+    assert_eq!(
+        VoiceSettings::default()
+            .stability_preset(Stability::Robust)
+            .stability,
+        Some(1.0)
+    );
+    assert_eq!(
+        elevenlabs_tts::DialogueSettings::with_stability(Stability::Creative).stability,
+        Some(0.0)
+    );
+}
+
+#[test]
+fn test_validate_catches_invalid_requests_locally() {
+    let client = ElevenLabsTTSClient::new("test-key");
+    let invalid = |builder: elevenlabs_tts::TextToSpeechBuilder| {
+        matches!(
+            builder.validate(),
+            Err(ElevenLabsTTSError::ValidationError(_))
+        )
+    };
+
+    assert!(client.text_to_speech("Hello").validate().is_ok());
+    assert!(invalid(client.text_to_speech("   ")));
+    assert!(invalid(
+        client
+            .text_to_speech("a".repeat(5_001))
+            .model(models::ModelId::ElevenV3)
+    ));
+    assert!(
+        client
+            .text_to_speech("a".repeat(5_001))
+            .model(models::ModelId::ElevenFlashV2_5)
+            .validate()
+            .is_ok()
+    );
+    assert!(invalid(
+        client.text_to_speech("Hello").previous_request_ids(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "d".to_string()
+        ])
+    ));
+    assert!(invalid(
+        client
+            .text_to_speech("Hello")
+            .previous_text("Before")
+            .previous_request_ids(vec!["a".to_string()])
+    ));
+    assert!(invalid(
+        client
+            .text_to_speech("Hello")
+            .next_request_ids(vec!["a".to_string()])
+            .next_text("After")
+    ));
+    assert!(
+        client
+            .text_to_speech("Hello")
+            .previous_text("Before")
+            .next_request_ids(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+            .validate()
+            .is_ok()
+    );
+    assert!(invalid(
+        client
+            .text_to_speech("Hello")
+            .voice_settings(VoiceSettings::default().speed(2.0))
+    ));
+}
+
+#[tokio::test]
+async fn test_builders_inherit_client_defaults() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .default_voice(&voices::all_voices::ADAM)
+        .default_model(models::ModelId::ElevenFlashV2_5)
+        .default_output_format(OutputFormat::Pcm_16000)
+        .default_voice_settings(VoiceSettings::robust())
+        .build()
+        .unwrap();
+
+    client.text_to_speech("Hello").execute().await.unwrap();
+    // Builder calls still override the defaults
+    client
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .output_format(OutputFormat::Mp3_44100_64)
+        .execute()
+        .await
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        format!(
+            "/v1/text-to-speech/{}?output_format=pcm_16000",
+            voices::all_voices::ADAM.voice_id
+        )
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(body["model_id"], "eleven_flash_v2_5");
+    assert_eq!(body["voice_settings"]["stability"], 1.0);
+    assert_eq!(
+        requests[1].path,
+        "/v1/text-to-speech/voice-123?output_format=mp3_44100_64"
+    );
+
+    let request = client.music("calm piano").build_request().unwrap();
+    assert_eq!(request.output_format, OutputFormat::Pcm_16000);
+}
+
+#[test]
+fn test_to_http_parts_describes_request_without_sending() {
+    let client = ElevenLabsTTSClient::with_base_url("secret-key", "http://localhost:1/v1");
+    let parts = client
+        .text_to_speech("Hello")
+        .voice_id("voice-123")
+        .enable_logging(false)
+        .header("x-correlation-id", "abc")
+        .to_http_parts()
+        .unwrap();
+
+    assert_eq!(parts.method, "POST");
+    assert_eq!(parts.url, "http://localhost:1/v1/text-to-speech/voice-123");
+    assert_eq!(
+        parts.query,
+        vec![
+            ("output_format".to_string(), "mp3_44100_128".to_string()),
+            ("enable_logging".to_string(), "false".to_string()),
+        ]
+    );
+    assert_eq!(parts.header("xi-api-key"), Some("[REDACTED]"));
+    assert_eq!(parts.header("x-correlation-id"), Some("abc"));
+    assert!(!format!("{:?}", parts).contains("secret-key"));
+    assert_eq!(parts.body["text"], "Hello");
+    assert_eq!(parts.body["model_id"], "eleven_multilingual_v2");
+
+    let parts = client
+        .music("calm piano")
+        .duration_ms(30_000)
+        .to_http_parts()
+        .unwrap();
+    assert_eq!(parts.url, "http://localhost:1/v1/music");
+    assert_eq!(parts.body["music_length_ms"], 30_000);
+}
+
+#[tokio::test]
+async fn test_execute_output_knows_its_format() {
+    let server = MockServer::start(vec![MockResponse::new(200, "pcm-samples")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let output = client
+        .text_to_speech("Hello")
+        .output_format(OutputFormat::Pcm_24000)
+        .execute_output()
+        .await
+        .unwrap();
+    assert_eq!(output.format(), OutputFormat::Pcm_24000);
+    assert_eq!(output.sample_rate(), 24_000);
+    assert_eq!(output.channels(), 1);
+    assert_eq!(output.extension(), "pcm");
+
+    let path = std::env::temp_dir().join(format!(
+        "elevenlabs-tts-output-{}.{}",
+        std::process::id(),
+        output.extension()
+    ));
+    output.save(&path).await.unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), b"pcm-samples");
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(output.into_bytes(), b"pcm-samples");
+
+    assert_eq!(OutputFormat::Mp3_44100_192.bitrate_kbps(), Some(192));
+    assert_eq!(OutputFormat::Ulaw_8000.bitrate_kbps(), None);
+    assert!(OutputFormat::Alaw_8000.is_raw());
+    // Saved files are WAV for PCM and Ogg for Opus
+    assert_eq!(OutputFormat::Pcm_24000.file_extension(), "wav");
+    assert_eq!(OutputFormat::Opus_48000_64.file_extension(), "ogg");
+    assert_eq!(OutputFormat::Mp3_44100_128.file_extension(), "mp3");
+}
+
+#[tokio::test]
+async fn test_execute_to_path_appends_extension_and_wraps_pcm() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "mp3-bytes"),
+        MockResponse::new(200, "\x01\x00\x02\x00"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let dir = std::env::temp_dir().join(format!("elevenlabs-tts-path-{}", std::process::id()));
+
+    let mp3 = client
+        .text_to_speech("Hello")
+        .execute_to_path(dir.join("nested/clip"))
+        .await
+        .unwrap();
+    assert_eq!(mp3, dir.join("nested/clip.mp3"));
+    assert_eq!(std::fs::read(&mp3).unwrap(), b"mp3-bytes");
+
+    let wav = client
+        .text_to_speech("Hello")
+        .output_format(OutputFormat::Pcm_16000)
+        .execute_to_path(dir.join("clip"))
+        .await
+        .unwrap();
+    assert_eq!(wav, dir.join("clip.wav"));
+    let bytes = std::fs::read(&wav).unwrap();
+    assert_eq!(bytes.len(), 48);
+    assert_eq!(&bytes[0..4], b"RIFF");
+    assert_eq!(
+        u32::from_le_bytes(bytes[24..28].try_into().unwrap()),
+        16_000
+    );
+    assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 4);
+    assert_eq!(&bytes[44..], b"\x01\x00\x02\x00");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_cost_estimation() {
+    let client = ElevenLabsTTSClient::new("test-key");
+
+    let estimate = client
+        .text_to_speech("Héllo wörld")
+        .previous_text("Not billed")
+        .estimate_cost()
+        .unwrap();
+    assert_eq!(estimate.characters, 11);
+    assert_eq!(estimate.credits, Some(11.0));
+
+    let flash = client
+        .text_to_speech("Hello")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .build_request()
+        .unwrap();
+    let custom = client
+        .text_to_speech("Hello")
+        .model("my-custom-model")
+        .build_request()
+        .unwrap();
+    assert_eq!(cost::estimate_cost(&flash).credits, Some(2.5));
+    assert_eq!(cost::estimate_cost(&custom).credits, None);
+
+    let total = cost::estimate_total([&flash, &flash]);
+    assert_eq!(total.characters, 10);
+    assert_eq!(total.credits, Some(5.0));
+    assert_eq!(cost::estimate_total([&flash, &custom]).credits, None);
+}
+
+#[test]
+fn test_split_text_is_sentence_aligned() {
+    use elevenlabs_tts::long_form::split_text;
+
+    assert_eq!(
+        split_text("First sentence. Second one!\nThird?", 30),
+        ["First sentence. Second one!", "Third?"]
+    );
+    assert_eq!(split_text("Dr. Who", 100), ["Dr. Who"]);
+    assert_eq!(
+        split_text("a verylongword", 5),
+        ["a", "veryl", "ongwo", "rd"]
+    );
+    assert!(split_text("   ", 10).is_empty());
+}
+
+#[tokio::test]
+async fn test_long_form_chains_chunks_with_context() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "one-"),
+        MockResponse::new(200, "two-"),
+        MockResponse::new(200, "three"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let audio = client
+        .text_to_speech("Alpha beta. Gamma delta. Epsilon zeta.")
+        .output_format(OutputFormat::Ulaw_8000)
+        .long_form()
+        .max_chunk_characters(13)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(audio, b"one-two-three");
+
+    let bodies: Vec<serde_json::Value> = server
+        .requests()
+        .iter()
+        .map(|request| serde_json::from_slice(&request.body).unwrap())
+        .collect();
+    assert_eq!(bodies.len(), 3);
+    assert_eq!(bodies[0]["text"], "Alpha beta.");
+    assert!(bodies[0]["previous_text"].is_null());
+    assert_eq!(bodies[0]["next_text"], "Gamma delta.");
+    assert_eq!(bodies[1]["previous_text"], "Alpha beta.");
+    assert_eq!(bodies[1]["next_text"], "Epsilon zeta.");
+    assert_eq!(bodies[2]["previous_text"], "Gamma delta.");
+    assert!(bodies[2]["next_text"].is_null());
+}
+
+#[tokio::test]
+async fn test_stitched_session_chains_request_ids() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "a").header("request-id", "req-1"),
+        MockResponse::new(200, "b").header("request-id", "req-2"),
+        MockResponse::new(200, "c").header("request-id", "req-3"),
+        MockResponse::new(200, "A").header("request-id", "req-4"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let mut session = client.stitched_session(|tts| tts.voice(&voices::all_voices::RACHEL));
+    session.generate("One.").await.unwrap();
+    session.generate("Two.").await.unwrap();
+    session.generate("Three.").await.unwrap();
+    let fixed = session.regenerate(0).await.unwrap();
+    assert_eq!(fixed.response.request_id.as_deref(), Some("req-4"));
+    assert_eq!(session.audio(), b"Abc");
+    assert!(session.regenerate(5).await.is_err());
+
+    let requests = server.requests();
+    assert!(
+        requests[0]
+            .path
+            .contains(voices::all_voices::RACHEL.voice_id)
+    );
+    let bodies: Vec<serde_json::Value> = requests
+        .iter()
+        .map(|request| serde_json::from_slice(&request.body).unwrap())
+        .collect();
+    assert!(bodies[0]["previous_request_ids"].is_null());
+    assert_eq!(
+        bodies[1]["previous_request_ids"],
+        serde_json::json!(["req-1"])
+    );
+    assert_eq!(
+        bodies[2]["previous_request_ids"],
+        serde_json::json!(["req-1", "req-2"])
+    );
+    assert_eq!(bodies[3]["text"], "One.");
+    assert!(bodies[3]["previous_request_ids"].is_null());
+    assert_eq!(
+        bodies[3]["next_request_ids"],
+        serde_json::json!(["req-2", "req-3"])
+    );
+}
+
+#[tokio::test]
+async fn test_batch_returns_results_in_order_with_retries() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "one"),
+        MockResponse::new(503, r#"{"detail":"busy"}"#),
+        MockResponse::new(400, r#"{"detail":"bad text"}"#),
+        MockResponse::new(200, "two"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let results = client
+        .batch([
+            BatchJob::from(("First", &voices::all_voices::ADAM)),
+            BatchJob::new("Second").voice_settings(VoiceSettings::robust()),
+            BatchJob::new("Third"),
+        ])
+        .job(("", &voices::all_voices::RACHEL))
+        .concurrency(1)
+        .retry_policy(RetryPolicy::new(2).base_delay(std::time::Duration::from_millis(1)))
+        .configure(|tts| tts.voice(&voices::all_voices::RACHEL))
+        .execute()
+        .await;
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().audio, b"one");
+    // 503 is retried, 400 is not
+    assert!(matches!(
+        results[1],
+        Err(ElevenLabsTTSError::ApiError { status: 400, .. })
+    ));
+    assert_eq!(results[2].as_ref().unwrap().audio, b"two");
+    assert!(matches!(
+        results[3],
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].path.contains(voices::all_voices::ADAM.voice_id));
+    assert!(
+        requests[1]
+            .path
+            .contains(voices::all_voices::RACHEL.voice_id)
+    );
+    let second: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(second["voice_settings"]["stability"], 1.0);
+}
+
+#[tokio::test]
+async fn test_cache_answers_repeated_requests() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "first"),
+        MockResponse::new(200, "second"),
+    ])
+    .await;
+    let cache = std::sync::Arc::new(MemoryCache::new(1));
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .cache(cache.clone())
+        .build()
+        .unwrap();
+
+    let first = client.text_to_speech("Hello").execute().await.unwrap();
+    let again = client.text_to_speech("Hello").execute().await.unwrap();
+    assert_eq!(first, b"first");
+    assert_eq!(again, b"first");
+    assert_eq!(server.requests().len(), 1);
+
+    // A different request misses, and evicts the least recently used clip
+    let other = client.text_to_speech("Bye").execute().await.unwrap();
+    assert_eq!(other, b"second");
+    assert_eq!(cache.len(), 1);
+    client.text_to_speech("Hello").execute().await.unwrap();
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_disk_cache_round_trip() {
+    let dir = std::env::temp_dir().join(format!("elevenlabs-tts-cache-{}", std::process::id()));
+    let cache = DiskCache::new(&dir);
+
+    assert!(cache.get("key").await.is_none());
+    cache.put("key", b"audio").await;
+    assert_eq!(cache.get("key").await.unwrap(), b"audio");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_tts_api_trait_can_be_mocked() {
+    struct FakeApi;
+
+    #[async_trait]
+    impl TtsApi for FakeApi {
+        async fn synthesize(
+            &self,
+            request: TTSRequest,
+        ) -> Result<AudioResponse, ElevenLabsTTSError> {
+            Ok(AudioResponse {
+                audio: request.text.into_bytes(),
+                request_id: Some("fake".to_string()),
+                history_item_id: None,
+                character_cost: None,
+                content_type: None,
+            })
+        }
+
+        async fn synthesize_stream(
+            &self,
+            _: TTSRequest,
+        ) -> Result<AudioStream, ElevenLabsTTSError> {
+            unimplemented!()
+        }
+
+        async fn synthesize_dialogue(
+            &self,
+            _: TextToDialogueRequest,
+        ) -> Result<Vec<u8>, ElevenLabsTTSError> {
+            unimplemented!()
+        }
+
+        async fn compose_music(&self, _: MusicRequest) -> Result<Vec<u8>, ElevenLabsTTSError> {
+            unimplemented!()
+        }
+
+        async fn compose_music_stream(
+            &self,
+            _: MusicRequest,
+        ) -> Result<AudioStream, ElevenLabsTTSError> {
+            unimplemented!()
+        }
+
+        async fn conversation_signed_url(
+            &self,
+            agent_id: &str,
+        ) -> Result<String, ElevenLabsTTSError> {
+            Ok(format!("wss://fake/{}", agent_id))
+        }
+    }
+
+    async fn narrate(api: &dyn TtsApi, request: TTSRequest) -> Vec<u8> {
+        api.synthesize(request).await.unwrap().audio
+    }
+
+    let server = MockServer::start(vec![MockResponse::new(200, "real-audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let request = client.text_to_speech("Hello").build_request().unwrap();
+
+    assert_eq!(narrate(&FakeApi, request.clone()).await, b"Hello");
+    assert_eq!(narrate(&client, request).await, b"real-audio");
+    assert_eq!(
+        FakeApi.conversation_signed_url("agent").await.unwrap(),
+        "wss://fake/agent"
+    );
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn test_cassette_records_then_replays_offline() {
+    use elevenlabs_tts::test_utils::Cassette;
+
+    let fixture = std::env::temp_dir().join(format!(
+        "elevenlabs-tts-cassette-{}.json",
+        std::process::id()
+    ));
+    let server = MockServer::start(vec![
+        MockResponse::new(200, "recorded-audio").header("request-id", "req-1"),
+    ])
+    .await;
+
+    let recorder = Cassette::record(&fixture, &server.base_url).await.unwrap();
+    let recorded = recorder
+        .client("secret-key")
+        .text_to_speech("Hello")
+        .execute_detailed()
+        .await
+        .unwrap();
+    assert_eq!(recorded.audio, b"recorded-audio");
+    assert_eq!(
+        server.requests()[0].header("xi-api-key"),
+        Some("secret-key")
+    );
+    assert!(
+        !std::fs::read_to_string(&fixture)
+            .unwrap()
+            .contains("secret-key")
+    );
+
+    let player = Cassette::replay(&fixture).await.unwrap();
+    let client = player.client("");
+    let replayed = client
+        .text_to_speech("Hello")
+        .execute_detailed()
+        .await
+        .unwrap();
+    assert_eq!(replayed.audio, b"recorded-audio");
+    assert_eq!(replayed.request_id.as_deref(), Some("req-1"));
+    assert_eq!(server.requests().len(), 1);
+
+    let missing = client.text_to_speech("Unrecorded").execute().await;
+    assert!(matches!(
+        missing,
+        Err(ElevenLabsTTSError::ApiError { status: 404, .. })
+    ));
+
+    std::fs::remove_file(&fixture).unwrap();
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn test_mock_client_returns_deterministic_audio() {
+    let client = ElevenLabsTTSClient::mock();
+
+    let first = client
+        .text_to_speech("Hello there")
+        .output_format(OutputFormat::Pcm_16000)
+        .execute_detailed()
+        .await
+        .unwrap();
+    let again = client
+        .text_to_speech("Hello there")
+        .output_format(OutputFormat::Pcm_16000)
+        .execute_detailed()
+        .await
+        .unwrap();
+    assert_eq!(first.audio, again.audio);
+    assert_eq!(first.request_id, again.request_id);
+    assert_eq!(first.character_cost, Some(11));
+    // 11 characters at 60ms, 16-bit samples at 16kHz
+    assert_eq!(first.audio.len(), 660 * 16 * 2);
+    assert!(first.audio.iter().any(|&byte| byte != 0));
+
+    let mp3 = client.text_to_speech("Hi").execute().await.unwrap();
+    assert_eq!(&mp3[..2], &[0xFF, 0xFB]);
+
+    let dialogue = client
+        .dialogue([(voices::all_voices::ADAM.voice_id, "Hi")])
+        .output_format(OutputFormat::Ulaw_8000)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(dialogue.len(), 4000);
+
+    let timed = client
+        .text_to_speech("Hi")
+        .execute_with_timestamps()
+        .await
+        .unwrap();
+    assert_eq!(timed.alignment.unwrap().characters, ["H", "i"]);
+    assert_eq!(&timed.audio[..2], &[0xFF, 0xFB]);
+
+    let url = client
+        .conversational_ai()
+        .signed_url("agent")
+        .await
+        .unwrap();
+    assert!(url.contains("agent_id=agent"));
+}
+
+#[test]
+fn test_audio_tags_compose_request_text() {
+    assert_eq!(AudioTag::new(" [Sighs] ").unwrap().name(), "Sighs");
+    assert!(AudioTag::new("").is_err());
+    assert!(AudioTag::new("laughs] [cries").is_err());
+    assert!(AudioTag::new("a".repeat(41)).is_err());
+
+    let client = ElevenLabsTTSClient::new("test-key");
+    let request = client
+        .text_to_speech(
+            TaggedText::new()
+                .tagged(AudioTag::EXCITED, "We won!")
+                .tag(AudioTag::LONG_PAUSE)
+                .text("  Now what?  "),
+        )
+        .model(models::ModelId::ElevenV3)
+        .build_request()
+        .unwrap();
+    assert_eq!(request.text, "[excited] We won! [long pause] Now what?");
+
+    let turn = DialogueInput::new("voice", "Psst.").audio_tag(&AudioTag::WHISPERS);
+    assert_eq!(turn.text, "[whispers] Psst.");
+}
+
+#[test]
+fn test_pronunciation_rules_and_phoneme_validation() {
+    assert!(
+        PhonemeAlphabet::CmuArpabet
+            .validate("T AH0 M EY1 T OW2")
+            .is_ok()
+    );
+    assert!(PhonemeAlphabet::CmuArpabet.validate("T AH4").is_err());
+    assert!(PhonemeAlphabet::CmuArpabet.validate("").is_err());
+    assert!(PhonemeAlphabet::Ipa.validate("təˈmeɪtoʊ").is_ok());
+    assert!(PhonemeAlphabet::Ipa.validate("t\"><b").is_err());
+
+    let rules = PronunciationRules::new()
+        .phoneme("tomato", PhonemeAlphabet::CmuArpabet, "T AH0  M EY1 T OW2")
+        .unwrap()
+        .alias("ASAP", "as soon as possible")
+        .unwrap();
+    assert!(rules.clone().alias("two words", "x").is_err());
+    assert_eq!(
+        rules.apply("Buy tomatoes, a tomato, ASAP!"),
+        r#"Buy tomatoes, a <phoneme alphabet="cmu-arpabet" ph="T AH0 M EY1 T OW2">tomato</phoneme>, as soon as possible!"#
+    );
+
+    let client = ElevenLabsTTSClient::new("test-key");
+    let request = client
+        .text_to_speech("One tomato")
+        .pronunciation(&rules)
+        .model(models::ModelId::ElevenFlashV2)
+        .build_request()
+        .unwrap();
+    assert!(request.text.contains("<phoneme"));
+
+    let unsupported = client
+        .text_to_speech("One tomato")
+        .pronunciation(&rules)
+        .model(models::ModelId::ElevenMultilingualV2)
+        .validate();
+    assert!(matches!(
+        unsupported,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_execute_with_timestamps_and_subtitles() {
+    let text = "Hello world. Bye";
+    let body = serde_json::json!({
+        "audio_base64": "YXVkaW8=",
+        "alignment": {
+            "characters": text.chars().map(String::from).collect::<Vec<_>>(),
+            "character_start_times_seconds": (0..16).map(|i| i as f64 * 0.25).collect::<Vec<_>>(),
+            "character_end_times_seconds": (1..17).map(|i| i as f64 * 0.25).collect::<Vec<_>>(),
+        },
+        "normalized_alignment": null,
+    });
+    let server = MockServer::start(vec![
+        MockResponse::new(200, body.to_string()).header("request-id", "req-1"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let result = client
+        .text_to_speech(text)
+        .execute_with_timestamps()
+        .await
+        .unwrap();
+    assert_eq!(result.audio, b"audio");
+    assert_eq!(result.request_id.as_deref(), Some("req-1"));
+    assert!(result.normalized_alignment.is_none());
+    assert!(server.requests()[0].path.contains("/with-timestamps"));
+
+    let alignment = result.alignment.unwrap();
+    assert_eq!(
+        subtitles::srt(&alignment, &SubtitleOptions::default()),
+        "1\n00:00:00,000 --> 00:00:03,000\nHello world.\n\n2\n00:00:03,250 --> 00:00:04,000\nBye\n\n"
+    );
+    let narrow = SubtitleOptions::default()
+        .max_line_length(6)
+        .max_lines(1)
+        .break_on_sentences(false);
+    assert_eq!(
+        subtitles::vtt(&alignment, &narrow),
+        "WEBVTT\n\n00:00:00.000 --> 00:00:01.250\nHello\n\n00:00:01.500 --> 00:00:03.000\nworld.\n\n00:00:03.250 --> 00:00:04.000\nBye\n\n"
+    );
+    let short = SubtitleOptions::default().max_duration(std::time::Duration::from_secs(2));
+    assert_eq!(subtitles::cues(&alignment, &short).len(), 3);
+}
+
+#[test]
+fn test_word_timestamps_handle_punctuation() {
+    let text = "Well—don't  stop... 3.5 \"ok\" !";
+    let count = text.chars().count();
+    let alignment = Alignment {
+        characters: text.chars().map(String::from).collect(),
+        character_start_times_seconds: (0..count).map(|i| i as f64).collect(),
+        character_end_times_seconds: (0..count).map(|i| i as f64 + 0.5).collect(),
+    };
+
+    let words: Vec<(String, f64, f64)> = alignment
+        .words()
+        .into_iter()
+        .map(|word| (word.word, word.start, word.end))
+        .collect();
+    assert_eq!(
+        words,
+        [
+            ("Well".to_string(), 0.0, 3.5),
+            ("don't".to_string(), 5.0, 9.5),
+            ("stop".to_string(), 12.0, 15.5),
+            ("3.5".to_string(), 20.0, 22.5),
+            ("ok".to_string(), 25.0, 26.5),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_execute_wav_wraps_pcm() {
+    let server = MockServer::start(vec![MockResponse::new(200, vec![1u8, 0, 2, 0])]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+
+    let wav = client.text_to_speech("Hello").execute_wav().await.unwrap();
+    assert!(
+        server.requests()[0]
+            .path
+            .ends_with("output_format=pcm_24000")
+    );
+    assert_eq!(&wav[..4], b"RIFF");
+    assert_eq!(&wav[8..12], b"WAVE");
+    assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 24_000);
+    assert_eq!(&wav[44..], &[1, 0, 2, 0]);
+
+    let mp3 = client
+        .text_to_speech("Hello")
+        .output_format(OutputFormat::Mp3_44100_128)
+        .execute_wav()
+        .await;
+    assert!(matches!(mp3, Err(ElevenLabsTTSError::ValidationError(_))));
+
+    let stereo = elevenlabs_tts::audio::wrap_wav(&[0; 8], 48_000, 2).unwrap();
+    assert_eq!(u16::from_le_bytes(stereo[22..24].try_into().unwrap()), 2);
+    assert_eq!(
+        u32::from_le_bytes(stereo[28..32].try_into().unwrap()),
+        192_000
+    );
+    assert!(elevenlabs_tts::audio::wrap_wav(&[], 16_000, 0).is_err());
+}
+
+#[test]
+fn test_g711_conversion_and_framing() {
+    use elevenlabs_tts::audio;
+
+    let pcm: Vec<u8> = (0..800i16)
+        .flat_map(|i| (i * 40 - 16_000).to_le_bytes())
+        .collect();
+    let ulaw = audio::pcm_to_ulaw(&pcm);
+    let alaw = audio::pcm_to_alaw(&pcm);
+    assert_eq!(ulaw.len(), 800);
+    assert_eq!(audio::ulaw_to_pcm(&ulaw).len(), pcm.len());
+    assert_eq!(audio::alaw_to_pcm(&alaw).len(), pcm.len());
+
+    let frames: Vec<&[u8]> = audio::telephony_frames(&ulaw, OutputFormat::Ulaw_8000)
+        .unwrap()
+        .collect();
+    assert_eq!(frames.len(), 5);
+    assert!(frames.iter().all(|frame| frame.len() == 160));
+
+    let pcm_frames = audio::telephony_frames(&pcm, OutputFormat::Pcm_16000).unwrap();
+    assert_eq!(
+        pcm_frames.map(<[u8]>::len).collect::<Vec<_>>(),
+        vec![640, 640, 320]
+    );
+
+    assert!(matches!(
+        audio::telephony_frames(&ulaw, OutputFormat::Mp3_44100_128),
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn test_audio_duration_from_generated_audio() {
+    use std::time::Duration;
+
+    let client = ElevenLabsTTSClient::mock();
+    let generate = |format| {
+        client
+            .text_to_speech("Hello there")
+            .output_format(format)
+            .execute_output()
+    };
+
+    // 11 characters at 60ms each
+    let pcm = generate(OutputFormat::Pcm_16000).await.unwrap();
+    assert_eq!(pcm.duration().unwrap(), Duration::from_millis(660));
+    let ulaw = generate(OutputFormat::Ulaw_8000).await.unwrap();
+    assert_eq!(ulaw.duration().unwrap(), Duration::from_millis(660));
+
+    // The mock pads MP3 to whole 1152-sample frames at 44.1kHz
+    let mp3 = generate(OutputFormat::Mp3_44100_128).await.unwrap();
+    let frames = mp3.as_bytes().len() / 417;
+    assert_eq!(frames, 26);
+    let duration = mp3.duration().unwrap();
+    assert_eq!(duration.as_micros(), 26 * 1152 * 1_000_000 / 44_100);
+
+    assert!(elevenlabs_tts::audio::duration(b"not audio", OutputFormat::Mp3_44100_128).is_err());
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn test_websocket_streams_text_and_audio() {
+    use base64::Engine;
+    use common::websocket::MockWebSocketServer;
+    use elevenlabs_tts::websocket::GenerationConfig;
+    use std::sync::{Arc, Mutex};
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let messages = received.clone();
+    let server = MockWebSocketServer::start(move |_, mut socket| {
+        let messages = messages.clone();
+        async move {
+            while let Some(message) = socket.recv_json().await {
+                let text = message["text"].as_str().unwrap_or_default().to_string();
+                messages.lock().unwrap().push(message);
+                let audio = base64::engine::general_purpose::STANDARD.encode(text.trim());
+                if text.is_empty() {
+                    socket.send_json(serde_json::json!({"isFinal": true})).await;
+                    break;
+                } else if text != " " {
+                    socket
+                        .send_json(serde_json::json!({"audio": audio, "isFinal": null}))
+                        .await;
+                }
+            }
+            socket.close().await;
+        }
+    })
+    .await;
+
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let mut socket = client
+        .text_to_speech_websocket("voice-1")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .output_format(OutputFormat::Pcm_16000)
+        .generation_config(GenerationConfig::default().chunk_length_schedule([50, 120, 500]))
+        .auto_mode(true)
+        .connect()
+        .await
+        .unwrap();
+    socket.send_text("Hello ").await.unwrap();
+    socket.send_text("world ").await.unwrap();
+    socket.close_input().await.unwrap();
+
+    let mut chunks = Vec::new();
+    while let Some(chunk) = socket.recv().await {
+        chunks.push(chunk.unwrap());
+    }
+    let audio: Vec<&[u8]> = chunks.iter().map(|chunk| chunk.audio.as_slice()).collect();
+    assert_eq!(audio, [b"Hello".as_slice(), b"world".as_slice()]);
+    assert!(chunks.iter().all(|chunk| !chunk.is_final));
+
+    let messages = received.lock().unwrap().clone();
+    assert_eq!(
+        messages[0],
+        serde_json::json!({"text": " ", "generation_config": {"chunk_length_schedule": [50, 120, 500]}})
+    );
+    assert_eq!(messages[3], serde_json::json!({"text": ""}));
+
+    let connection = &server.connections()[0];
+    assert_eq!(
+        connection.path,
+        "/v1/text-to-speech/voice-1/stream-input?model_id=eleven_flash_v2_5&output_format=pcm_16000&auto_mode=true"
+    );
+    assert_eq!(connection.header("xi-api-key"), Some("test-key"));
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn test_websocket_errors() {
+    use common::websocket::MockWebSocketServer;
+    use std::time::Duration;
+
+    let client = ElevenLabsTTSClient::new("test-key");
+    for schedule in [vec![], vec![40, 120], vec![120, 501]] {
+        let result = client
+            .text_to_speech_websocket("voice-1")
+            .chunk_length_schedule(schedule)
+            .connect()
+            .await;
+        assert!(matches!(
+            result,
+            Err(ElevenLabsTTSError::ValidationError(_))
+        ));
+    }
+
+    // A rejected handshake maps to the same errors as HTTP calls
+    let server = MockServer::start(vec![MockResponse::new(
+        401,
+        r#"{"detail": {"status": "invalid_api_key", "message": "Invalid API key"}}"#,
+    )])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("bad-key".to_string(), server.base_url.clone());
+    let error = client
+        .text_to_speech_websocket("voice-1")
+        .connect()
+        .await
+        .err()
+        .unwrap();
+    assert!(error.is_auth_error());
+    assert_eq!(error.status_code(), Some(401));
+
+    for (timeout, keepalive) in [(0, 1), (181, 10), (20, 20), (20, 0)] {
+        let result = client
+            .text_to_speech_websocket("voice-1")
+            .inactivity_timeout(Duration::from_secs(timeout))
+            .keepalive(Duration::from_secs(keepalive))
+            .connect()
+            .await;
+        assert!(matches!(
+            result,
+            Err(ElevenLabsTTSError::ValidationError(_))
+        ));
+    }
+
+    // Errors sent by the server end the session; an idle session has its own error
+    let server = MockWebSocketServer::start(|_, mut socket| async move {
+        socket.recv_json().await;
+        socket
+            .send_json(serde_json::json!({
+                "message": "No text received for 20 seconds",
+                "error": "input_timeout_exceeded",
+                "code": 1008
+            }))
+            .await;
+    })
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let mut socket = client
+        .text_to_speech_websocket("voice-1")
+        .connect()
+        .await
+        .unwrap();
+    let error = socket.recv().await.unwrap().unwrap_err();
+    assert!(matches!(error, ElevenLabsTTSError::WebSocketTimeout(_)));
+    assert_eq!(
+        error.to_string(),
+        "WebSocket session timed out: No text received for 20 seconds"
+    );
+    assert!(socket.recv().await.is_none());
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn test_websocket_flush_and_keepalive() {
+    use common::websocket::MockWebSocketServer;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let messages = received.clone();
+    let server = MockWebSocketServer::start(move |_, mut socket| {
+        let messages = messages.clone();
+        async move {
+            while let Some(message) = socket.recv_json().await {
+                let done = message["text"] == "";
+                messages.lock().unwrap().push(message);
+                if done {
+                    socket.send_json(serde_json::json!({"isFinal": true})).await;
+                    break;
+                }
+            }
+        }
+    })
+    .await;
+
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let mut socket = client
+        .text_to_speech_websocket("voice-1")
+        .inactivity_timeout(Duration::from_secs(1))
+        .keepalive(Duration::from_millis(100))
+        .connect()
+        .await
+        .unwrap();
+    socket.send_text("Hello ").await.unwrap();
+    socket.flush().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(350)).await;
+    socket.close_input().await.unwrap();
+    assert!(socket.recv().await.is_none());
+
+    let messages = received.lock().unwrap().clone();
+    assert_eq!(messages[1], serde_json::json!({"text": "Hello "}));
+    assert_eq!(messages[2], serde_json::json!({"text": " ", "flush": true}));
+    let keepalives = &messages[3..messages.len() - 1];
+    assert!((2..=4).contains(&keepalives.len()), "{:?}", messages);
+    assert!(
+        keepalives
+            .iter()
+            .all(|m| *m == serde_json::json!({"text": " "}))
+    );
+    assert_eq!(messages.last(), Some(&serde_json::json!({"text": ""})));
+    assert!(
+        server.connections()[0]
+            .path
+            .ends_with("&inactivity_timeout=1")
+    );
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn test_websocket_reconnects_and_replays_pending_text() {
+    use base64::Engine;
+    use common::websocket::MockWebSocketServer;
+    use elevenlabs_tts::websocket::ReconnectEvent;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let encode = |text: &str| base64::engine::general_purpose::STANDARD.encode(text);
+    let received = Arc::new(Mutex::new(vec![Vec::new(), Vec::new()]));
+    let messages = received.clone();
+    let server = MockWebSocketServer::start(move |index, mut socket| {
+        let messages = messages.clone();
+        async move {
+            while let Some(message) = socket.recv_json().await {
+                let text = message["text"].as_str().unwrap_or_default().to_string();
+                messages.lock().unwrap()[index].push(message);
+                match (index, text.as_str()) {
+                    // Generate the first piece, then drop without a close frame
+                    (0, "Hello ") => {
+                        let alignment = serde_json::json!({
+                            "chars": ["H", "e", "l", "l", "o", " "],
+                            "charStartTimesMs": [0, 10, 20, 30, 40, 50],
+                            "charDurationsMs": [10, 10, 10, 10, 10, 10]
+                        });
+                        socket
+                            .send_json(serde_json::json!({"audio": encode("Hello"), "alignment": alignment}))
+                            .await;
+                    }
+                    (0, "world ") => return,
+                    (1, "") => {
+                        socket
+                            .send_json(serde_json::json!({"audio": encode("world"), "isFinal": true}))
+                            .await;
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            socket.close().await;
+        }
+    })
+    .await;
+
+    let events = Arc::new(Mutex::new(Vec::<ReconnectEvent>::new()));
+    let recorded = events.clone();
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let mut socket = client
+        .text_to_speech_websocket("voice-1")
+        .reconnect(
+            RetryPolicy::new(3)
+                .base_delay(Duration::from_millis(10))
+                .jitter(false),
+        )
+        .on_reconnect(move |event| recorded.lock().unwrap().push(event.clone()))
+        .connect()
+        .await
+        .unwrap();
+
+    socket.send_text("Hello ").await.unwrap();
+    let chunk = socket.recv().await.unwrap().unwrap();
+    assert_eq!(chunk.audio, b"Hello");
+    let alignment = chunk.alignment.unwrap();
+    assert_eq!(alignment.characters.concat(), "Hello ");
+    assert_eq!(alignment.character_start_times_seconds[1], 0.01);
+    assert_eq!(alignment.character_end_times_seconds[5], 0.06);
+    assert_eq!(alignment.words()[0].word, "Hello");
+    assert!(chunk.normalized_alignment.is_none());
+    socket.send_text("world ").await.unwrap();
+    socket.close_input().await.unwrap();
+    let chunk = socket.recv().await.unwrap().unwrap();
+    assert_eq!(chunk.audio, b"world");
+    assert!(chunk.is_final);
+    assert!(socket.recv().await.is_none());
+
+    let events = events.lock().unwrap().clone();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].attempts, 1);
+    assert_eq!(events[0].replayed_text, "world ");
+
+    // The second connection got the settings again, then only the unanswered text
+    let messages = received.lock().unwrap().clone();
+    assert_eq!(messages[1][0], serde_json::json!({"text": " "}));
+    assert_eq!(messages[1][1], serde_json::json!({"text": "world "}));
+    assert_eq!(messages[1][2], serde_json::json!({"text": ""}));
+    assert_eq!(server.connections().len(), 2);
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn test_websocket_drop_without_reconnect_policy_fails() {
+    use common::websocket::MockWebSocketServer;
+
+    let server = MockWebSocketServer::start(|_, mut socket| async move {
+        socket.recv_json().await;
+        socket.recv_json().await;
+    })
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let mut socket = client
+        .text_to_speech_websocket("voice-1")
+        .connect()
+        .await
+        .unwrap();
+    socket.send_text("Hello ").await.unwrap();
+    let error = socket.recv().await.unwrap().unwrap_err();
+    assert!(matches!(error, ElevenLabsTTSError::WebSocketError(_)));
+    assert_eq!(server.connections().len(), 1);
+}
+
+#[tokio::test]
+async fn test_synthesize_stream_generates_each_sentence_in_order() {
+    use futures_util::{StreamExt, stream};
+
+    let server = MockServer::start(vec![MockResponse::new(200, "audio;")]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let tokens = stream::iter(["Hello", " there. How", " are", " you?", " Bye"].map(String::from));
+    let audio: Vec<u8> = client
+        .text_to_speech("")
+        .voice_id("voice-1")
+        .synthesize_stream(tokens)
+        .map(|chunk| chunk.unwrap().to_vec())
+        .concat()
+        .await;
+    assert_eq!(audio, b"audio;audio;audio;");
+
+    let bodies: Vec<serde_json::Value> = server
+        .requests()
+        .iter()
+        .map(|request| serde_json::from_slice(&request.body).unwrap())
+        .collect();
+    let texts: Vec<&str> = bodies
+        .iter()
+        .map(|body| body["text"].as_str().unwrap())
+        .collect();
+    assert_eq!(texts, ["Hello there.", "How are you?", "Bye"]);
+    assert!(bodies[0]["previous_text"].is_null());
+    assert_eq!(bodies[2]["previous_text"], "How are you?");
+}
+
+#[cfg(feature = "websocket")]
+#[tokio::test]
+async fn test_websocket_synthesize_stream() {
+    use base64::Engine;
+    use common::websocket::MockWebSocketServer;
+    use futures_util::{StreamExt, stream};
+    use std::sync::{Arc, Mutex};
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let messages = received.clone();
+    let server = MockWebSocketServer::start(move |_, mut socket| {
+        let messages = messages.clone();
+        async move {
+            while let Some(message) = socket.recv_json().await {
+                let text = message["text"].as_str().unwrap_or_default().to_string();
+                messages.lock().unwrap().push(text.clone());
+                if text.is_empty() {
+                    socket.send_json(serde_json::json!({"isFinal": true})).await;
+                    break;
+                } else if text != " " {
+                    let audio = base64::engine::general_purpose::STANDARD.encode(text.trim());
+                    socket.send_json(serde_json::json!({"audio": audio})).await;
+                }
+            }
+            socket.close().await;
+        }
+    })
+    .await;
+
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let tokens = stream::iter(["One", ". Two", "! Three"].map(String::from));
+    let audio: Vec<u8> = client
+        .text_to_speech_websocket("voice-1")
+        .synthesize_stream(tokens)
+        .await
+        .unwrap()
+        .map(|chunk| chunk.unwrap().to_vec())
+        .concat()
+        .await;
+    assert_eq!(audio, b"One.Two!Three");
+    assert_eq!(
+        *received.lock().unwrap(),
+        [" ", "One. ", "Two! ", "Three ", ""]
+    );
+}
+
+#[tokio::test]
+async fn test_voice_search_sends_typed_parameters() {
+    use elevenlabs_tts::voice_search::{VoiceCategory, VoiceSort, VoiceType};
+
+    let body = r#"{
+        "voices": [{
+            "voice_id": "abc123",
+            "name": "Narrator",
+            "category": "cloned",
+            "labels": {"accent": "british"},
+            "preview_url": "https://example.com/preview.mp3",
+            "created_at_unix": 1700000000
+        }],
+        "has_more": true,
+        "total_count": 42,
+        "next_page_token": "page-2"
+    }"#;
+    let server = MockServer::start(vec![MockResponse::new(200, body)]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let page = client
+        .search_voices()
+        .search("narrator")
+        .category(VoiceCategory::Cloned)
+        .voice_type(VoiceType::NonDefault)
+        .sort(VoiceSort::CreatedAt)
+        .descending(true)
+        .page_size(50)
+        .page_token("page-1")
+        .include_total_count(true)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(page.voices[0].voice_id, "abc123");
+    assert_eq!(page.voices[0].labels["accent"], "british");
+    assert_eq!(page.total_count, Some(42));
+    assert!(page.has_more);
+    assert_eq!(page.next_page_token.as_deref(), Some("page-2"));
+
+    assert_eq!(
+        server.requests()[0].path,
+        "/v2/voices?search=narrator&category=cloned&voice_type=non-default&sort=created_at_unix\
+         &sort_direction=desc&page_size=50&next_page_token=page-1&include_total_count=true"
+    );
+
+    let result = client.search_voices().page_size(101).execute().await;
+    assert!(matches!(
+        result,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn test_mock_client_searches_premade_voices() {
+    let client = ElevenLabsTTSClient::mock();
+    let page = client
+        .search_voices()
+        .search("RACH")
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(page.voices.len(), 1);
+    assert_eq!(page.voices[0].voice_id, voices::all_voices::RACHEL.voice_id);
+
+    let page = client
+        .search_voices()
+        .page_size(50)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(page.voices.len(), 50);
+    assert_eq!(page.total_count, Some(voices::all().len() as u64));
+    let next = client
+        .search_voices()
+        .page_size(50)
+        .page_token(page.next_page_token.unwrap())
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(next.voices.len(), voices::all().len() - 50);
+    assert!(!next.has_more);
+}
+
+#[tokio::test]
+async fn test_history_list_all_follows_cursor() {
+    use futures_util::TryStreamExt;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"history": [{"history_item_id": "a"}, {"history_item_id": "b"}],
+                "last_history_item_id": "b", "has_more": true}"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"{"history": [{"history_item_id": "c", "text": "Hi", "date_unix": 1700000000}],
+                "last_history_item_id": "c", "has_more": false}"#,
+        ),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let items: Vec<HistoryItem> = client
+        .history()
+        .list()
+        .page_size(2)
+        .list_all()
+        .try_collect()
+        .await
+        .unwrap();
+    let ids: Vec<&str> = items
+        .iter()
+        .map(|item| item.history_item_id.as_str())
+        .collect();
+    assert_eq!(ids, ["a", "b", "c"]);
+    assert_eq!(items[2].text.as_deref(), Some("Hi"));
+
+    let paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.path)
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "/v1/history?page_size=2",
+            "/v1/history?page_size=2&start_after_history_item_id=b"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_history_list_filters() {
+    use elevenlabs_tts::history::HistorySource;
+
+    let server = MockServer::start(vec![MockResponse::new(200, r#"{"history": []}"#)]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let page = client
+        .history()
+        .list()
+        .voice_id("narrator")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .date_after(1_700_000_000)
+        .date_before(1_700_086_400)
+        .search("chapter")
+        .source(HistorySource::SpeechToSpeech)
+        .execute()
+        .await
+        .unwrap();
+    assert!(page.history.is_empty() && !page.has_more);
+    assert_eq!(
+        server.requests()[0].path,
+        "/v1/history?voice_id=narrator&model_id=eleven_flash_v2_5&date_after_unix=1700000000\
+         &date_before_unix=1700086400&search=chapter&source=STS"
+    );
+
+    let empty_range = client
+        .history()
+        .list()
+        .date_after(1_700_000_000)
+        .date_before(1_700_000_000);
+    assert!(empty_range.query().is_err());
+}
+
+#[tokio::test]
+async fn test_text_to_speech_from_history_item() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"history": [{
+                "history_item_id": "h1",
+                "request_id": "r1",
+                "voice_id": "voice-1",
+                "voice_name": "Narrator",
+                "model_id": "eleven_multilingual_v2",
+                "text": "Once upon a time",
+                "date_unix": 1700000000,
+                "character_count_change_from": 100,
+                "character_count_change_to": 116,
+                "content_type": "audio/mpeg",
+                "state": "created",
+                "settings": {"stability": 0.3, "similarity_boost": 0.9, "style": 0.1},
+                "source": "TTS"
+            }, {"history_item_id": "h2", "state": "archived"}], "has_more": false}"#,
+        ),
+        MockResponse::new(200, "audio"),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let page = client.history().list().execute().await.unwrap();
+    let item = &page.history[0];
+    assert_eq!(item.state, HistoryItemState::Created);
+    assert_eq!(item.model_id, Some(models::ModelId::ElevenMultilingualV2));
+    assert_eq!(item.character_count_change(), Some(16));
+    assert_eq!(page.history[1].state, HistoryItemState::Unknown);
+    assert_eq!(page.history[1].character_count_change(), None);
+
+    client
+        .text_to_speech_from_history(item)
+        .seed(7)
+        .execute()
+        .await
+        .unwrap();
+    let request = &server.requests()[1];
+    assert!(request.path.starts_with("/v1/text-to-speech/voice-1?"));
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["text"], "Once upon a time");
+    assert_eq!(body["model_id"], "eleven_multilingual_v2");
+    assert_eq!(body["seed"], 7);
+    assert!((body["voice_settings"]["stability"].as_f64().unwrap() - 0.3).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn test_voice_preview_downloads_from_preview_url() {
+    // Previews are hosted outside the API
+    let storage = MockServer::start(vec![
+        MockResponse::new(200, vec![0xFF, 0xFB, 0x90, 0xC4]).header("content-type", "audio/mpeg"),
+    ])
+    .await;
+    let server = MockServer::start(vec![MockResponse::new(
+        200,
+        format!(
+            r#"{{"voice_id": "21m00Tcm4TlvDq8ikWAM", "name": "Rachel", "preview_url": "{}/previews/rachel.mp3"}}"#,
+            storage.base_url
+        ),
+    )])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let preview = client
+        .voices()
+        .preview("21m00Tcm4TlvDq8ikWAM")
+        .await
+        .unwrap();
+    assert_eq!(preview.audio, [0xFF, 0xFB, 0x90, 0xC4]);
+    assert_eq!(preview.content_type.as_deref(), Some("audio/mpeg"));
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/v1/voices/21m00Tcm4TlvDq8ikWAM");
+    assert_eq!(requests[0].header("xi-api-key"), Some("test-key"));
+    // The API key isn't sent to the preview host
+    let downloads = storage.requests();
+    assert_eq!(downloads[0].path, "/v1/previews/rachel.mp3");
+    assert_eq!(downloads[0].header("xi-api-key"), None);
+
+    let voice = elevenlabs_tts::Voice {
+        preview_url: None,
+        ..client.voices().get("21m00Tcm4TlvDq8ikWAM").await.unwrap()
+    };
+    let error = client.voices().download_preview(&voice).await.unwrap_err();
+    assert!(matches!(error, ElevenLabsTTSError::ValidationError(_)));
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn test_voice_search_list_all_follows_page_tokens() {
+    use futures_util::TryStreamExt;
+
+    let client = ElevenLabsTTSClient::mock();
+    let voices: Vec<elevenlabs_tts::Voice> = client
+        .search_voices()
+        .page_size(20)
+        .list_all()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(voices.len(), voices::all().len());
+    assert_eq!(voices[54].name, voices::all()[54].name);
+
+    // Errors end the stream
+    let server = MockServer::start(vec![MockResponse::new(500, "oops")]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let mut voices = client.search_voices().list_all();
+    assert!(voices.try_next().await.is_err());
+}
+
+#[tokio::test]
+async fn test_text_to_speech_from_path_and_reader() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let path =
+        std::env::temp_dir().join(format!("elevenlabs-tts-script-{}.txt", std::process::id()));
+    std::fs::write(&path, b"\xEF\xBB\xBFChapter one.\n").unwrap();
+    let builder = client.text_to_speech_from_path(&path).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+    builder.voice_id("voice").execute().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[0].body).unwrap();
+    assert_eq!(body["text"], "Chapter one.\n");
+
+    let utf16: Vec<u8> = [0xFF, 0xFE]
+        .into_iter()
+        .chain("Ça va".encode_utf16().flat_map(u16::to_le_bytes))
+        .collect();
+    let builder = client.text_to_speech_from_reader(&utf16[..]).await.unwrap();
+    builder.voice_id("voice").execute().await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[1].body).unwrap();
+    assert_eq!(body["text"], "Ça va");
+
+    let missing = client
+        .text_to_speech_from_path(std::env::temp_dir().join("elevenlabs-tts-missing.txt"))
+        .await;
+    assert!(matches!(missing, Err(ElevenLabsTTSError::IoError(_))));
+}
+
+#[tokio::test]
+async fn test_audiobook_writes_chapters_and_manifest() {
+    use elevenlabs_tts::audiobook::MANIFEST_FILE_NAME;
+
+    // 0.1s of 24kHz 16-bit PCM per request
+    let pcm = vec![0u8; 4_800];
+    let server = MockServer::start(
+        (1..=3)
+            .map(|n| {
+                MockResponse::new(200, pcm.clone()).header("request-id", &format!("req-{}", n))
+            })
+            .collect(),
+    )
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let dir = std::env::temp_dir().join(format!("elevenlabs-tts-book-{}", std::process::id()));
+
+    let book = client
+        .audiobook(&dir, |builder| {
+            builder
+                .voice_id("narrator")
+                .output_format(OutputFormat::Pcm_24000)
+        })
+        .seed(7)
+        .max_chunk_characters(10)
+        .chapters([("Intro", "One. Two."), ("", "Bye.")]);
+    assert_eq!(book.chunks(), [vec!["Intro.", "One. Two."], vec!["Bye."]]);
+    let manifest = book.execute().await.unwrap();
+
+    assert_eq!(manifest.chapters.len(), 2);
+    assert_eq!(manifest.chapters[0].file, "01-intro.wav");
+    assert_eq!(manifest.chapters[0].request_ids, ["req-1", "req-2"]);
+    assert_eq!(manifest.chapters[0].characters, 15);
+    assert_eq!(manifest.chapters[0].duration_seconds, Some(0.2));
+    assert_eq!(manifest.chapters[1].file, "02.wav");
+    assert_eq!(manifest.total_characters, 19);
+    assert_eq!(manifest.seed, Some(7));
+    assert_eq!(manifest.output_format, "pcm_24000");
+
+    let wav = std::fs::read(dir.join("01-intro.wav")).unwrap();
+    assert_eq!(&wav[..4], b"RIFF");
+    assert_eq!(wav.len(), 44 + 2 * 4_800);
+    let saved = elevenlabs_tts::AudiobookManifest::load(dir.join(MANIFEST_FILE_NAME))
+        .await
+        .unwrap();
+    assert_eq!(saved, manifest);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let requests = server.requests();
+    let second: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(second["text"], "One. Two.");
+    assert_eq!(second["seed"], 7);
+    assert_eq!(second["previous_request_ids"], serde_json::json!(["req-1"]));
+    // Chapters are stitched independently
+    let third: serde_json::Value = serde_json::from_slice(&requests[2].body).unwrap();
+    assert!(
+        third
+            .get("previous_request_ids")
+            .is_none_or(|ids| ids.is_null())
+    );
+}
+
+#[tokio::test]
+async fn test_long_form_execute_to_path_writes_one_file() {
+    let server = MockServer::start(vec![
+        MockResponse::new(200, vec![1u8; 320]),
+        MockResponse::new(200, vec![2u8; 160]),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let base = std::env::temp_dir().join(format!("elevenlabs-tts-long-{}", std::process::id()));
+
+    let path = client
+        .text_to_speech("First sentence. Second one.")
+        .output_format(OutputFormat::Pcm_16000)
+        .long_form()
+        .max_chunk_characters(16)
+        .execute_to_path(&base)
+        .await
+        .unwrap();
+    assert_eq!(
+        path.extension().unwrap(),
+        OutputFormat::Pcm_16000.file_extension()
+    );
+    let wav = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&wav[..4], b"RIFF");
+    assert_eq!(&wav[44..], [vec![1u8; 320], vec![2u8; 160]].concat());
+}
+
+#[cfg(feature = "langdetect")]
+#[tokio::test]
+async fn test_language_detection_sets_language_code() {
+    use elevenlabs_tts::TextToSpeechBuilder;
+
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let text = "Der schnelle braune Fuchs springt über den faulen Hund und läuft davon.";
+
+    let language_code =
+        |model: models::ModelId, configure: fn(TextToSpeechBuilder) -> TextToSpeechBuilder| {
+            let builder = client.text_to_speech(text).model(model);
+            let request = configure(builder).build_request().unwrap();
+            request.language_code
+        };
+    let flash = models::ModelId::ElevenFlashV2_5;
+    assert_eq!(language_code(flash.clone(), |b| b).as_deref(), Some("de"));
+    assert_eq!(
+        language_code(models::ModelId::ElevenTurboV2_5, |b| b).as_deref(),
+        Some("de")
+    );
+    assert_eq!(
+        language_code(flash.clone(), |b| b.language_code(LanguageCode::EN)).as_deref(),
+        Some("en")
+    );
+    assert_eq!(
+        language_code(flash.clone(), |b| b.detect_language(false)),
+        None
+    );
+    assert_eq!(
+        language_code(models::ModelId::ElevenMultilingualV2, |b| b),
+        None
+    );
+
+    client
+        .text_to_speech(text)
+        .model(flash)
+        .execute()
+        .await
+        .unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&server.requests()[0].body).unwrap();
+    assert_eq!(body["language_code"], "de");
+}
+
+#[tokio::test]
+async fn test_compare_voices_labels_each_result() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::with_base_url("test-key", &server.base_url);
+    let rachel = voices::all_voices::RACHEL.voice_id;
+
+    let auditions = client
+        .compare_voices("Hello there", [rachel, "custom-voice"])
+        .configure(|builder| builder.model(models::ModelId::ElevenFlashV2_5))
+        .concurrency(2)
+        .execute()
+        .await;
+    assert_eq!(auditions.len(), 2);
+    assert_eq!(auditions[0].voice_id, rachel);
+    assert_eq!(auditions[0].name, Some("Rachel"));
+    assert_eq!(auditions[1].voice_id, "custom-voice");
+    assert_eq!(auditions[1].name, None);
+    assert!(auditions.iter().all(|audition| audition.result.is_ok()));
+
+    let mut paths: Vec<String> = server
+        .requests()
+        .into_iter()
+        .map(|request| request.path)
+        .collect();
+    paths.sort();
+    assert!(paths[0].starts_with(&format!("/v1/text-to-speech/{}?", rachel)));
+    assert!(paths[1].starts_with("/v1/text-to-speech/custom-voice?"));
+    for request in server.requests() {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["text"], "Hello there");
+        assert_eq!(body["model_id"], "eleven_flash_v2_5");
+    }
+}
+
+#[tokio::test]
+async fn test_model_capability_check_uses_cached_models() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"[{
+                "model_id": "eleven_multilingual_v2",
+                "name": "Eleven Multilingual v2",
+                "can_do_text_to_speech": true,
+                "can_use_style": true,
+                "languages": [{"language_id": "en", "name": "English"}],
+                "maximum_text_length_per_request": 10000
+            }, {
+                "model_id": "eleven_flash_v2_5",
+                "name": "Eleven Flash v2.5",
+                "can_do_text_to_speech": true,
+                "can_use_style": false,
+                "languages": [{"language_id": "en", "name": "English"}, {"language_id": "fr", "name": "French"}]
+            }, {
+                "model_id": "eleven_english_sts_v2",
+                "name": "Eleven English v2",
+                "can_do_text_to_speech": false,
+                "languages": []
+            }]"#,
+        ),
+        MockResponse::new(200, "audio"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .check_model_capabilities(true)
+        .build()
+        .unwrap();
+
+    let error = client
+        .text_to_speech("Bonjour")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .language_code("de")
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("supported: en, fr"), "{}", error);
+
+    let error = client
+        .text_to_speech("Hello")
+        .model(models::ModelId::ElevenEnglishStsV2)
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("text-to-speech"), "{}", error);
+
+    let error = client
+        .text_to_speech("Hello")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .voice_settings(VoiceSettings::energetic())
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("style"), "{}", error);
+
+    let error = client
+        .text_to_speech("Hello")
+        .model(models::ModelId::ElevenTurboV2)
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("not available"), "{}", error);
+
+    client
+        .text_to_speech("Bonjour")
+        .model(models::ModelId::ElevenFlashV2_5)
+        .language_code("fr")
+        .execute()
+        .await
+        .unwrap();
+
+    // The models list is fetched once and every rejected request stays local
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].path, "/v1/models");
+    assert!(requests[1].path.starts_with("/v1/text-to-speech/"));
+}
+
+#[test]
+fn test_voice_settings_omitted_unless_set() {
+    let client = ElevenLabsTTSClient::new("test-key");
+
+    // Without settings the voice's stored settings apply, so none are sent
+    let request = client.text_to_speech("Hello").build_request().unwrap();
+    assert_eq!(request.voice_settings, None);
+    let body = serde_json::to_value(&request).unwrap();
+    assert!(body.get("voice_settings").is_none());
+
+    let request = client
+        .text_to_speech("Hello")
+        .voice_settings(VoiceSettings::calm())
+        .build_request()
+        .unwrap();
+    let body = serde_json::to_value(&request).unwrap();
+    assert_eq!(body["voice_settings"]["speed"], 0.9f32);
+}
+
+#[tokio::test]
+async fn test_usage_tracker_enforces_daily_budget() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let usage = UsageTracker::new().daily_budget(10);
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .usage_tracker(usage.clone())
+        .build()
+        .unwrap();
+
+    client
+        .text_to_speech("Hello")
+        .voice_id("voice-1")
+        .execute()
+        .await
+        .unwrap();
+    client
+        .text_to_speech("Héllo")
+        .voice_id("voice-1")
+        .execute()
+        .await
+        .unwrap();
+    let error = client.text_to_speech("!").execute().await.unwrap_err();
+    assert!(matches!(
+        error,
+        ElevenLabsTTSError::QuotaExceededError {
+            used: Some(10),
+            limit: Some(10),
+            ..
+        }
+    ));
+    assert_eq!(server.requests().len(), 2);
+
+    assert_eq!(usage.characters_today(), 10);
+    assert_eq!(usage.characters_this_month(), 10);
+    let entries = usage.entries();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].voice_id, "voice-1");
+    assert_eq!(entries[0].model_id, "eleven_multilingual_v2");
+    assert_eq!(entries[0].characters, 10);
+
+    usage.reset();
+    assert!(usage.check(10).is_ok());
+    assert!(usage.check(11).is_err());
+}
+
+#[tokio::test]
+async fn test_usage_tracker_skips_cached_replays() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let usage = UsageTracker::new().daily_budget(5);
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .cache(std::sync::Arc::new(MemoryCache::new(4)))
+        .usage_tracker(usage.clone())
+        .build()
+        .unwrap();
+
+    client.text_to_speech("Hello").execute().await.unwrap();
+    // The budget is spent, but replays come from the cache without an API call
+    for _ in 0..3 {
+        let again = client.text_to_speech("Hello").execute().await.unwrap();
+        assert_eq!(again, b"audio");
+    }
+    assert_eq!(usage.characters_today(), 5);
+    assert_eq!(usage.entries().len(), 1);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_execute_checked_fails_fast_on_low_quota() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"tier": "starter", "character_count": 990, "character_limit": 1000, "status": "active"}"#,
+        ),
+        MockResponse::new(200, "audio").header("character-cost", "6"),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let audio = client
+        .text_to_speech("Hello")
+        .execute_checked()
+        .await
+        .unwrap();
+    assert_eq!(audio, b"audio");
+
+    // The cached subscription now has 996 of 1000 characters used
+    let error = client
+        .text_to_speech("Hello")
+        .execute_checked()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ElevenLabsTTSError::QuotaExceededError {
+            used: Some(996),
+            limit: Some(1000),
+            ..
+        }
+    ));
+    assert_eq!(error.quota_remaining(), Some(4));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].path, "/v1/user/subscription");
+    assert!(requests[1].path.starts_with("/v1/text-to-speech/"));
+}
+
+#[tokio::test]
+async fn test_execute_checked_does_not_count_cached_replays() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"tier": "starter", "character_count": 980, "character_limit": 1000, "status": "active"}"#,
+        ),
+        MockResponse::new(200, "audio").header("character-cost", "6"),
+    ])
+    .await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key("test-key")
+        .base_url(&server.base_url)
+        .cache(std::sync::Arc::new(MemoryCache::new(4)))
+        .build()
+        .unwrap();
+
+    for _ in 0..4 {
+        let audio = client
+            .text_to_speech("Hello")
+            .execute_checked()
+            .await
+            .unwrap();
+        assert_eq!(audio, b"audio");
+    }
+
+    // Only the first call was billed, so 986 of 1000 characters are used
+    let error = client
+        .text_to_speech("Hello there, how are you?")
+        .execute_checked()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        ElevenLabsTTSError::QuotaExceededError {
+            used: Some(986),
+            limit: Some(1000),
+            ..
+        }
+    ));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_queue_runs_interactive_jobs_first() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let queue = TtsQueue::new(1);
+    let mut events = queue.subscribe();
+
+    let background: Vec<_> = ["one", "two", "three"]
+        .into_iter()
+        .map(|text| queue.enqueue(client.text_to_speech(text), Priority::Background))
+        .collect();
+    let cancelled = queue.enqueue(client.text_to_speech("never sent"), Priority::Normal);
+    let cancelled_id = cancelled.id();
+    drop(cancelled);
+    let interactive = queue.enqueue(client.text_to_speech("now"), Priority::Interactive);
+
+    assert_eq!(interactive.await.unwrap().audio, b"audio");
+    for job in background {
+        job.await.unwrap();
+    }
+
+    let texts: Vec<String> = server
+        .requests()
+        .iter()
+        .map(|request| {
+            let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+            body["text"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(texts, ["now", "one", "two", "three"]);
+    assert_eq!(queue.waiting(), 0);
+
+    let mut cancelled_event = false;
+    while let Ok(event) = events.try_recv() {
+        cancelled_event |= event
+            == QueueEvent::Cancelled {
+                job_id: cancelled_id,
+            };
+    }
+    assert!(cancelled_event);
+}
+
+#[test]
+fn test_debug_output_redacts_api_key() {
+    let client = ElevenLabsTTSClient::new("sk-very-secret");
+    let debug = format!("{:?}", client);
+    assert!(debug.contains("api.elevenlabs.io"));
+    assert!(debug.contains("[REDACTED]"));
+    assert!(!debug.contains("sk-very-secret"));
+
+    let key = format!("{:?}", elevenlabs_tts::StaticApiKey::new("sk-very-secret"));
+    assert!(!key.contains("sk-very-secret"));
+}
+
+#[cfg(feature = "secrecy")]
+#[tokio::test]
+async fn test_secret_api_key_is_sent() {
+    let server = MockServer::start(vec![MockResponse::new(200, "audio")]).await;
+    let client = ElevenLabsTTSClient::builder()
+        .api_key_secret(elevenlabs_tts::secrecy::SecretString::from(
+            "sk-very-secret",
+        ))
+        .base_url(&server.base_url)
+        .build()
+        .unwrap();
+    assert!(!format!("{:?}", client).contains("sk-very-secret"));
+
+    client.text_to_speech("Hello").execute().await.unwrap();
+    assert_eq!(
+        server.requests()[0].header("xi-api-key"),
+        Some("sk-very-secret")
+    );
+}
+
+#[test]
+fn test_voice_and_request_id_newtypes() {
+    let voice_id: VoiceId = "21m00Tcm4TlvDq8ikWAM".parse().unwrap();
+    assert_eq!(voice_id.to_string(), "21m00Tcm4TlvDq8ikWAM");
+    assert_eq!(VoiceId::from(&voices::all_voices::RACHEL), voice_id);
+
+    let error = VoiceId::new("Rachel").unwrap_err().to_string();
+    assert!(error.contains("voice name"), "{}", error);
+    assert!(error.contains("21m00Tcm4TlvDq8ikWAM"), "{}", error);
+    assert!(VoiceId::new("too-short").is_err());
+
+    let request_id = RequestId::new("req_5Ntc2Q9s-YqDh").unwrap();
+    assert!(RequestId::new("").is_err());
+    assert!(RequestId::new("has spaces").is_err());
+    assert!(RequestId::new("x".repeat(65)).is_err());
+
+    let json = serde_json::to_string(&voice_id).unwrap();
+    assert_eq!(json, "\"21m00Tcm4TlvDq8ikWAM\"");
+    assert_eq!(serde_json::from_str::<VoiceId>(&json).unwrap(), voice_id);
+    assert!(serde_json::from_str::<RequestId>("\"not valid!\"").is_err());
+
+    let client = ElevenLabsTTSClient::new("test-key");
+    let request = client
+        .text_to_speech("Hello")
+        .voice_id(&voice_id)
+        .previous_request_ids([request_id.clone()])
+        .next_request_ids(vec!["req_next"]);
+    request.validate().unwrap();
+    let request = request.build_request().unwrap();
+    assert_eq!(
+        request.previous_request_ids,
+        Some(vec![request_id.to_string()])
+    );
+
+    let error = client
+        .text_to_speech("Hello")
+        .voice_id("Rachel")
+        .validate()
+        .unwrap_err();
+    assert!(error.to_string().contains("21m00Tcm4TlvDq8ikWAM"));
+}
+
+#[cfg(feature = "tags")]
+#[tokio::test]
+async fn test_id3_tags_written_into_mp3() {
+    use elevenlabs_tts::tags::Id3Tags;
+
+    // One 128kbps 44.1kHz MPEG-1 Layer III frame
+    let mut frame = vec![0u8; 417];
+    frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0xC4]);
+
+    let tagged = Id3Tags::new()
+        .title("Episode 1")
+        .voice(&voices::all_voices::RACHEL)
+        .track(1)
+        .apply(&frame)
+        .unwrap();
+    assert_eq!(&tagged[..3], b"ID3");
+    assert!(tagged.ends_with(&frame));
+    let tags = Id3Tags::read(&tagged).unwrap().unwrap();
+    assert_eq!(tags.title.as_deref(), Some("Episode 1"));
+    assert_eq!(tags.artist.as_deref(), Some("Rachel"));
+    assert_eq!(tags.track, Some(1));
+
+    // Retagging replaces the tag and keeps the fields not set again
+    let retagged = Id3Tags::new().album("Pilot").apply(&tagged).unwrap();
+    assert!(retagged.ends_with(&frame));
+    let tags = Id3Tags::read(&retagged).unwrap().unwrap();
+    assert_eq!(tags.title.as_deref(), Some("Episode 1"));
+    assert_eq!(tags.album.as_deref(), Some("Pilot"));
+    assert_eq!(Id3Tags::read(&frame).unwrap(), None);
+    assert!(Id3Tags::new().title("x").apply(b"RIFF....WAVE").is_err());
+
+    let server = MockServer::start(vec![MockResponse::new(200, frame.clone())]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let dir = std::env::temp_dir().join(format!("elevenlabs-tts-tags-{}", std::process::id()));
+    client
+        .audiobook(&dir, |builder| builder.voice(&voices::all_voices::ADAM))
+        .id3_tags(Id3Tags::new().album("The Book"))
+        .chapters([("Opening", "Hello."), ("Ending", "Bye.")])
+        .execute()
+        .await
+        .unwrap();
+    let chapter = std::fs::read(dir.join("02-ending.mp3")).unwrap();
+    let tags = Id3Tags::read(&chapter).unwrap().unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        tags,
+        Id3Tags::new()
+            .title("Ending")
+            .artist("Adam")
+            .album("The Book")
+            .track(2)
+            .total_tracks(2)
+    );
+
+    let error = client
+        .audiobook(&dir, |builder| {
+            builder.output_format(OutputFormat::Pcm_24000)
+        })
+        .id3_tags(Id3Tags::new())
+        .chapter("One", "Hi.")
+        .execute()
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("MP3"));
+}
+
+#[cfg(feature = "decode")]
+#[tokio::test]
+async fn test_decode_output_to_pcm_samples() {
+    use elevenlabs_tts::decode;
+
+    // Two silent 128kbps 44.1kHz mono MPEG-1 Layer III frames
+    let mut mp3 = vec![0u8; 2 * 417];
+    mp3[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0xC4]);
+    mp3[417..421].copy_from_slice(&[0xFF, 0xFB, 0x90, 0xC4]);
+    let server = MockServer::start(vec![
+        MockResponse::new(200, mp3).header("content-type", "audio/mpeg"),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let response = client
+        .text_to_speech("Hello")
+        .execute_detailed()
+        .await
+        .unwrap();
+    let decoded = response.decode_pcm().unwrap();
+    assert_eq!((decoded.sample_rate, decoded.channels), (44_100, 1));
+    assert!(decoded.frames() > 0);
+    assert!(decoded.samples.iter().all(|sample| sample.abs() < 1e-3));
+
+    let ulaw = decode::decode_pcm(&[0xFF, 0x00], OutputFormat::Ulaw_8000).unwrap();
+    assert_eq!((ulaw.sample_rate, ulaw.channels), (8_000, 1));
+    assert_eq!(ulaw.samples[0], 0.0);
+    assert!(ulaw.samples[1] < -0.9);
+
+    let pcm = AudioResponse {
+        audio: vec![0; 4],
+        request_id: None,
+        history_item_id: None,
+        character_cost: None,
+        content_type: None,
+    };
+    assert!(pcm.decode_pcm().is_err());
+    assert!(decode::decode_pcm(&[0; 3], OutputFormat::Pcm_16000).is_err());
+    assert!(decode::decode_pcm(b"OggS", OutputFormat::Opus_48000_64).is_err());
+}
+
+#[cfg(feature = "wav")]
+#[tokio::test]
+async fn test_wav_files_from_raw_formats() {
+    use elevenlabs_tts::wav;
+
+    let server =
+        MockServer::start(vec![MockResponse::new(200, vec![0x34, 0x12, 0xFF, 0x7F])]).await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let output = client
+        .text_to_speech("Hello")
+        .output_format(OutputFormat::Pcm_22050)
+        .execute_output()
+        .await
+        .unwrap();
+    let bytes = output.to_wav().unwrap();
+    assert_eq!(&bytes[..4], b"RIFF");
+    assert_eq!(
+        u32::from_le_bytes(bytes[24..28].try_into().unwrap()),
+        22_050
+    );
+    assert_eq!(u16::from_le_bytes(bytes[34..36].try_into().unwrap()), 16);
+    assert_eq!(&bytes[bytes.len() - 4..], [0x34, 0x12, 0xFF, 0x7F]);
+
+    let path = std::env::temp_dir().join(format!("elevenlabs-tts-{}.wav", std::process::id()));
+    output.save_wav(&path).await.unwrap();
+    assert_eq!(std::fs::read(&path).unwrap(), bytes);
+    std::fs::remove_file(&path).unwrap();
+
+    // Telephony audio is expanded to 16-bit PCM at 8kHz
+    let ulaw = [0xFFu8, 0x00, 0x80];
+    let bytes = wav::to_wav(&ulaw, OutputFormat::Ulaw_8000).unwrap();
+    assert_eq!(u32::from_le_bytes(bytes[24..28].try_into().unwrap()), 8_000);
+    assert_eq!(&bytes[44..], elevenlabs_tts::audio::ulaw_to_pcm(&ulaw));
+    let bytes = wav::to_wav(&[0xD5], OutputFormat::Alaw_8000).unwrap();
+    assert_eq!(bytes.len(), 44 + 2);
+
+    assert!(wav::to_wav(&[0xFF, 0xFB], OutputFormat::Mp3_44100_128).is_err());
+    assert!(wav::to_wav(&[0; 3], OutputFormat::Pcm_16000).is_err());
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn test_axum_speech_response_streams_audio() {
+    use axum::response::IntoResponse;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(200, b"OggS-audio".to_vec()),
+        MockResponse::new(429, r#"{"detail":"Too many requests"}"#).header("retry-after", "3"),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let speech = client
+        .text_to_speech("Hello")
+        .output_format(OutputFormat::Opus_48000_64)
+        .execute_response()
+        .await
+        .unwrap();
+    assert_eq!(speech.content_type(), "audio/ogg");
+    let response = speech.into_response();
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.headers()["content-type"], "audio/ogg");
+    assert!(response.headers().get("content-length").is_none());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(&body[..], b"OggS-audio");
+
+    let error = client
+        .text_to_speech("Hello")
+        .execute_response()
+        .await
+        .err()
+        .unwrap();
+    let response = error.into_response();
+    assert_eq!(response.status(), 429);
+    assert_eq!(response.headers()["retry-after"], "3");
+
+    let invalid = client
+        .text_to_speech("")
+        .execute_response()
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(invalid.into_response().status(), 400);
+    assert_eq!(OutputFormat::Mp3_44100_128.mime_type(), "audio/mpeg");
+}
+
+#[cfg(feature = "actix-web")]
+#[tokio::test]
+async fn test_actix_speech_responder_streams_audio() {
+    use actix_web::{Responder, ResponseError, test::TestRequest};
+
+    let server = MockServer::start(vec![
+        MockResponse::new(200, vec![0xFF, 0xFB, 0x90, 0xC4]),
+        MockResponse::new(429, r#"{"detail":"Too many requests"}"#).header("retry-after", "3"),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let request = TestRequest::default().to_http_request();
+
+    let speech = client
+        .text_to_speech("Hello")
+        .execute_response()
+        .await
+        .unwrap();
+    let response = speech.respond_to(&request);
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        response.headers().get("content-type").unwrap(),
+        "audio/mpeg"
+    );
+    let body = actix_web::body::to_bytes(response.into_body())
+        .await
+        .unwrap();
+    assert_eq!(&body[..], [0xFF, 0xFB, 0x90, 0xC4]);
+
+    let error = client
+        .text_to_speech("Hello")
+        .execute_response()
+        .await
+        .err()
+        .unwrap();
+    let response = error.error_response();
+    assert_eq!(response.status(), 429);
+    assert_eq!(response.headers().get("retry-after").unwrap(), "3");
+
+    let invalid = client
+        .text_to_speech("")
+        .execute_response()
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(ResponseError::status_code(&invalid), 400);
+}
+
+#[test]
+fn test_webhook_signature_verification() {
+    use elevenlabs_tts::webhooks::{WebhookPayload, WebhookVerifier};
+
+    let body = br#"{
+        "type": "speech_to_text_transcription",
+        "event_timestamp": 1700000000,
+        "data": {
+            "request_id": "stt_123",
+            "transcription": {
+                "language_code": "eng",
+                "text": "Hello world",
+                "words": [{"text": "Hello", "type": "word", "start": 0.0, "end": 0.4}]
+            },
+            "webhook_metadata": {"job": 7}
+        }
+    }"#;
+    let verifier = WebhookVerifier::new("wsec_secret");
+    let now = 1_700_000_100;
+    let header = verifier.sign(body, now - 60);
+    assert!(header.starts_with("t=1700000040,v0="));
+    verifier.verify_at(body, &header, now).unwrap();
+
+    // Tampered body, wrong secret, stale timestamp and malformed headers are rejected
+    let mut tampered = body.to_vec();
+    tampered[20] ^= 1;
+    assert!(verifier.verify_at(&tampered, &header, now).is_err());
+    let other = WebhookVerifier::new("wsec_other");
+    assert!(other.verify_at(body, &header, now).is_err());
+    let stale = verifier.verify_at(body, &header, now + 3600).unwrap_err();
+    assert!(matches!(stale, ElevenLabsTTSError::WebhookError(_)));
+    assert!(verifier.verify_at(body, "v0=abcd", now).is_err());
+    assert!(verifier.verify_at(body, "t=1700000040,v0=zz", now).is_err());
+    assert!(!format!("{:?}", verifier).contains("wsec_secret"));
+
+    let event = verifier
+        .tolerance(std::time::Duration::MAX)
+        .construct_event(body, &header)
+        .unwrap();
+    assert_eq!(event.event_timestamp, Some(1_700_000_000));
+    let WebhookPayload::SpeechToTextTranscription(completed) = event.payload().unwrap() else {
+        panic!("expected a transcription");
+    };
+    assert_eq!(completed.request_id, "stt_123");
+    assert_eq!(completed.transcription.text, "Hello world");
+    assert_eq!(completed.transcription.words[0].end, Some(0.4));
+    assert_eq!(
+        completed.webhook_metadata,
+        Some(serde_json::json!({ "job": 7 }))
+    );
+}
+
+#[tokio::test]
+async fn test_speech_to_text_webhook_mode() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"message": "Request accepted", "request_id": "stt_123"}"#,
+        ),
+        MockResponse::new(200, r#"{"language_code": "eng", "text": "Hi there"}"#),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let job = client
+        .speech_to_text(b"fake-mp3".to_vec())
+        .file_name("meeting.mp3")
+        .diarize(true)
+        .webhook_metadata(serde_json::json!({ "meeting_id": 42 }))
+        .submit_webhook()
+        .await
+        .unwrap();
+    assert_eq!(job.request_id, "stt_123");
+
+    let transcription = client
+        .speech_to_text(b"short".to_vec())
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(transcription.text, "Hi there");
+
+    let requests = server.requests();
+    assert_eq!(requests[0].path, "/v1/speech-to-text");
+    let content_type = requests[0].header("content-type").unwrap();
+    let boundary = content_type
+        .strip_prefix("multipart/form-data; boundary=")
+        .unwrap();
+    let body = String::from_utf8_lossy(&requests[0].body);
+    assert!(body.ends_with(&format!("--{}--\r\n", boundary)));
+    assert!(body.contains("name=\"file\"; filename=\"meeting.mp3\"\r\nContent-Type: application/octet-stream\r\n\r\nfake-mp3\r\n"));
+    assert!(body.contains("name=\"model_id\"\r\n\r\nscribe_v1\r\n"));
+    assert!(body.contains("name=\"webhook\"\r\n\r\ntrue\r\n"));
+    assert!(body.contains("name=\"webhook_metadata\"\r\n\r\n{\"meeting_id\":42}\r\n"));
+    let body = String::from_utf8_lossy(&requests[1].body);
+    assert!(!body.contains("name=\"webhook\""));
+
+    // Invalid options fail before anything is sent
+    let errors = [
+        client.speech_to_text(Vec::new()).execute().await,
+        client
+            .speech_to_text(b"a".to_vec())
+            .webhook_metadata(serde_json::json!({ "id": 1 }))
+            .execute()
+            .await,
+    ];
+    for error in errors {
+        assert!(matches!(error, Err(ElevenLabsTTSError::ValidationError(_))));
+    }
+    let not_object = client
+        .speech_to_text(b"a".to_vec())
+        .webhook_metadata(serde_json::json!([1, 2]))
+        .submit_webhook()
+        .await;
+    assert!(matches!(
+        not_object,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_voice_remix_previews() {
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"previews": [{"audio_base_64": "SUQz", "generated_voice_id": "gen_1",
+                "media_type": "audio/mpeg", "duration_secs": 4.5, "language": "en"}],
+                "text": "Generated preview text"}"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"{"voice_id": "new_voice", "name": "Rachel, older", "category": "generated"}"#,
+        ),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+
+    let remixed = client
+        .remix_voice("21m00Tcm4TlvDq8ikWAM", "Older and raspier")
+        .auto_generate_text(true)
+        .prompt_strength(0.7)
+        .output_format(OutputFormat::Mp3_22050_32)
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(remixed.previews[0].audio, b"ID3");
+    assert_eq!(remixed.previews[0].generated_voice_id, "gen_1");
+    assert_eq!(remixed.text.as_deref(), Some("Generated preview text"));
+
+    let voice = client
+        .create_voice_from_preview("Rachel, older", "Older and raspier", "gen_1")
+        .await
+        .unwrap();
+    assert_eq!(voice.voice_id, "new_voice");
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v1/text-to-voice/21m00Tcm4TlvDq8ikWAM/remix?output_format=mp3_22050_32"
+    );
+    let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "voice_description": "Older and raspier",
+            "auto_generate_text": true,
+            "prompt_strength": 0.7
+        })
+    );
+    assert_eq!(requests[1].path, "/v1/text-to-voice");
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(body["generated_voice_id"], "gen_1");
+
+    // A preview text or auto-generated text is required, and voice names are rejected
+    let missing_text = client.remix_voice("21m00Tcm4TlvDq8ikWAM", "Older and raspier");
+    assert!(missing_text.build_request().is_err());
+    let short_text = client
+        .remix_voice("21m00Tcm4TlvDq8ikWAM", "Older and raspier")
+        .text("Too short");
+    assert!(short_text.build_request().is_err());
+    let by_name = client
+        .remix_voice("Rachel", "Older")
+        .auto_generate_text(true);
+    assert!(by_name.build_request().is_err());
+}
+
+#[tokio::test]
+async fn test_studio_project_workflow() {
+    use elevenlabs_tts::studio::ProjectState;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"project": {"project_id": "proj_1", "name": "Book", "state": "creating"}}"#,
+        ),
+        MockResponse::new(
+            200,
+            r#"{"chapter": {"chapter_id": "ch_2", "name": "Chapter 2", "state": "default"}}"#,
+        ),
+        MockResponse::new(200, r#"{"status": "ok"}"#),
+        MockResponse::new(
+            200,
+            r#"{"snapshots": [{"project_snapshot_id": "snap_1", "project_id": "proj_1",
+                "name": "v1", "created_at_unix": 1700000000}]}"#,
+        ),
+        MockResponse::new(200, vec![0xFF, 0xFB, 0x90, 0xC4]),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let studio = client.studio();
+
+    let project = studio
+        .create_project("Book")
+        .voice("21m00Tcm4TlvDq8ikWAM")
+        .from_document("book.epub", b"epub-bytes".to_vec())
+        .author("Ferris")
+        .execute()
+        .await
+        .unwrap();
+    assert_eq!(project.project_id, "proj_1");
+    assert_eq!(project.state, ProjectState::Creating);
+
+    let chapter = studio
+        .add_chapter_from_url("proj_1", "Chapter 2", "https://example.com/ch2")
+        .await
+        .unwrap();
+    assert_eq!(chapter.chapter_id, "ch_2");
+    studio.convert_project("proj_1").await.unwrap();
+    let snapshots = studio.project_snapshots("proj_1").await.unwrap();
+    assert_eq!(snapshots[0].project_snapshot_id, "snap_1");
+    let audio = studio
+        .download_project_snapshot("proj_1", "snap_1")
+        .await
+        .unwrap();
+    assert_eq!(audio, [0xFF, 0xFB, 0x90, 0xC4]);
+
+    let requests = server.requests();
+    let paths: Vec<&str> = requests
+        .iter()
+        .map(|request| request.path.as_str())
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            "/v1/studio/projects",
+            "/v1/studio/projects/proj_1/chapters",
+            "/v1/studio/projects/proj_1/convert",
+            "/v1/studio/projects/proj_1/snapshots",
+            "/v1/studio/projects/proj_1/snapshots/snap_1/stream",
+        ]
+    );
+    let form = String::from_utf8_lossy(&requests[0].body);
+    assert!(form.contains("name=\"default_paragraph_voice_id\"\r\n\r\n21m00Tcm4TlvDq8ikWAM\r\n"));
+    assert!(form.contains("name=\"default_model_id\"\r\n\r\neleven_multilingual_v2\r\n"));
+    assert!(form.contains("filename=\"book.epub\""));
+    let body: serde_json::Value = serde_json::from_slice(&requests[1].body).unwrap();
+    assert_eq!(
+        body,
+        serde_json::json!({ "name": "Chapter 2", "from_url": "https://example.com/ch2" })
+    );
+
+    // Projects need voices, and IDs can't be empty
+    let no_voice = studio.create_project("Book").execute().await;
+    assert!(matches!(
+        no_voice,
+        Err(ElevenLabsTTSError::ValidationError(_))
+    ));
+    assert!(studio.convert_chapter("proj_1", " ").await.is_err());
+    // A `/` or `?` would address another endpoint
+    assert!(studio.convert_chapter("proj_1", "ch_2/../x").await.is_err());
+    assert!(studio.project("proj_1?x=1").await.is_err());
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[tokio::test]
+async fn test_studio_chapter_snapshots_stream_latest() {
+    use futures_util::TryStreamExt;
+
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{"snapshots": [
+                {"chapter_snapshot_id": "old", "project_id": "p", "chapter_id": "c", "created_at_unix": 1700000000},
+                {"chapter_snapshot_id": "new", "project_id": "p", "chapter_id": "c", "created_at_unix": 1700009999},
+                {"chapter_snapshot_id": "mid", "project_id": "p", "chapter_id": "c", "created_at_unix": 1700005000}
+            ]}"#,
+        ),
+        MockResponse::new(200, vec![1, 2, 3, 4, 5]),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let studio = client.studio();
+
+    let latest = studio
+        .latest_chapter_snapshot("p", "c")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(latest.chapter_snapshot_id, "new");
+
+    let chunks: Vec<_> = studio
+        .stream_chapter_snapshot("p", "c", &latest.chapter_snapshot_id)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(chunks.concat(), [1, 2, 3, 4, 5]);
+
+    let requests = server.requests();
+    assert_eq!(
+        requests[0].path,
+        "/v1/studio/projects/p/chapters/c/snapshots"
+    );
+    assert_eq!(
+        requests[1].path,
+        "/v1/studio/projects/p/chapters/c/snapshots/new/stream"
+    );
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(&requests[1].body).unwrap(),
+        serde_json::json!({ "convert_to_mpeg": true })
+    );
+}
+
+#[tokio::test]
+async fn test_dubbing_resource_edit_and_rerender() {
+    use elevenlabs_tts::dubbing::{RenderType, SegmentUpdate};
+
+    let server = MockServer::start(vec![
+        MockResponse::new(
+            200,
+            r#"{
+                "id": "dub_1", "version": 3, "source_language": "en", "target_languages": ["es"],
+                "speaker_tracks": {
+                    "spk_b": {"id": "spk_b", "speaker_name": "Bob", "voices": {"es": "v_b"}, "segments": ["seg_2"]},
+                    "spk_a": {"id": "spk_a", "speaker_name": "Alice", "voices": {"es": "v_a"}, "segments": ["seg_3", "seg_1"]}
+                },
+                "speaker_segments": {
+                    "seg_3": {"id": "seg_3", "start_time": 9.0, "end_time": 11.0, "text": "Bye",
+                        "dubs": {"es": {"start_time": 9.0, "end_time": 11.0, "text": "Adiós"}}},
+                    "seg_1": {"id": "seg_1", "start_time": 0.5, "end_time": 2.0, "text": "Hello",
+                        "dubs": {"es": {"start_time": 0.5, "end_time": 2.0, "text": "Hola", "audio_stale": false}}},
+                    "seg_2": {"id": "seg_2", "start_time": 2.5, "end_time": 4.0, "text": "Hi Alice", "dubs": {}}
+                }
+            }"#,
+        ),
+        MockResponse::new(200, r#"{"version": 4}"#),
+        MockResponse::new(200, r#"{"version": 5}"#),
+        MockResponse::new(200, r#"{"version": 5, "render_id": "render_1"}"#),
+    ])
+    .await;
+    let client =
+        ElevenLabsTTSClient::with_base_url("test-key".to_string(), server.base_url.clone());
+    let dubbing = client.dubbing();
+
+    let resource = dubbing.resource("dub_1").await.unwrap();
+    let names: Vec<&str> = resource
+        .speakers()
+        .iter()
+        .map(|speaker| speaker.speaker_name.as_str())
+        .collect();
+    assert_eq!(names, ["Alice", "Bob"]);
+    let order: Vec<&str> = resource
+        .segments()
+        .iter()
+        .map(|segment| segment.id.as_str())
+        .collect();
+    assert_eq!(order, ["seg_1", "seg_2", "seg_3"]);
+    let alice: Vec<&str> = resource
+        .segments_of("spk_a")
+        .iter()
+        .map(|segment| segment.text.as_str())
+        .collect();
+    assert_eq!(alice, ["Hello", "Bye"]);
+    assert_eq!(resource.speaker_segments["seg_1"].dubs["es"].text, "Hola");
+
+    let version = dubbing
+        .update_segment("dub_1", "seg_1", "es", &SegmentUpdate::text("¡Hola!"))
+        .await
+        .unwrap();
+    assert_eq!(version, 4);
+    let version = dubbing
+        .dub_segments("dub_1", &["seg_1".to_string()], &["es".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(version, 5);
+    let render = dubbing
+        .render("dub_1", "es", RenderType::Mp3)
+        .await
+        .unwrap();
+    assert_eq!(render.render_id, "render_1");
+
+    let requests = server.requests();
+    let calls: Vec<(&str, &str)> = requests
+        .iter()
+        .map(|request| (request.method.as_str(), request.path.as_str()))
+        .collect();
+    assert_eq!(
+        calls,
+        [
+            ("GET", "/v1/dubbing/resource/dub_1"),
+            ("PATCH", "/v1/dubbing/resource/dub_1/segment/seg_1/es"),
+            ("POST", "/v1/dubbing/resource/dub_1/dub"),
+            ("POST", "/v1/dubbing/resource/dub_1/render/es"),
+        ]
+    );
+    let bodies: Vec<serde_json::Value> = requests[1..]
+        .iter()
+        .map(|request| serde_json::from_slice(&request.body).unwrap())
+        .collect();
+    assert_eq!(
+        bodies,
+        [
+            serde_json::json!({ "text": "¡Hola!" }),
+            serde_json::json!({ "segments": ["seg_1"], "languages": ["es"] }),
+            serde_json::json!({ "render_type": "mp3" }),
+        ]
+    );
+
+    // Invalid edits fail before anything is sent
+    let backwards = SegmentUpdate::text("Hola").timing(3.0, 1.0);
+    assert!(
+        dubbing
+            .update_segment("dub_1", "seg_1", "es", &backwards)
+            .await
+            .is_err()
+    );
+    assert!(
+        dubbing
+            .dub_segments("dub_1", &[], &["es".to_string()])
+            .await
+            .is_err()
+    );
+    assert!(dubbing.resource("").await.is_err());
+    let edit = SegmentUpdate::text("Hola");
+    assert!(
+        dubbing
+            .update_segment("dub_1", "seg_1", "es/../fr", &edit)
+            .await
+            .is_err()
+    );
+    assert_eq!(server.requests().len(), 4);
+}
+
 // Mock tests for API calls (without real HTTP requests)
 #[cfg(test)]
 mod mock_tests {